@@ -0,0 +1,253 @@
+//
+// MIT License
+//
+// Copyright (c) 2026 Firelink Data
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//
+// File created: 2026-10-18
+// Last updated: 2026-10-18
+//
+
+use arrow::array::{ArrayRef, UInt32Array};
+use arrow::compute::take;
+use evolution_common::error::Result;
+use evolution_parser::trimmer::count_bytes_in_runes;
+
+use std::sync::{Arc, Mutex};
+
+use crate::builder::ColumnBuilder;
+
+/// The field of the most recently built row of a column, shared with the builders of other columns.
+pub type SharedField = Arc<Mutex<Vec<u8>>>;
+
+/// A column builder which shares the field of every row it builds, so that an
+/// [`AlternativeColumnBuilder`] can check whether its condition matches the row, or so that a
+/// [`ListColumnBuilder`](crate::list::ListColumnBuilder) can read its number of elements.
+pub struct DiscriminatingColumnBuilder {
+    inner: Box<dyn ColumnBuilder>,
+    shared: SharedField,
+}
+
+impl DiscriminatingColumnBuilder {
+    /// Create a new [`DiscriminatingColumnBuilder`] wrapping the provided column builder.
+    pub fn new(inner: Box<dyn ColumnBuilder>, shared: SharedField) -> Self {
+        Self { inner, shared }
+    }
+}
+
+impl ColumnBuilder for DiscriminatingColumnBuilder {
+    fn try_build_column(&mut self, bytes: &[u8]) -> Result<usize> {
+        let n_bytes: usize = self.inner.try_build_column(bytes)?;
+        let mut field = self.shared.lock().unwrap();
+        field.clear();
+        field.extend_from_slice(&bytes[..n_bytes]);
+        Ok(n_bytes)
+    }
+
+    fn n_parse_failures(&self) -> usize {
+        self.inner.n_parse_failures()
+    }
+
+    fn finish(&mut self) -> (&str, ArrayRef) {
+        self.inner.finish()
+    }
+}
+
+/// A builder of columns holding one of several alternative layouts of the same region of a
+/// row, which is only built in the rows where the field of another column, with surrounding
+/// whitespace removed, is one of the values of its condition. All other rows are null.
+///
+/// # Note
+/// The column holding the field has to be built before this column in every row, and its
+/// builder has to be wrapped in a [`DiscriminatingColumnBuilder`] sharing the same [`SharedField`].
+pub struct AlternativeColumnBuilder {
+    inner: Box<dyn ColumnBuilder>,
+    name: String,
+    n_runes: usize,
+    values: Vec<String>,
+    discriminator: SharedField,
+    /// Whether or not the region is consumed, which only the last of the alternatives of the
+    /// region that are built should do, since all of them read the same bytes.
+    consumes: bool,
+    /// The index of each row among the built rows, or `None` if the row was not built.
+    indices: Vec<Option<u32>>,
+    n_built: u32,
+}
+
+impl AlternativeColumnBuilder {
+    /// Create a new [`AlternativeColumnBuilder`] of a region of `n_runes` runes, wrapping the
+    /// builder of the alternative.
+    pub fn new(
+        name: String,
+        n_runes: usize,
+        values: Vec<String>,
+        discriminator: SharedField,
+        consumes: bool,
+        inner: Box<dyn ColumnBuilder>,
+    ) -> Self {
+        Self {
+            inner,
+            name,
+            n_runes,
+            values,
+            discriminator,
+            consumes,
+            indices: Vec::new(),
+            n_built: 0,
+        }
+    }
+
+    /// Check whether the shared field of the current row is one of the values of the condition.
+    fn is_match(&self) -> bool {
+        let field = self.discriminator.lock().unwrap();
+        match std::str::from_utf8(&field) {
+            Ok(value) => self.values.iter().any(|v| v == value.trim()),
+            Err(_) => false,
+        }
+    }
+}
+
+impl ColumnBuilder for AlternativeColumnBuilder {
+    fn try_build_column(&mut self, bytes: &[u8]) -> Result<usize> {
+        let n_bytes: usize = count_bytes_in_runes(bytes, self.n_runes);
+
+        if self.is_match() {
+            self.inner.try_build_column(bytes)?;
+            self.indices.push(Some(self.n_built));
+            self.n_built += 1;
+        } else {
+            self.indices.push(None);
+        }
+
+        Ok(if self.consumes { n_bytes } else { 0 })
+    }
+
+    fn n_parse_failures(&self) -> usize {
+        self.inner.n_parse_failures()
+    }
+
+    fn finish(&mut self) -> (&str, ArrayRef) {
+        let (_, built) = self.inner.finish();
+        let indices = UInt32Array::from(std::mem::take(&mut self.indices));
+        self.n_built = 0;
+
+        // Taking a null index produces a null row, and all other indices are in bounds.
+        let array: ArrayRef = take(&built, &indices, None).unwrap();
+        (&self.name, array)
+    }
+}
+
+#[cfg(test)]
+mod tests_alternative {
+    use super::*;
+
+    use crate::datatype::Utf8ColumnBuilder;
+    use crate::nested::StructColumnBuilder;
+    use arrow::array::{Array, AsArray};
+    use evolution_parser::datatype::Utf8Parser;
+    use padder::{Alignment, Symbol};
+
+    fn struct_builder(name: &str) -> Box<dyn ColumnBuilder> {
+        Box::new(StructColumnBuilder::new(
+            name.to_string(),
+            vec![Box::new(Utf8ColumnBuilder::new(
+                "name".to_string(),
+                5,
+                false,
+                None,
+                Utf8Parser::new(Alignment::Left, Symbol::Whitespace),
+            ))],
+            vec![false],
+        ))
+    }
+
+    #[test]
+    fn test_build_alternative_columns() {
+        let shared: SharedField = Arc::new(Mutex::new(Vec::new()));
+        let mut discriminator = DiscriminatingColumnBuilder::new(
+            Box::new(Utf8ColumnBuilder::new(
+                "kind".to_string(),
+                1,
+                false,
+                None,
+                Utf8Parser::new(Alignment::Left, Symbol::Whitespace),
+            )),
+            shared.clone(),
+        );
+        let mut person = AlternativeColumnBuilder::new(
+            "person".to_string(),
+            5,
+            vec!["P".to_string()],
+            shared.clone(),
+            false,
+            struct_builder("person"),
+        );
+        let mut company = AlternativeColumnBuilder::new(
+            "company".to_string(),
+            5,
+            vec!["C".to_string()],
+            shared,
+            true,
+            struct_builder("company"),
+        );
+
+        let rows: [&[u8]; 3] = [b"PAnna ", b"CAcme ", b"XNone "];
+        for row in rows {
+            let mut idx: usize = discriminator.try_build_column(row).unwrap();
+            idx += person.try_build_column(&row[idx..]).unwrap();
+            idx += company.try_build_column(&row[idx..]).unwrap();
+            assert_eq!(6, idx);
+        }
+
+        let (_, person) = person.finish();
+        let (_, company) = company.finish();
+        assert_eq!(3, person.len());
+        assert_eq!(
+            vec![true, false, false],
+            (0..3).map(|i| person.is_valid(i)).collect::<Vec<bool>>()
+        );
+        assert_eq!(
+            vec![false, true, false],
+            (0..3).map(|i| company.is_valid(i)).collect::<Vec<bool>>()
+        );
+        assert_eq!(
+            "Acme",
+            company.as_struct().column(0).as_string::<i32>().value(1),
+        );
+    }
+
+    #[test]
+    fn test_build_alternative_column_without_matches() {
+        let shared: SharedField = Arc::new(Mutex::new(b"X".to_vec()));
+        let mut builder = AlternativeColumnBuilder::new(
+            "person".to_string(),
+            5,
+            vec!["P".to_string()],
+            shared,
+            true,
+            struct_builder("person"),
+        );
+
+        assert_eq!(5, builder.try_build_column(b"Anna ").unwrap());
+        let (name, array) = builder.finish();
+        assert_eq!("person", name);
+        assert_eq!(1, array.null_count());
+    }
+}
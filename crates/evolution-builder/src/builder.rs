@@ -22,12 +22,16 @@
 // SOFTWARE.
 //
 // File created: 2024-05-07
-// Last updated: 2024-10-11
+// Last updated: 2026-10-18
 //
 
-use arrow::array::ArrayRef;
-use evolution_common::error::Result;
+use arrow::array::{ArrayRef, RecordBatch};
+use evolution_common::cancellation::CancellationToken;
+use evolution_common::error::{ExecutionError, Result};
 use evolution_common::NUM_BYTES_FOR_NEWLINE;
+use evolution_parser::trimmer::count_bytes_in_runes;
+
+use std::collections::BTreeMap;
 
 ///
 pub trait Builder: From<Vec<ColumnBuilderRef>> {}
@@ -38,24 +42,59 @@ pub type BuilderRef = Box<dyn Builder>;
 ///
 pub trait ColumnBuilder: Send + Sync {
     fn try_build_column(&mut self, bytes: &[u8]) -> Result<usize>;
+    /// Get the number of fields that could not be parsed since the column was last finished.
+    fn n_parse_failures(&self) -> usize;
     fn finish(&mut self) -> (&str, ArrayRef);
 }
 
 ///
 pub type ColumnBuilderRef = Box<dyn ColumnBuilder>;
 
+/// The number of rows to build between each check of the [`CancellationToken`].
+pub static NUM_ROWS_BETWEEN_CANCELLATION_CHECKS: usize = 1024;
+
 ///
 pub struct ParquetBuilder {
     columns: Vec<ColumnBuilderRef>,
+    /// The number of runes to skip before each column, and after the last column, in every row.
+    n_runes_to_skip: Option<Vec<usize>>,
+    cancellation_token: Option<CancellationToken>,
 }
 
 impl ParquetBuilder {
+    /// Try and build all of the rows in the byte slice, appending each field to its column builder.
     ///
+    /// # Errors
+    /// If any of the columns could not be built, or if cancellation was requested on the
+    /// [`CancellationToken`] of the builder before the entire slice was built.
     pub fn try_build_from_slice(&mut self, buffer: &[u8]) -> Result<()> {
         let mut idx: usize = 0;
+        let mut n_rows_until_check: usize = 0;
         while idx < buffer.len() {
-            for column in self.columns.iter_mut() {
-                idx += column.try_build_column(&buffer[idx..])?;
+            if n_rows_until_check == 0 {
+                if self.is_cancelled() {
+                    return Err(Box::new(ExecutionError::new(
+                        "Building was cancelled, exiting...",
+                    )));
+                }
+                n_rows_until_check = NUM_ROWS_BETWEEN_CANCELLATION_CHECKS;
+            }
+            n_rows_until_check -= 1;
+
+            match &self.n_runes_to_skip {
+                Some(n_runes_to_skip) => {
+                    for (column, n_runes) in self.columns.iter_mut().zip(n_runes_to_skip.iter()) {
+                        idx += count_bytes_in_runes(&buffer[idx..], *n_runes);
+                        idx += column.try_build_column(&buffer[idx..])?;
+                    }
+                    idx +=
+                        count_bytes_in_runes(&buffer[idx..], n_runes_to_skip[self.columns.len()]);
+                }
+                None => {
+                    for column in self.columns.iter_mut() {
+                        idx += column.try_build_column(&buffer[idx..])?;
+                    }
+                }
             }
             idx += NUM_BYTES_FOR_NEWLINE;
         }
@@ -63,16 +102,186 @@ impl ParquetBuilder {
         Ok(())
     }
 
+    /// Set the number of runes to skip before each of the columns, and after the last column,
+    /// in every row. This is used to jump over the fields of columns which should not be built.
+    ///
+    /// # Panics
+    /// If the number of values is not exactly one more than the number of columns.
+    pub fn with_runes_to_skip(mut self, n_runes_to_skip: Vec<usize>) -> Self {
+        assert_eq!(
+            self.columns.len() + 1,
+            n_runes_to_skip.len(),
+            "Expected one more number of runes to skip than there are columns!",
+        );
+        self.n_runes_to_skip = Some(n_runes_to_skip);
+        self
+    }
+
+    /// Set the [`CancellationToken`] which is checked while building from a slice.
+    pub fn set_cancellation_token(&mut self, token: CancellationToken) {
+        self.cancellation_token = Some(token);
+    }
+
+    /// Get whether or not cancellation has been requested on the [`CancellationToken`] of the builder.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancellation_token
+            .as_ref()
+            .is_some_and(|t| t.is_cancelled())
+    }
+
     ///
     pub fn columns(&mut self) -> &mut Vec<ColumnBuilderRef> {
         &mut self.columns
     }
+
+    /// Get the number of fields, per column, that could not be parsed since the
+    /// column builders were last finished.
+    pub fn n_parse_failures(&self) -> Vec<usize> {
+        self.columns
+            .iter()
+            .map(|c| c.n_parse_failures())
+            .collect::<Vec<usize>>()
+    }
+
+    /// Try and finish all of the column builders and create a [`RecordBatch`] from the
+    /// built columns. The column builders are reset and can be reused afterwards.
+    ///
+    /// # Errors
+    /// If the built columns do not all have the same number of rows.
+    pub fn try_finish(&mut self) -> Result<RecordBatch> {
+        // TODO: NOTE THIS ALLOCATES MEMORY ON THE HEAP!!! BAD!!!
+        let columns: Vec<(&str, ArrayRef)> = self
+            .columns
+            .iter_mut()
+            .map(|c| c.finish())
+            .collect::<Vec<(&str, ArrayRef)>>();
+
+        Ok(RecordBatch::try_from_iter(columns)?)
+    }
 }
 
 impl From<Vec<ColumnBuilderRef>> for ParquetBuilder {
     fn from(columns: Vec<ColumnBuilderRef>) -> Self {
-        Self { columns }
+        Self {
+            columns,
+            n_runes_to_skip: None,
+            cancellation_token: None,
+        }
     }
 }
 
 impl Builder for ParquetBuilder {}
+
+/// A builder for files which interleave several record layouts, where the layout of each
+/// record is given by a type code (the discriminator) at a fixed position in the record.
+/// Each record is routed to the [`ParquetBuilder`] of its layout.
+pub struct MultiParquetBuilder {
+    /// The offset of the discriminator in each record (in number of runes).
+    discriminator_offset: usize,
+    /// The length of the discriminator (in number of runes).
+    discriminator_length: usize,
+    builders: BTreeMap<String, ParquetBuilder>,
+}
+
+impl MultiParquetBuilder {
+    /// Create a new [`MultiParquetBuilder`] which routes each record to the builder whose
+    /// key matches the (whitespace trimmed) discriminator of the record.
+    pub fn new(
+        discriminator_offset: usize,
+        discriminator_length: usize,
+        builders: BTreeMap<String, ParquetBuilder>,
+    ) -> Self {
+        Self {
+            discriminator_offset,
+            discriminator_length,
+            builders,
+        }
+    }
+
+    /// Try and build all of the records in the byte slice, routing each record to the
+    /// [`ParquetBuilder`] of its layout. Consecutive records of the same layout are built
+    /// together. Lines which only contain whitespace are skipped.
+    ///
+    /// # Errors
+    /// If the discriminator of a record is not valid UTF-8 or does not match any of the
+    /// layouts, or if any of the layout builders returned an error.
+    pub fn try_build_from_slice(&mut self, buffer: &[u8]) -> Result<()> {
+        let mut idx: usize = 0;
+        let mut run_start: usize = 0;
+        let mut run_code: Option<&str> = None;
+
+        while idx < buffer.len() {
+            let row_end: usize = match buffer[idx..].iter().position(|b| *b == 0x0a) {
+                Some(n_bytes) => idx + n_bytes + 1,
+                None => buffer.len(),
+            };
+            let row: &[u8] = &buffer[idx..row_end];
+
+            let code: Option<&str> = if row.iter().all(|b| b.is_ascii_whitespace()) {
+                None
+            } else {
+                Some(self.try_find_discriminator(row, idx)?)
+            };
+
+            if code != run_code {
+                self.try_build_run(run_code, &buffer[run_start..idx])?;
+                run_start = idx;
+                run_code = code;
+            }
+
+            // Whitespace-only lines are never part of a run.
+            if code.is_none() {
+                run_start = row_end;
+            }
+
+            idx = row_end;
+        }
+
+        self.try_build_run(run_code, &buffer[run_start..idx])
+    }
+
+    fn try_find_discriminator<'a>(&self, row: &'a [u8], byte_idx: usize) -> Result<&'a str> {
+        let start: usize = count_bytes_in_runes(row, self.discriminator_offset);
+        let end: usize = start + count_bytes_in_runes(&row[start..], self.discriminator_length);
+
+        match std::str::from_utf8(&row[start..end]) {
+            Ok(code) => Ok(code.trim()),
+            Err(_) => Err(Box::new(ExecutionError::new(&format!(
+                "The record type of the record at byte {} is not valid UTF-8, exiting...",
+                byte_idx,
+            )))),
+        }
+    }
+
+    fn try_build_run(&mut self, code: Option<&str>, records: &[u8]) -> Result<()> {
+        let code: &str = match code {
+            Some(c) => c,
+            None => return Ok(()),
+        };
+
+        match self.builders.get_mut(code) {
+            Some(builder) => builder.try_build_from_slice(records),
+            None => Err(Box::new(ExecutionError::new(&format!(
+                "Unknown record type '{}', no layout exists for it, exiting...",
+                code,
+            )))),
+        }
+    }
+
+    /// Set the [`CancellationToken`] which is checked by each of the layout builders.
+    pub fn set_cancellation_token(&mut self, token: CancellationToken) {
+        for builder in self.builders.values_mut() {
+            builder.set_cancellation_token(token.clone());
+        }
+    }
+
+    /// Get whether or not cancellation has been requested on the [`CancellationToken`], if any.
+    pub fn is_cancelled(&self) -> bool {
+        self.builders.values().any(|b| b.is_cancelled())
+    }
+
+    /// Get mutable references to the builder of each layout, ordered by record type code.
+    pub fn builders_mut(&mut self) -> impl Iterator<Item = (&String, &mut ParquetBuilder)> {
+        self.builders.iter_mut()
+    }
+}
@@ -22,16 +22,20 @@
 // SOFTWARE.
 //
 // File created: 2024-05-07
-// Last updated: 2024-10-11
+// Last updated: 2026-10-18
 //
 
 use arrow::array::{
-    ArrayRef, BooleanBuilder as BooleanArray, Float16Builder as Float16Array,
+    ArrayRef, BooleanBuilder as BooleanArray, Date32Builder as Date32Array,
+    Decimal128Builder as Decimal128Array, Float16Builder as Float16Array,
     Float32Builder as Float32Array, Float64Builder as Float64Array, Int16Builder as Int16Array,
     Int32Builder as Int32Array, Int64Builder as Int64Array, StringBuilder as Utf8Array,
 };
+use arrow::datatypes::DataType as ArrowDataType;
 use evolution_common::error::{ExecutionError, Result};
-use evolution_parser::datatype::{BooleanParser, FloatParser, IntParser, Utf8Parser};
+use evolution_parser::datatype::{
+    BooleanParser, DateParser, DecimalParser, FloatParser, IntParser, Utf8Parser,
+};
 use half::f16;
 use log::warn;
 
@@ -46,17 +50,27 @@ pub struct BooleanColumnBuilder {
     name: String,
     n_runes: usize,
     is_nullable: bool,
+    default: Option<bool>,
+    n_parse_failures: usize,
 }
 
 impl BooleanColumnBuilder {
     ///
-    pub fn new(name: String, n_runes: usize, is_nullable: bool, parser: BooleanParser) -> Self {
+    pub fn new(
+        name: String,
+        n_runes: usize,
+        is_nullable: bool,
+        default: Option<bool>,
+        parser: BooleanParser,
+    ) -> Self {
         Self {
             inner: BooleanArray::new(),
             parser,
             name,
             n_runes,
             is_nullable,
+            default,
+            n_parse_failures: 0,
         }
     }
 }
@@ -70,7 +84,13 @@ impl ColumnBuilder for BooleanColumnBuilder {
                 n
             }
             (n, None) => {
-                if self.is_nullable {
+                if !self.parser.is_empty(&bytes[..n]) {
+                    self.n_parse_failures += 1;
+                }
+                if let Some(v) = self.default {
+                    self.inner.append_value(v);
+                    n
+                } else if self.is_nullable {
                     warn!("Could not parse byte slice to 'Boolean' datatype, appending null.");
                     self.inner.append_null();
                     n
@@ -85,8 +105,13 @@ impl ColumnBuilder for BooleanColumnBuilder {
         Ok(n_bytes_in_column)
     }
 
+    fn n_parse_failures(&self) -> usize {
+        self.n_parse_failures
+    }
+
     ///
     fn finish(&mut self) -> (&str, ArrayRef) {
+        self.n_parse_failures = 0;
         (&self.name, Arc::new(self.inner.finish()) as ArrayRef)
     }
 }
@@ -98,17 +123,27 @@ pub struct Float16ColumnBuilder {
     name: String,
     n_runes: usize,
     is_nullable: bool,
+    default: Option<f16>,
+    n_parse_failures: usize,
 }
 
 impl Float16ColumnBuilder {
     ///
-    pub fn new(name: String, n_runes: usize, is_nullable: bool, parser: FloatParser) -> Self {
+    pub fn new(
+        name: String,
+        n_runes: usize,
+        is_nullable: bool,
+        default: Option<f16>,
+        parser: FloatParser,
+    ) -> Self {
         Self {
             inner: Float16Array::new(),
             parser,
             name,
             n_runes,
             is_nullable,
+            default,
+            n_parse_failures: 0,
         }
     }
 }
@@ -122,7 +157,13 @@ impl ColumnBuilder for Float16ColumnBuilder {
                 n
             }
             (n, None) => {
-                if self.is_nullable {
+                if !self.parser.is_empty(&bytes[..n]) {
+                    self.n_parse_failures += 1;
+                }
+                if let Some(v) = self.default {
+                    self.inner.append_value(v);
+                    n
+                } else if self.is_nullable {
                     warn!("Could not parse byte slice to 'Float16' datatype, appending null.");
                     self.inner.append_null();
                     n
@@ -137,8 +178,13 @@ impl ColumnBuilder for Float16ColumnBuilder {
         Ok(n_bytes_in_column)
     }
 
+    fn n_parse_failures(&self) -> usize {
+        self.n_parse_failures
+    }
+
     ///
     fn finish(&mut self) -> (&str, ArrayRef) {
+        self.n_parse_failures = 0;
         (&self.name, Arc::new(self.inner.finish()) as ArrayRef)
     }
 }
@@ -150,17 +196,27 @@ pub struct Float32ColumnBuilder {
     name: String,
     n_runes: usize,
     is_nullable: bool,
+    default: Option<f32>,
+    n_parse_failures: usize,
 }
 
 impl Float32ColumnBuilder {
     ///
-    pub fn new(name: String, n_runes: usize, is_nullable: bool, parser: FloatParser) -> Self {
+    pub fn new(
+        name: String,
+        n_runes: usize,
+        is_nullable: bool,
+        default: Option<f32>,
+        parser: FloatParser,
+    ) -> Self {
         Self {
             inner: Float32Array::new(),
             parser,
             name,
             n_runes,
             is_nullable,
+            default,
+            n_parse_failures: 0,
         }
     }
 }
@@ -174,7 +230,13 @@ impl ColumnBuilder for Float32ColumnBuilder {
                 n
             }
             (n, None) => {
-                if self.is_nullable {
+                if !self.parser.is_empty(&bytes[..n]) {
+                    self.n_parse_failures += 1;
+                }
+                if let Some(v) = self.default {
+                    self.inner.append_value(v);
+                    n
+                } else if self.is_nullable {
                     warn!("Could not parse byte slice to 'Float32' datatype, appending null.");
                     self.inner.append_null();
                     n
@@ -189,8 +251,13 @@ impl ColumnBuilder for Float32ColumnBuilder {
         Ok(n_bytes_in_column)
     }
 
+    fn n_parse_failures(&self) -> usize {
+        self.n_parse_failures
+    }
+
     ///
     fn finish(&mut self) -> (&str, ArrayRef) {
+        self.n_parse_failures = 0;
         (&self.name, Arc::new(self.inner.finish()) as ArrayRef)
     }
 }
@@ -202,17 +269,27 @@ pub struct Float64ColumnBuilder {
     name: String,
     n_runes: usize,
     is_nullable: bool,
+    default: Option<f64>,
+    n_parse_failures: usize,
 }
 
 impl Float64ColumnBuilder {
     ///
-    pub fn new(name: String, n_runes: usize, is_nullable: bool, parser: FloatParser) -> Self {
+    pub fn new(
+        name: String,
+        n_runes: usize,
+        is_nullable: bool,
+        default: Option<f64>,
+        parser: FloatParser,
+    ) -> Self {
         Self {
             inner: Float64Array::new(),
             parser,
             name,
             n_runes,
             is_nullable,
+            default,
+            n_parse_failures: 0,
         }
     }
 }
@@ -226,7 +303,13 @@ impl ColumnBuilder for Float64ColumnBuilder {
                 n
             }
             (n, None) => {
-                if self.is_nullable {
+                if !self.parser.is_empty(&bytes[..n]) {
+                    self.n_parse_failures += 1;
+                }
+                if let Some(v) = self.default {
+                    self.inner.append_value(v);
+                    n
+                } else if self.is_nullable {
                     warn!("Could not parse byte slice to 'Float64' datatype, appending null.");
                     self.inner.append_null();
                     n
@@ -241,8 +324,163 @@ impl ColumnBuilder for Float64ColumnBuilder {
         Ok(n_bytes_in_column)
     }
 
+    fn n_parse_failures(&self) -> usize {
+        self.n_parse_failures
+    }
+
+    ///
+    fn finish(&mut self) -> (&str, ArrayRef) {
+        self.n_parse_failures = 0;
+        (&self.name, Arc::new(self.inner.finish()) as ArrayRef)
+    }
+}
+
+/// A builder of date columns, where each date is the number of days since the unix epoch.
+pub struct Date32ColumnBuilder {
+    inner: Date32Array,
+    parser: DateParser,
+    name: String,
+    n_runes: usize,
+    is_nullable: bool,
+    default: Option<i32>,
+    n_parse_failures: usize,
+}
+
+impl Date32ColumnBuilder {
+    /// Create a new [`Date32ColumnBuilder`], where the default value is a number of days
+    /// since the unix epoch.
+    pub fn new(
+        name: String,
+        n_runes: usize,
+        is_nullable: bool,
+        default: Option<i32>,
+        parser: DateParser,
+    ) -> Self {
+        Self {
+            inner: Date32Array::new(),
+            parser,
+            name,
+            n_runes,
+            is_nullable,
+            default,
+            n_parse_failures: 0,
+        }
+    }
+}
+
+impl ColumnBuilder for Date32ColumnBuilder {
+    fn try_build_column(&mut self, bytes: &[u8]) -> Result<usize> {
+        let n_bytes_in_column: usize = match self.parser.try_parse(bytes, self.n_runes) {
+            (n, Some(v)) => {
+                self.inner.append_value(v);
+                n
+            }
+            (n, None) => {
+                if !self.parser.is_empty(&bytes[..n]) {
+                    self.n_parse_failures += 1;
+                }
+                if let Some(v) = self.default {
+                    self.inner.append_value(v);
+                    n
+                } else if self.is_nullable {
+                    warn!("Could not parse byte slice to 'Date32' datatype, appending null.");
+                    self.inner.append_null();
+                    n
+                } else {
+                    return Err(Box::new(ExecutionError::new(
+                        "Could not parse byte slice to 'Date32' datatype, column is not nullable, exiting...",
+                    )));
+                }
+            }
+        };
+
+        Ok(n_bytes_in_column)
+    }
+
+    fn n_parse_failures(&self) -> usize {
+        self.n_parse_failures
+    }
+
+    fn finish(&mut self) -> (&str, ArrayRef) {
+        self.n_parse_failures = 0;
+        (&self.name, Arc::new(self.inner.finish()) as ArrayRef)
+    }
+}
+
+/// A builder of decimal columns with a fixed precision and scale.
+pub struct Decimal128ColumnBuilder {
+    inner: Decimal128Array,
+    parser: DecimalParser,
+    name: String,
+    n_runes: usize,
+    is_nullable: bool,
+    default: Option<i128>,
+    n_parse_failures: usize,
+}
+
+impl Decimal128ColumnBuilder {
+    /// Create a new [`Decimal128ColumnBuilder`] with the provided precision and scale, where
+    /// the default value is scaled by ten to the power of the scale.
     ///
+    /// # Panics
+    /// If the precision is not between 1 and 38, or the scale is larger than the precision.
+    pub fn new(
+        name: String,
+        n_runes: usize,
+        is_nullable: bool,
+        default: Option<i128>,
+        precision: u8,
+        scale: i8,
+        parser: DecimalParser,
+    ) -> Self {
+        Self {
+            inner: Decimal128Array::new()
+                .with_data_type(ArrowDataType::Decimal128(precision, scale)),
+            parser,
+            name,
+            n_runes,
+            is_nullable,
+            default,
+            n_parse_failures: 0,
+        }
+    }
+}
+
+impl ColumnBuilder for Decimal128ColumnBuilder {
+    fn try_build_column(&mut self, bytes: &[u8]) -> Result<usize> {
+        let n_bytes_in_column: usize = match self.parser.try_parse(bytes, self.n_runes) {
+            (n, Some(v)) => {
+                self.inner.append_value(v);
+                n
+            }
+            (n, None) => {
+                if !self.parser.is_empty(&bytes[..n]) {
+                    self.n_parse_failures += 1;
+                }
+                if let Some(v) = self.default {
+                    self.inner.append_value(v);
+                    n
+                } else if self.is_nullable {
+                    warn!("Could not parse byte slice to 'Decimal128' datatype, appending null.");
+                    self.inner.append_null();
+                    n
+                } else {
+                    return Err(Box::new(ExecutionError::new(
+                        "Could not parse byte slice to 'Decimal128' datatype, column is not nullable, exiting...",
+                    )));
+                }
+            }
+        };
+
+        Ok(n_bytes_in_column)
+    }
+
+    fn n_parse_failures(&self) -> usize {
+        self.n_parse_failures
+    }
+
     fn finish(&mut self) -> (&str, ArrayRef) {
+        self.n_parse_failures = 0;
         (&self.name, Arc::new(self.inner.finish()) as ArrayRef)
     }
 }
@@ -254,17 +492,27 @@ pub struct Int16ColumnBuilder {
     name: String,
     n_runes: usize,
     is_nullable: bool,
+    default: Option<i16>,
+    n_parse_failures: usize,
 }
 
 impl Int16ColumnBuilder {
     ///
-    pub fn new(name: String, n_runes: usize, is_nullable: bool, parser: IntParser) -> Self {
+    pub fn new(
+        name: String,
+        n_runes: usize,
+        is_nullable: bool,
+        default: Option<i16>,
+        parser: IntParser,
+    ) -> Self {
         Self {
             inner: Int16Array::new(),
             parser,
             name,
             n_runes,
             is_nullable,
+            default,
+            n_parse_failures: 0,
         }
     }
 }
@@ -278,7 +526,13 @@ impl ColumnBuilder for Int16ColumnBuilder {
                 n
             }
             (n, None) => {
-                if self.is_nullable {
+                if !self.parser.is_empty(&bytes[..n]) {
+                    self.n_parse_failures += 1;
+                }
+                if let Some(v) = self.default {
+                    self.inner.append_value(v);
+                    n
+                } else if self.is_nullable {
                     warn!("Could not parse byte slice to 'Int16' datatype, appending null.");
                     self.inner.append_null();
                     n
@@ -293,8 +547,13 @@ impl ColumnBuilder for Int16ColumnBuilder {
         Ok(n_bytes_in_column)
     }
 
+    fn n_parse_failures(&self) -> usize {
+        self.n_parse_failures
+    }
+
     ///
     fn finish(&mut self) -> (&str, ArrayRef) {
+        self.n_parse_failures = 0;
         (&self.name, Arc::new(self.inner.finish()) as ArrayRef)
     }
 }
@@ -306,17 +565,27 @@ pub struct Int32ColumnBuilder {
     name: String,
     n_runes: usize,
     is_nullable: bool,
+    default: Option<i32>,
+    n_parse_failures: usize,
 }
 
 impl Int32ColumnBuilder {
     ///
-    pub fn new(name: String, n_runes: usize, is_nullable: bool, parser: IntParser) -> Self {
+    pub fn new(
+        name: String,
+        n_runes: usize,
+        is_nullable: bool,
+        default: Option<i32>,
+        parser: IntParser,
+    ) -> Self {
         Self {
             inner: Int32Array::new(),
             parser,
             name,
             n_runes,
             is_nullable,
+            default,
+            n_parse_failures: 0,
         }
     }
 }
@@ -330,7 +599,13 @@ impl ColumnBuilder for Int32ColumnBuilder {
                 n
             }
             (n, None) => {
-                if self.is_nullable {
+                if !self.parser.is_empty(&bytes[..n]) {
+                    self.n_parse_failures += 1;
+                }
+                if let Some(v) = self.default {
+                    self.inner.append_value(v);
+                    n
+                } else if self.is_nullable {
                     warn!("Could not parse byte slice to 'Int32' datatype, appending null.");
                     self.inner.append_null();
                     n
@@ -345,8 +620,13 @@ impl ColumnBuilder for Int32ColumnBuilder {
         Ok(n_bytes_in_column)
     }
 
+    fn n_parse_failures(&self) -> usize {
+        self.n_parse_failures
+    }
+
     ///
     fn finish(&mut self) -> (&str, ArrayRef) {
+        self.n_parse_failures = 0;
         (&self.name, Arc::new(self.inner.finish()) as ArrayRef)
     }
 }
@@ -358,17 +638,27 @@ pub struct Int64ColumnBuilder {
     name: String,
     n_runes: usize,
     is_nullable: bool,
+    default: Option<i64>,
+    n_parse_failures: usize,
 }
 
 impl Int64ColumnBuilder {
     ///
-    pub fn new(name: String, n_runes: usize, is_nullable: bool, parser: IntParser) -> Self {
+    pub fn new(
+        name: String,
+        n_runes: usize,
+        is_nullable: bool,
+        default: Option<i64>,
+        parser: IntParser,
+    ) -> Self {
         Self {
             inner: Int64Array::new(),
             parser,
             name,
             n_runes,
             is_nullable,
+            default,
+            n_parse_failures: 0,
         }
     }
 }
@@ -382,7 +672,13 @@ impl ColumnBuilder for Int64ColumnBuilder {
                 n
             }
             (n, None) => {
-                if self.is_nullable {
+                if !self.parser.is_empty(&bytes[..n]) {
+                    self.n_parse_failures += 1;
+                }
+                if let Some(v) = self.default {
+                    self.inner.append_value(v);
+                    n
+                } else if self.is_nullable {
                     warn!("Could not parse byte slice to 'Int64' datatype, appending null.");
                     self.inner.append_null();
                     n
@@ -397,8 +693,13 @@ impl ColumnBuilder for Int64ColumnBuilder {
         Ok(n_bytes_in_column)
     }
 
+    fn n_parse_failures(&self) -> usize {
+        self.n_parse_failures
+    }
+
     ///
     fn finish(&mut self) -> (&str, ArrayRef) {
+        self.n_parse_failures = 0;
         (&self.name, Arc::new(self.inner.finish()) as ArrayRef)
     }
 }
@@ -410,17 +711,27 @@ pub struct Utf8ColumnBuilder {
     name: String,
     n_runes: usize,
     is_nullable: bool,
+    default: Option<String>,
+    n_parse_failures: usize,
 }
 
 impl Utf8ColumnBuilder {
     ///
-    pub fn new(name: String, n_runes: usize, is_nullable: bool, parser: Utf8Parser) -> Self {
+    pub fn new(
+        name: String,
+        n_runes: usize,
+        is_nullable: bool,
+        default: Option<String>,
+        parser: Utf8Parser,
+    ) -> Self {
         Self {
             inner: Utf8Array::new(),
             parser,
             name,
             n_runes,
             is_nullable,
+            default,
+            n_parse_failures: 0,
         }
     }
 }
@@ -430,11 +741,19 @@ impl ColumnBuilder for Utf8ColumnBuilder {
     fn try_build_column(&mut self, bytes: &[u8]) -> Result<usize> {
         let n_bytes_in_column: usize = match self.parser.try_parse(bytes, self.n_runes) {
             (n, Some(v)) => {
-                self.inner.append_value(v);
+                // An empty field is replaced by the default value, if the column has one.
+                match &self.default {
+                    Some(d) if v.is_empty() => self.inner.append_value(d),
+                    _ => self.inner.append_value(v),
+                };
                 n
             }
             (n, None) => {
-                if self.is_nullable {
+                self.n_parse_failures += 1;
+                if let Some(v) = &self.default {
+                    self.inner.append_value(v);
+                    n
+                } else if self.is_nullable {
                     warn!("Could not parse byte slice to 'Int64' datatype, appending null.");
                     self.inner.append_null();
                     n
@@ -449,8 +768,13 @@ impl ColumnBuilder for Utf8ColumnBuilder {
         Ok(n_bytes_in_column)
     }
 
+    fn n_parse_failures(&self) -> usize {
+        self.n_parse_failures
+    }
+
     ///
     fn finish(&mut self) -> (&str, ArrayRef) {
+        self.n_parse_failures = 0;
         (&self.name, Arc::new(self.inner.finish()) as ArrayRef)
     }
 }
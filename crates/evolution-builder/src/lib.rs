@@ -22,8 +22,11 @@
 // SOFTWARE.
 //
 // File created: 2024-05-07
-// Last updated: 2024-05-29
+// Last updated: 2026-10-18
 //
 
+pub mod alternative;
 pub mod builder;
 pub mod datatype;
+pub mod list;
+pub mod nested;
@@ -0,0 +1,322 @@
+//
+// MIT License
+//
+// Copyright (c) 2026 Firelink Data
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//
+// File created: 2026-10-18
+// Last updated: 2026-10-18
+//
+
+use arrow::array::{Array, ArrayRef, FixedSizeListArray, ListArray};
+use arrow::buffer::{NullBuffer, OffsetBuffer};
+use arrow::datatypes::Field as ArrowField;
+use evolution_common::error::{ExecutionError, Result};
+use evolution_parser::datatype::IntParser;
+use evolution_parser::trimmer::count_bytes_in_runes;
+
+use std::sync::Arc;
+
+use crate::alternative::SharedField;
+use crate::builder::ColumnBuilder;
+
+/// The name of the element field of list columns.
+pub static LIST_ITEM_NAME: &str = "item";
+
+/// A builder of columns where every row holds a fixed number of elements, which are built
+/// one after the other by the element builder.
+pub struct FixedSizeListColumnBuilder {
+    elements: Box<dyn ColumnBuilder>,
+    name: String,
+    n_elements: usize,
+    is_nullable: bool,
+}
+
+impl FixedSizeListColumnBuilder {
+    /// Create a new [`FixedSizeListColumnBuilder`], where the element builder builds a single
+    /// element and is named [`LIST_ITEM_NAME`].
+    pub fn new(
+        name: String,
+        n_elements: usize,
+        is_nullable: bool,
+        elements: Box<dyn ColumnBuilder>,
+    ) -> Self {
+        Self {
+            elements,
+            name,
+            n_elements,
+            is_nullable,
+        }
+    }
+
+    /// Finish the element builder and get the elements of all rows, and their field.
+    fn finish_elements(&mut self) -> (Arc<ArrowField>, ArrayRef) {
+        let (_, values) = self.elements.finish();
+        let field = Arc::new(ArrowField::new(
+            LIST_ITEM_NAME,
+            values.data_type().clone(),
+            self.is_nullable,
+        ));
+        (field, values)
+    }
+}
+
+impl ColumnBuilder for FixedSizeListColumnBuilder {
+    fn try_build_column(&mut self, bytes: &[u8]) -> Result<usize> {
+        let mut n_bytes: usize = 0;
+        for _ in 0..self.n_elements {
+            n_bytes += self.elements.try_build_column(&bytes[n_bytes..])?;
+        }
+        Ok(n_bytes)
+    }
+
+    fn n_parse_failures(&self) -> usize {
+        self.elements.n_parse_failures()
+    }
+
+    fn finish(&mut self) -> (&str, ArrayRef) {
+        let (field, values) = self.finish_elements();
+        let array = FixedSizeListArray::new(field, self.n_elements as i32, values, None);
+        (&self.name, Arc::new(array) as ArrayRef)
+    }
+}
+
+/// A builder of columns where every row reserves space for a fixed number of elements, but
+/// only the number of elements given by another column of the row are in use, which are the
+/// only elements that are built.
+///
+/// # Note
+/// The column holding the number of elements has to be built before this column in every row,
+/// and its builder has to be wrapped in a
+/// [`DiscriminatingColumnBuilder`](crate::alternative::DiscriminatingColumnBuilder) sharing
+/// the same [`SharedField`].
+pub struct ListColumnBuilder {
+    elements: Box<dyn ColumnBuilder>,
+    name: String,
+    max_n_elements: usize,
+    /// The number of runes of a single element.
+    n_element_runes: usize,
+    is_nullable: bool,
+    count: SharedField,
+    count_parser: IntParser,
+    offsets: Vec<i32>,
+    validity: Vec<bool>,
+}
+
+impl ListColumnBuilder {
+    /// Create a new [`ListColumnBuilder`] whose number of elements per row are parsed by the
+    /// provided parser from the field shared by the column holding them. The element builder
+    /// builds a single element of `n_element_runes` runes and is named [`LIST_ITEM_NAME`].
+    pub fn new(
+        name: String,
+        max_n_elements: usize,
+        n_element_runes: usize,
+        is_nullable: bool,
+        elements: Box<dyn ColumnBuilder>,
+        count: SharedField,
+        count_parser: IntParser,
+    ) -> Self {
+        Self {
+            elements,
+            name,
+            max_n_elements,
+            n_element_runes,
+            is_nullable,
+            count,
+            count_parser,
+            offsets: vec![0],
+            validity: Vec::new(),
+        }
+    }
+
+    /// Try and parse the number of elements of the current row from the shared field, which is
+    /// `None` if the field is empty or could not be parsed.
+    ///
+    /// # Errors
+    /// If the number of elements is negative or larger than the number of elements reserved.
+    fn try_count(&self) -> Result<Option<usize>> {
+        let field = self.count.lock().unwrap();
+        // Every byte except UTF-8 continuation bytes starts a rune.
+        let n_runes: usize = field.iter().filter(|b| **b & 0xC0 != 0x80).count();
+
+        match self.count_parser.try_parse::<i64>(&field, n_runes) {
+            (_, None) => Ok(None),
+            (_, Some(n)) if (0..=self.max_n_elements as i64).contains(&n) => Ok(Some(n as usize)),
+            (_, Some(n)) => Err(Box::new(ExecutionError::new(&format!(
+                "Column '{}' has {} elements in use, but only {} elements are reserved, exiting...",
+                self.name, n, self.max_n_elements,
+            )))),
+        }
+    }
+}
+
+impl ColumnBuilder for ListColumnBuilder {
+    /// Build the elements in use of the row and skip the rest of the reserved elements. A row
+    /// without a number of elements is null if the column is nullable and empty otherwise.
+    fn try_build_column(&mut self, bytes: &[u8]) -> Result<usize> {
+        let count: Option<usize> = self.try_count()?;
+        let n_elements: usize = count.unwrap_or(0);
+
+        let mut n_bytes: usize = 0;
+        for _ in 0..n_elements {
+            n_bytes += self.elements.try_build_column(&bytes[n_bytes..])?;
+        }
+        n_bytes += count_bytes_in_runes(
+            &bytes[n_bytes..],
+            (self.max_n_elements - n_elements) * self.n_element_runes,
+        );
+
+        let offset: i32 = self.offsets.last().copied().unwrap_or(0) + n_elements as i32;
+        self.offsets.push(offset);
+        self.validity.push(count.is_some() || !self.is_nullable);
+
+        Ok(n_bytes)
+    }
+
+    fn n_parse_failures(&self) -> usize {
+        self.elements.n_parse_failures()
+    }
+
+    fn finish(&mut self) -> (&str, ArrayRef) {
+        let (_, values) = self.elements.finish();
+        let field = Arc::new(ArrowField::new(
+            LIST_ITEM_NAME,
+            values.data_type().clone(),
+            self.is_nullable,
+        ));
+
+        let offsets: Vec<i32> = std::mem::replace(&mut self.offsets, vec![0]);
+        let validity: Vec<bool> = std::mem::take(&mut self.validity);
+        let nulls: Option<NullBuffer> = match validity.iter().all(|v| *v) {
+            true => None,
+            false => Some(NullBuffer::from(validity)),
+        };
+
+        let array = ListArray::new(field, OffsetBuffer::new(offsets.into()), values, nulls);
+        (&self.name, Arc::new(array) as ArrayRef)
+    }
+}
+
+#[cfg(test)]
+mod tests_list {
+    use super::*;
+
+    use crate::alternative::DiscriminatingColumnBuilder;
+    use crate::datatype::Int32ColumnBuilder;
+    use arrow::array::{AsArray, Int32Array};
+    use arrow::datatypes::Int32Type;
+
+    use std::sync::Mutex;
+
+    fn element_builder(is_nullable: bool) -> Box<dyn ColumnBuilder> {
+        Box::new(Int32ColumnBuilder::new(
+            LIST_ITEM_NAME.to_string(),
+            2,
+            is_nullable,
+            None,
+            IntParser::new(),
+        ))
+    }
+
+    fn list_builders(is_nullable: bool) -> (DiscriminatingColumnBuilder, ListColumnBuilder) {
+        let shared: SharedField = Arc::new(Mutex::new(Vec::new()));
+        let counts = DiscriminatingColumnBuilder::new(
+            Box::new(Int32ColumnBuilder::new(
+                "n".to_string(),
+                1,
+                true,
+                None,
+                IntParser::new(),
+            )),
+            shared.clone(),
+        );
+        let list = ListColumnBuilder::new(
+            "b".to_string(),
+            3,
+            2,
+            is_nullable,
+            element_builder(is_nullable),
+            shared,
+            IntParser::new(),
+        );
+        (counts, list)
+    }
+
+    #[test]
+    fn test_build_list_columns() {
+        let mut fixed =
+            FixedSizeListColumnBuilder::new("a".to_string(), 3, true, element_builder(true));
+        assert_eq!(6, fixed.try_build_column(" 1 2 3".as_bytes()).unwrap());
+        assert_eq!(6, fixed.try_build_column(" 4 5 6".as_bytes()).unwrap());
+
+        let (_, array) = fixed.finish();
+        let array: &FixedSizeListArray = array.as_fixed_size_list();
+        assert_eq!(2, array.len());
+        assert_eq!(
+            &[4, 5, 6],
+            array.value(1).as_primitive::<Int32Type>().values().as_ref(),
+        );
+
+        let (mut counts, mut list) = list_builders(true);
+        for row in ["2 1 2 3", "  4 5 6", "3 7 8 9"] {
+            let n: usize = counts.try_build_column(row.as_bytes()).unwrap();
+            assert_eq!(6, list.try_build_column(&row.as_bytes()[n..]).unwrap());
+        }
+
+        let (_, array) = list.finish();
+        let array: &ListArray = array.as_list::<i32>();
+
+        assert_eq!(&[0, 2, 2, 5], array.value_offsets());
+        assert!(array.is_null(1));
+        assert_eq!(
+            &Int32Array::from(vec![7, 8, 9]),
+            array.value(2).as_primitive::<Int32Type>(),
+        );
+    }
+
+    #[test]
+    fn test_build_list_column_only_parses_elements_in_use() {
+        let (mut counts, mut list) = list_builders(false);
+        for row in ["2 1 2  ", "0      ", "1 3    "] {
+            let n: usize = counts.try_build_column(row.as_bytes()).unwrap();
+            assert_eq!(6, list.try_build_column(&row.as_bytes()[n..]).unwrap());
+        }
+
+        let (_, array) = list.finish();
+        let array: &ListArray = array.as_list::<i32>();
+
+        assert_eq!(&[0, 2, 2, 3], array.value_offsets());
+        assert_eq!(0, array.null_count());
+        assert_eq!(
+            &Int32Array::from(vec![1, 2, 3]),
+            array.values().as_primitive::<Int32Type>(),
+        );
+    }
+
+    #[test]
+    fn test_build_list_column_with_too_many_elements() {
+        let (mut counts, mut list) = list_builders(true);
+        let row: &str = "4 1 2 3";
+        let n: usize = counts.try_build_column(row.as_bytes()).unwrap();
+
+        let error = list.try_build_column(&row.as_bytes()[n..]).unwrap_err();
+        assert!(error.to_string().contains("only 3 elements are reserved"));
+    }
+}
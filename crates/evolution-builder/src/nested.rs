@@ -0,0 +1,138 @@
+//
+// MIT License
+//
+// Copyright (c) 2026 Firelink Data
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//
+// File created: 2026-10-18
+// Last updated: 2026-10-18
+//
+
+use arrow::array::{ArrayRef, StructArray};
+use arrow::datatypes::{Field as ArrowField, Fields as ArrowFields};
+
+use std::sync::Arc;
+
+use crate::builder::{ColumnBuilder, ColumnBuilderRef};
+
+/// A builder of struct columns, where the consecutive fields of the struct are built one
+/// after the other by their own column builders.
+pub struct StructColumnBuilder {
+    fields: Vec<ColumnBuilderRef>,
+    name: String,
+    /// Whether or not each of the fields can contain null values.
+    fields_nullable: Vec<bool>,
+}
+
+impl StructColumnBuilder {
+    /// Create a new [`StructColumnBuilder`] from the builders of its fields, and whether or
+    /// not each of the fields can contain null values.
+    pub fn new(name: String, fields: Vec<ColumnBuilderRef>, fields_nullable: Vec<bool>) -> Self {
+        Self {
+            fields,
+            name,
+            fields_nullable,
+        }
+    }
+}
+
+impl ColumnBuilder for StructColumnBuilder {
+    fn try_build_column(&mut self, bytes: &[u8]) -> evolution_common::error::Result<usize> {
+        let mut n_bytes: usize = 0;
+        for field in self.fields.iter_mut() {
+            n_bytes += field.try_build_column(&bytes[n_bytes..])?;
+        }
+        Ok(n_bytes)
+    }
+
+    fn n_parse_failures(&self) -> usize {
+        self.fields.iter().map(|f| f.n_parse_failures()).sum()
+    }
+
+    fn finish(&mut self) -> (&str, ArrayRef) {
+        let (fields, arrays): (Vec<ArrowField>, Vec<ArrayRef>) = self
+            .fields
+            .iter_mut()
+            .zip(self.fields_nullable.iter())
+            .map(|(f, is_nullable)| {
+                let (name, array) = f.finish();
+                (
+                    ArrowField::new(name, array.data_type().clone(), *is_nullable),
+                    array,
+                )
+            })
+            .unzip();
+
+        let array = StructArray::new(ArrowFields::from(fields), arrays, None);
+        (&self.name, Arc::new(array) as ArrayRef)
+    }
+}
+
+#[cfg(test)]
+mod tests_nested {
+    use super::*;
+
+    use crate::datatype::{Int32ColumnBuilder, Utf8ColumnBuilder};
+    use arrow::array::{Array, AsArray};
+    use arrow::datatypes::Int32Type;
+    use evolution_parser::datatype::{IntParser, Utf8Parser};
+    use padder::{Alignment, Symbol};
+
+    #[test]
+    fn test_build_struct_column() {
+        let mut builder = StructColumnBuilder::new(
+            "address".to_string(),
+            vec![
+                Box::new(Utf8ColumnBuilder::new(
+                    "city".to_string(),
+                    6,
+                    false,
+                    None,
+                    Utf8Parser::new(Alignment::Left, Symbol::Whitespace),
+                )),
+                Box::new(Int32ColumnBuilder::new(
+                    "zip".to_string(),
+                    5,
+                    true,
+                    None,
+                    IntParser::new(),
+                )),
+            ],
+            vec![false, true],
+        );
+
+        assert_eq!(
+            12,
+            builder.try_build_column("Malmö 21120".as_bytes()).unwrap()
+        );
+        assert_eq!(
+            12,
+            builder.try_build_column("Umeå  90325".as_bytes()).unwrap()
+        );
+
+        let (name, array) = builder.finish();
+        let array: &StructArray = array.as_struct();
+
+        assert_eq!("address", name);
+        assert_eq!(2, array.len());
+        assert_eq!("Umeå", array.column(0).as_string::<i32>().value(1));
+        assert_eq!(21120, array.column(1).as_primitive::<Int32Type>().value(0));
+    }
+}
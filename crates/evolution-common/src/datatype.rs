@@ -22,7 +22,7 @@
 // SOFTWARE.
 //
 // File created: 2024-05-10
-// Last updated: 2024-05-24
+// Last updated: 2026-10-18
 //
 
 use serde::{Deserialize, Serialize};
@@ -43,4 +43,25 @@ pub enum DataType {
     Int64,
     Utf8,
     LargeUtf8,
+    /// A date, which is read in the format of the column.
+    Date32,
+    /// A decimal number, with the precision and scale of the column.
+    Decimal128,
+    /// A group of consecutive fields, which are described by the fields of the column.
+    Struct,
+}
+
+/// How the sign of a number is written, when it is not written as a leading `-`.
+///
+/// The overpunched formats encode the sign in the zone of the first or last digit, as in COBOL
+/// display fields without a separate sign, where `{` and `A` to `I` are the positive digits 0 to
+/// 9, and `}` and `J` to `R` are the negative digits 0 to 9, e.g., `1234J` is `-12341`.
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Serialize)]
+pub enum SignFormat {
+    /// The sign is overpunched on the first digit.
+    LeadingOverpunch,
+    /// The sign is overpunched on the last digit.
+    TrailingOverpunch,
+    /// The sign is a separate `+` or `-` after the last digit.
+    TrailingSeparate,
 }
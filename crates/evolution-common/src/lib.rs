@@ -22,7 +22,7 @@
 // SOFTWARE.
 //
 // File created: 2024-05-24
-// Last updated: 2024-05-27
+// Last updated: 2026-10-18
 //
 
 /// Get the number of bytes representing a newline character on a Windows system.
@@ -45,6 +45,7 @@ pub fn newline<'a>() -> &'a str {
     "\n"
 }
 
+pub mod cancellation;
 pub mod datatype;
 pub mod error;
 pub mod thread;
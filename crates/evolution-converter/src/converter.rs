@@ -22,23 +22,23 @@
 // SOFTWARE.
 //
 // File created: 2024-02-17
-// Last updated: 2024-10-14
+// Last updated: 2026-10-18
 //
 
 use arrow::datatypes::SchemaRef as ArrowSchemaRef;
 use bytesize::ByteSize;
-use crossbeam::channel;
-use crossbeam::thread::scope;
-use crossbeam::thread::ScopedJoinHandle;
 use parquet::file::properties::WriterProperties as ArrowWriterProperties;
 
+use arrow::array::RecordBatch;
 use evolution_builder::builder::ParquetBuilder;
+use evolution_common::cancellation::CancellationToken;
 use evolution_common::error::{ExecutionError, Result, SetupError};
 use evolution_common::thread::estimate_best_thread_channel_capacity;
 use evolution_common::NUM_BYTES_FOR_NEWLINE;
 use evolution_schema::schema::FixedSchema;
 use evolution_slicer::slicer::{FileSlicer, Slicer};
 use evolution_writer::parquet::ParquetWriter;
+use parquet::format::FileMetaData;
 
 #[cfg(debug_assertions)]
 use log::debug;
@@ -46,7 +46,13 @@ use log::info;
 
 use std::path::PathBuf;
 use std::sync::Arc;
-use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::envelope::{ControlTotals, Envelope};
+use crate::filter::RowFilter;
+use crate::progress::{Progress, ProgressObserverRef};
+use crate::report::ConversionReport;
+use crate::worker::try_build_and_write_in_order;
 
 /// /// Unified trait for all types of converters.
 pub trait Converter {}
@@ -58,12 +64,28 @@ pub struct ParquetConverter {
     writer: ParquetWriter,
     builder: ParquetBuilder,
     schema: FixedSchema,
+    // The sorted indices of the columns to convert, all columns are converted if `None`.
+    projection: Option<Vec<usize>>,
+    // The predicate which rows have to satisfy to be written, if any.
+    filter: Option<RowFilter>,
+    // The indices of the built columns to write, if only some of them should be written.
+    output_projection: Option<Vec<usize>>,
+    // The control totals declared by the header and trailer records, if any.
+    control_totals: Option<ControlTotals>,
     // The size of the buffer that reads the input file (in bytes).
     read_buffer_size: usize,
     // The number of threads to use when converting.
     n_threads: usize,
     // The maximum number of active messages allowed in the thread channels.
     thread_channel_capacity: usize,
+    // The summary of the conversion, updated as the conversion progresses.
+    report: ConversionReport,
+    // The path to write the json summary of the conversion to, if any.
+    report_path: Option<PathBuf>,
+    // The observer to notify about the progress of the conversion, if any.
+    progress_observer: Option<ProgressObserverRef>,
+    // The token used to request that the conversion stops, if any.
+    cancellation_token: Option<CancellationToken>,
 }
 
 impl ParquetConverter {
@@ -74,7 +96,23 @@ impl ParquetConverter {
         }
     }
 
-    /// Try and convert the provided fixed-length file to parquet output format.
+    /// Create a new [`ParquetBuilder`] from the schema, which only builds the projected
+    /// columns if a projection was set.
+    fn new_builder(&self) -> ParquetBuilder {
+        match &self.projection {
+            // The projection was validated when the converter was built, so this can't fail.
+            Some(projection) => self
+                .schema
+                .clone()
+                .try_into_projected_builder(projection)
+                .unwrap(),
+            None => self.schema.clone().into_builder::<ParquetBuilder>(),
+        }
+    }
+
+    /// Try and convert the provided fixed-length file to parquet output format, returning
+    /// a [`ConversionReport`] summarizing the conversion. If a report path was set, then the
+    /// report is also written as json to that path.
     ///
     /// # Errors
     /// This function will propagate any errors created in any of the conversion modes, see any
@@ -82,20 +120,34 @@ impl ParquetConverter {
     ///
     /// [`try_convert_multithreaded`]: ParquetConverter::try_convert_multithreaded
     /// [`try_convert_single_threaded`]: ParquetConverter::try_convert_single_threaded
-    pub fn try_convert(&mut self) -> Result<()> {
-        if self.n_threads > 1 {
-            self.try_convert_multithreaded()?;
+    pub fn try_convert(&mut self) -> Result<ConversionReport> {
+        let report: ConversionReport = if self.n_threads > 1 {
+            self.try_convert_multithreaded()?
         } else {
-            self.try_convert_single_threaded()?;
+            self.try_convert_single_threaded()?
+        };
+
+        info!(
+            "Converted {} records to {} rows in {} row groups ({:.2} MB/s).",
+            report.record_count(),
+            report.output_rows(),
+            report.row_groups(),
+            report.bytes_per_second() / 1_000_000.0,
+        );
+
+        if let Some(path) = &self.report_path {
+            info!("Writing conversion report to {:?}.", path);
+            report.try_write_to_path(path.to_path_buf())?;
         }
-        Ok(())
+
+        Ok(report)
     }
 
     /// Try and convert the target file to parquet format in multithreaded mode.
     ///
     /// # Panics
-    /// The multithreading is implemented using [`crossbeam`] scoped threads, and we don't currently support returning
-    /// anything from the scope, so any function called inside the scope that returns a [`Result`] has to be unwrapped.
+    /// The multithreading is implemented using [`crossbeam`] scoped threads, which communicate through channels, so
+    /// this function panics if a thread is not able to communicate through a channel due to it being disconnected.
     ///
     /// # Errors
     /// This function might return an error for the following reasons:
@@ -104,7 +156,12 @@ impl ParquetConverter {
     /// * If the buffer did not contain any line-break characters at all.
     /// * If any of the threading operations returned an Error during the conversion.
     /// * If the [`ParquetWriter`] failed flushing all of the RowGroups to file.
-    pub fn try_convert_multithreaded(&mut self) -> Result<()> {
+    /// * If cancellation was requested on the [`CancellationToken`], in which case the output
+    ///   file is left incomplete.
+    /// * If the control totals of the header and trailer records do not match the converted
+    ///   records, in which case the output file is left incomplete.
+    pub fn try_convert_multithreaded(&mut self) -> Result<ConversionReport> {
+        let start: Instant = Instant::now();
         let mut buffer_capacity = self.read_buffer_size;
         let n_worker_threads: usize = self.n_threads - 1;
         let mut ratio_processed: f32;
@@ -123,6 +180,8 @@ impl ParquetConverter {
                 break;
             }
 
+            self.try_check_cancelled()?;
+
             let mut remaining_bytes: usize = self.slicer.remaining_bytes();
             let mut bytes_processed: usize = self.slicer.bytes_processed();
             let mut bytes_overlapped: usize = self.slicer.bytes_overlapped();
@@ -131,11 +190,19 @@ impl ParquetConverter {
                 buffer_capacity = remaining_bytes;
             }
 
+            let now: Instant = Instant::now();
             let mut buffer: Vec<u8> = vec![0u8; buffer_capacity];
             self.slicer.try_read_to_buffer(&mut buffer)?;
             self.slicer
                 .try_distribute_buffer_chunks_on_workers(&buffer, &mut thread_workloads)?;
-            self.spawn_converter_threads(&buffer, &thread_workloads)?;
+            self.report.add_read_time(now.elapsed());
+
+            let now: Instant = Instant::now();
+            let write_time_before: Duration = self.report.timings().write();
+            self.spawn_converter_threads(&buffer, &thread_workloads, start)?;
+            let write_time: Duration = self.report.timings().write() - write_time_before;
+            self.report
+                .add_convert_time(now.elapsed().saturating_sub(write_time));
 
             let n_bytes_left_after_last_line_break: usize =
                 buffer_capacity - thread_workloads[n_worker_threads - 1].1 - NUM_BYTES_FOR_NEWLINE;
@@ -155,11 +222,23 @@ impl ParquetConverter {
             thread_workloads.clear();
 
             info!("Estimated progress: {:.2}%", ratio_processed);
+            Self::notify_progress(
+                self.progress_observer.as_ref(),
+                &self.slicer,
+                &self.report,
+                bytes_processed,
+                start,
+            );
         }
 
+        self.try_validate_control_totals()?;
+
         #[cfg(debug_assertions)]
         debug!("Finishing and closing writer.");
-        self.writer.try_finish()?;
+        let now: Instant = Instant::now();
+        let metadata: FileMetaData = self.writer.try_finish()?;
+        self.report
+            .complete(&metadata, now.elapsed(), start.elapsed());
 
         info!("Done converting flf to parquet in multithreaded mode!");
 
@@ -170,7 +249,7 @@ impl ParquetConverter {
             );
         }
 
-        Ok(())
+        Ok(self.report.clone())
     }
 
     /// Try and convert the target file to parquet format in single-threaded mode.
@@ -181,7 +260,12 @@ impl ParquetConverter {
     /// * If the buffer was empty when trying to find line-breaks in it.
     /// * If the buffer did not contain any line-break characters at all.
     /// * If the [`ParquetWriter`] failed flushing all of the RowGroups to file.
-    pub fn try_convert_single_threaded(&mut self) -> Result<()> {
+    /// * If cancellation was requested on the [`CancellationToken`], in which case the output
+    ///   file is left incomplete.
+    /// * If the control totals of the header and trailer records do not match the converted
+    ///   records, in which case the output file is left incomplete.
+    pub fn try_convert_single_threaded(&mut self) -> Result<ConversionReport> {
+        let start: Instant = Instant::now();
         let mut buffer_capacity: usize = self.read_buffer_size;
 
         info!("Converting flf to parquet in single-threaded mode.");
@@ -195,6 +279,8 @@ impl ParquetConverter {
                 break;
             }
 
+            self.try_check_cancelled()?;
+
             let mut remaining_bytes: usize = self.slicer.remaining_bytes();
             let mut bytes_processed: usize = self.slicer.bytes_processed();
             let mut bytes_overlapped: usize = self.slicer.bytes_overlapped();
@@ -210,15 +296,30 @@ impl ParquetConverter {
             // way to do this, because it really bothers me!!!!!
             //
             // But maybe this is not too bad, the allocations don't take that long compared to the I/O...
+            let now: Instant = Instant::now();
             let mut buffer: Vec<u8> = vec![0u8; buffer_capacity];
             self.slicer.try_read_to_buffer(&mut buffer)?;
+            self.report.add_read_time(now.elapsed());
 
             let byte_idx_last_line_break: usize = self.slicer.try_find_last_line_break(&buffer)?;
             let n_bytes_left_after_last_line_break: usize =
                 buffer_capacity - byte_idx_last_line_break - NUM_BYTES_FOR_NEWLINE;
 
-            self.builder.try_build_from_slice(&buffer)?;
-            self.writer.try_write_from_builder(&mut self.builder)?;
+            let now: Instant = Instant::now();
+            // Only build the complete rows, the bytes after the last line break are read again.
+            self.builder.try_build_from_slice(
+                &buffer[..buffer_capacity - n_bytes_left_after_last_line_break],
+            )?;
+            self.report.add_convert_time(now.elapsed());
+
+            Self::try_write_from_builder(
+                &mut self.writer,
+                &mut self.report,
+                self.filter.as_ref(),
+                self.output_projection.as_deref(),
+                self.control_totals.as_mut(),
+                &mut self.builder,
+            )?;
 
             self.slicer
                 .try_seek_relative(-(n_bytes_left_after_last_line_break as i64))?;
@@ -230,9 +331,22 @@ impl ParquetConverter {
             self.slicer.set_remaining_bytes(remaining_bytes);
             self.slicer.set_bytes_processed(bytes_processed);
             self.slicer.set_bytes_overlapped(bytes_overlapped);
+
+            Self::notify_progress(
+                self.progress_observer.as_ref(),
+                &self.slicer,
+                &self.report,
+                bytes_processed,
+                start,
+            );
         }
 
-        self.writer.try_finish()?;
+        self.try_validate_control_totals()?;
+
+        let now: Instant = Instant::now();
+        let metadata: FileMetaData = self.writer.try_finish()?;
+        self.report
+            .complete(&metadata, now.elapsed(), start.elapsed());
 
         info!("Done converting flf to parquet in single-threaded mode!");
 
@@ -243,6 +357,100 @@ impl ParquetConverter {
             );
         }
 
+        Ok(self.report.clone())
+    }
+
+    /// Notify the [`ProgressObserver`], if any, about the progress of the conversion.
+    ///
+    /// [`ProgressObserver`]: crate::progress::ProgressObserver
+    fn notify_progress(
+        observer: Option<&ProgressObserverRef>,
+        slicer: &FileSlicer,
+        report: &ConversionReport,
+        bytes_processed: usize,
+        start: Instant,
+    ) {
+        if let Some(observer) = observer {
+            observer.on_progress(&Progress::new(
+                bytes_processed,
+                slicer.bytes_to_read(),
+                report.record_count(),
+                start.elapsed(),
+            ));
+        }
+    }
+
+    /// Try and validate the control totals of the header and trailer records, if any, against
+    /// the converted records. This is done before the writer is finished, so that the output
+    /// file is never completed when they do not match.
+    ///
+    /// # Errors
+    /// If the declared number of records, or any of the declared sums, does not match.
+    fn try_validate_control_totals(&self) -> Result<()> {
+        if let Some(control_totals) = &self.control_totals {
+            control_totals.try_validate(self.report.record_count())?;
+            info!("The control totals of the header and trailer records match.");
+        }
+        Ok(())
+    }
+
+    /// Get whether or not cancellation has been requested on the [`CancellationToken`], if any.
+    fn is_cancelled(&self) -> bool {
+        self.cancellation_token
+            .as_ref()
+            .is_some_and(|t| t.is_cancelled())
+    }
+
+    /// Try and check whether the conversion should stop.
+    ///
+    /// # Errors
+    /// If cancellation has been requested on the [`CancellationToken`].
+    fn try_check_cancelled(&self) -> Result<()> {
+        if self.is_cancelled() {
+            return Err(Box::new(ExecutionError::new(
+                "Conversion was cancelled, exiting...",
+            )));
+        }
+        Ok(())
+    }
+
+    /// Try and finish the column builders of the [`ParquetBuilder`], update the [`ConversionReport`]
+    /// with the built [`RecordBatch`], and then write it with the [`ParquetWriter`]. If a
+    /// [`RowFilter`] is provided, then only the rows satisfying it are written, and if an output
+    /// projection is provided, then only the built columns with those indices are written. If
+    /// [`ControlTotals`] are provided, then they are accumulated from all built rows.
+    ///
+    /// # Errors
+    /// If the [`RecordBatch`] could not be created from the column builders, if the
+    /// [`RowFilter`] could not be evaluated, or if the [`ParquetWriter`] failed to write it.
+    fn try_write_from_builder(
+        writer: &mut ParquetWriter,
+        report: &mut ConversionReport,
+        filter: Option<&RowFilter>,
+        output_projection: Option<&[usize]>,
+        control_totals: Option<&mut ControlTotals>,
+        builder: &mut ParquetBuilder,
+    ) -> Result<()> {
+        let n_parse_failures: Vec<usize> = builder.n_parse_failures();
+        let mut record_batch: RecordBatch = builder.try_finish()?;
+        report.update_from_batch(&record_batch, &n_parse_failures);
+
+        if let Some(control_totals) = control_totals {
+            control_totals.try_update(&record_batch)?;
+        }
+
+        if let Some(filter) = filter {
+            record_batch = filter.try_filter(&record_batch)?;
+        }
+
+        if let Some(indices) = output_projection {
+            record_batch = record_batch.project(indices)?;
+        }
+
+        let now: Instant = Instant::now();
+        writer.try_write_batch(&record_batch)?;
+        report.add_write_time(now.elapsed());
+
         Ok(())
     }
 
@@ -253,64 +461,63 @@ impl ParquetConverter {
     /// The threading is implemented using [`crossbeam`] and might perform differently depending on host system.
     ///
     /// # Panics
-    /// This function will panic and terminate execution for the following reasons:
-    /// * If the [`ParquetBuilder`] was unable to parse a column to its expected format.
-    /// * If a thread not being able to communicate through the channel due to it being disconnected.
-    /// * If the [`ParquetWriter`] could not write the parsed columns as a [`RecordBatch`].
-    /// * If any worker thread could not join the main thread from its handle.
+    /// If a thread not being able to communicate through the channel due to it being disconnected.
     ///
     /// # Errors
-    /// If and only if the thread scope closure returned an error, which will propagate an [`ExecutionError`].
+    /// If the thread scope closure returned an error, which will propagate an [`ExecutionError`],
+    /// if a [`ParquetBuilder`] was unable to parse a column to its expected format, if the
+    /// [`ParquetWriter`] could not write the parsed columns as a [`RecordBatch`], or if
+    /// cancellation was requested on the [`CancellationToken`] while the threads were working.
     ///
     /// [`RecordBatch`]: arrow::array::RecordBatch
     fn spawn_converter_threads(
         &mut self,
-        buffer: &Vec<u8>,
+        buffer: &[u8],
         thread_workloads: &[(usize, usize)],
+        start: Instant,
     ) -> Result<()> {
-        let (sender, receiver) = channel::bounded(self.thread_channel_capacity);
-        let arc_buffer: Arc<&Vec<u8>> = Arc::new(buffer);
-
-        let thread_result: thread::Result<()> = scope(|s| {
-            let threads = thread_workloads
-                .iter()
-                .map(|(from, to)| {
-                    let t_sender: channel::Sender<ParquetBuilder> = sender.clone();
-                    // Can we do this in another way? So we don't have to allocate a bunch of stuff in our loop...
-                    // TODO: pull this out and create them as part of the ParquetConverter struct?..
-                    let mut t_builder: ParquetBuilder = self.schema.clone().into_builder();
-
-                    let t_buffer: Arc<&Vec<u8>> = arc_buffer.clone();
-                    let t_buffer_slice: &[u8] = &t_buffer[*from..*to];
-
-                    s.spawn(move |_| {
-                        t_builder.try_build_from_slice(t_buffer_slice).unwrap();
-                        t_sender.send(t_builder).unwrap();
-                        drop(t_sender);
-                    })
-                })
-                .collect::<Vec<ScopedJoinHandle<()>>>();
-
-            drop(sender);
-            for mut builder in receiver {
-                self.writer.try_write_from_builder(&mut builder).unwrap();
-                drop(builder);
-            }
-
-            for handle in threads {
-                handle.join().expect("Could not join worker thread handle!");
-            }
-        });
-
-        if thread_result.is_err() {
-            return Err(Box::new(ExecutionError::new(
-                format!(
-                    "One of the scoped threads returned an error: {:?}",
-                    thread_result
-                )
-                .as_str(),
-            )));
-        }
+        // Can we do this in another way? So we don't have to allocate a bunch of stuff in our loop...
+        // TODO: pull this out and create them as part of the ParquetConverter struct?..
+        let builders: Vec<ParquetBuilder> = thread_workloads
+            .iter()
+            .map(|_| {
+                let mut t_builder: ParquetBuilder = self.new_builder();
+                if let Some(token) = &self.cancellation_token {
+                    t_builder.set_cancellation_token(token.clone());
+                }
+                t_builder
+            })
+            .collect::<Vec<ParquetBuilder>>();
+
+        let mut bytes_processed: usize = self.slicer.bytes_processed();
+        try_build_and_write_in_order(
+            buffer,
+            thread_workloads,
+            builders,
+            self.thread_channel_capacity,
+            |mut builder, n_bytes| {
+                Self::try_write_from_builder(
+                    &mut self.writer,
+                    &mut self.report,
+                    self.filter.as_ref(),
+                    self.output_projection.as_deref(),
+                    self.control_totals.as_mut(),
+                    &mut builder,
+                )?;
+
+                bytes_processed += n_bytes;
+                Self::notify_progress(
+                    self.progress_observer.as_ref(),
+                    &self.slicer,
+                    &self.report,
+                    bytes_processed,
+                    start,
+                );
+                Ok(())
+            },
+        )?;
+
+        self.try_check_cancelled()?;
 
         #[cfg(debug_assertions)]
         debug!("Buffer chunk done!");
@@ -331,6 +538,11 @@ pub struct ParquetConverterBuilder {
     read_buffer_size: Option<usize>,
     thread_channel_capacity: Option<usize>,
     write_properties: Option<ArrowWriterProperties>,
+    report_path: Option<PathBuf>,
+    progress_observer: Option<ProgressObserverRef>,
+    cancellation_token: Option<CancellationToken>,
+    columns: Option<Vec<String>>,
+    filter: Option<String>,
 }
 
 impl ParquetConverterBuilder {
@@ -370,6 +582,42 @@ impl ParquetConverterBuilder {
         self
     }
 
+    /// Set the relative or absolute path to write a json summary of the conversion to.
+    pub fn with_report_file(mut self, report_path: Option<PathBuf>) -> Self {
+        self.report_path = report_path;
+        self
+    }
+
+    /// Set the observer to notify about the progress of the conversion. The observer is
+    /// notified after each chunk of the input file, and after each worker thread is done.
+    pub fn with_progress_observer(mut self, observer: ProgressObserverRef) -> Self {
+        self.progress_observer = Some(observer);
+        self
+    }
+
+    /// Set the token used to request that the conversion stops. The token is checked before
+    /// reading each chunk of the input file, and periodically by each of the worker threads.
+    pub fn with_cancellation_token(mut self, token: CancellationToken) -> Self {
+        self.cancellation_token = Some(token);
+        self
+    }
+
+    /// Set the names of the columns to convert. The fields of all other columns are skipped
+    /// without being parsed, and the output contains the selected columns in the order in
+    /// which they appear in the schema. All columns are converted if `None`.
+    pub fn with_columns(mut self, columns: Option<Vec<String>>) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    /// Set the filter expression which rows have to satisfy to be written, e.g.,
+    /// `country = 'SE' AND amount > 0`, see [`RowFilter`] for the supported syntax. All
+    /// rows are written if `None`.
+    pub fn with_filter(mut self, filter: Option<String>) -> Self {
+        self.filter = filter;
+        self
+    }
+
     /// Set the maximum message capacity on the multithreaded converter thread channels.
     /// See https://docs.rs/crossbeam/latest/crossbeam/channel/fn.bounded.html for specifics.
     pub fn with_thread_channel_capacity(mut self, capacity: Option<usize>) -> Self {
@@ -401,6 +649,7 @@ impl ParquetConverterBuilder {
     ///
     /// [`SerializedFileWriter`]: parquet::file::writer::SerializedFileWriter
     pub fn try_build(self) -> Result<ParquetConverter> {
+        let start: Instant = Instant::now();
         let in_file: PathBuf = self.in_path.ok_or_else(|| {
             Box::new(SetupError::new(
                 "Required field 'in_path' was not provided, exiting...",
@@ -435,28 +684,462 @@ impl ParquetConverterBuilder {
             .thread_channel_capacity
             .unwrap_or(estimate_best_thread_channel_capacity(n_threads));
 
-        let slicer: FileSlicer = FileSlicer::try_from_path(in_file)?;
+        let mut slicer: FileSlicer = FileSlicer::try_from_path(in_file)?;
         let schema: FixedSchema = FixedSchema::from_path(schema_path)?;
 
+        // Split off the header and trailer records before slicing, so that only body records
+        // are read by the slicer.
+        let envelope: Envelope = Envelope::try_read(&mut slicer, &schema)?;
+        let control_totals: Option<ControlTotals> = ControlTotals::try_new(&schema, &envelope)?;
+
+        let filter: Option<RowFilter> = match &self.filter {
+            Some(expression) => Some(RowFilter::try_parse(expression)?),
+            None => None,
+        };
+
+        let selection: Option<Vec<usize>> = match &self.columns {
+            Some(names) => {
+                let mut indices: Vec<usize> = schema.try_column_indices(names)?;
+                indices.sort_unstable();
+                indices.dedup();
+                Some(indices)
+            }
+            None => None,
+        };
+
+        // The columns used by the filter and the control sums, and the columns that list columns
+        // and alternatives depend on, also have to be built, even if they are not selected, in
+        // which case they are removed again before writing.
+        let mut required: Vec<String> = Vec::new();
+        if let Some(filter) = &filter {
+            required.extend(filter.column_names());
+        }
+        if let Some(control_totals) = &control_totals {
+            required.extend(control_totals.column_names());
+        }
+        let required: Vec<usize> = schema.try_column_indices(&required)?;
+
+        let projection: Option<Vec<usize>> = match &selection {
+            Some(selected) => {
+                let mut indices: Vec<usize> = selected.clone();
+                indices.extend(required);
+                let dependencies: Vec<String> = schema.dependency_column_names(&indices);
+                indices.extend(schema.try_column_indices(&dependencies)?);
+                indices.sort_unstable();
+                indices.dedup();
+                Some(indices)
+            }
+            None => None,
+        };
+
+        let output_projection: Option<Vec<usize>> = match (&selection, &projection) {
+            (Some(selected), Some(indices)) if selected.len() < indices.len() => Some(
+                selected
+                    .iter()
+                    .map(|idx| indices.binary_search(idx).unwrap())
+                    .collect::<Vec<usize>>(),
+            ),
+            _ => None,
+        };
+
+        // The filter is evaluated against the built columns, so check that it can be before
+        // the output file is created.
+        if let Some(filter) = &filter {
+            let built_schema: ArrowSchemaRef = match &projection {
+                Some(indices) => Arc::new(schema.clone().try_into_projected_arrow_schema(indices)?),
+                None => Arc::new(schema.clone().into_arrow_schema()),
+            };
+            filter.try_validate(built_schema)?;
+        }
+
         // Here it is okay to clone the entire struct, since this is not executed
         // during any heavy workload, and should only happen during setup.
-        let builder: ParquetBuilder = schema.clone().into_builder::<ParquetBuilder>();
-        let arrow_schema: ArrowSchemaRef = Arc::new(schema.clone().into_arrow_schema());
+        let mut builder: ParquetBuilder = match &projection {
+            Some(indices) => schema.clone().try_into_projected_builder(indices)?,
+            None => schema.clone().into_builder::<ParquetBuilder>(),
+        };
+        let arrow_schema: ArrowSchemaRef = match &selection {
+            Some(selected) => Arc::new(schema.clone().try_into_projected_arrow_schema(selected)?),
+            None => Arc::new(schema.clone().into_arrow_schema()),
+        };
+        if let Some(token) = &self.cancellation_token {
+            builder.set_cancellation_token(token.clone());
+        }
 
-        let writer: ParquetWriter = ParquetWriter::builder()
+        let mut writer: ParquetWriter = ParquetWriter::builder()
             .with_out_path(out_path)
             .with_arrow_schema(arrow_schema)
             .with_properties(self.write_properties)
             .try_build()?;
+        for (key, value) in envelope.key_value_metadata() {
+            writer.append_key_value_metadata(key, value);
+        }
+
+        let mut report: ConversionReport = ConversionReport::new(&schema, slicer.bytes_to_read());
+        if let Some(indices) = &projection {
+            report.retain_columns(indices);
+        }
+        report.set_setup_time(start.elapsed());
 
         Ok(ParquetConverter {
             slicer,
             writer,
             builder,
             schema,
+            projection,
+            filter,
+            output_projection,
+            control_totals,
             read_buffer_size,
             n_threads,
             thread_channel_capacity,
+            report,
+            report_path: self.report_path,
+            progress_observer: self.progress_observer,
+            cancellation_token: self.cancellation_token,
         })
     }
 }
+
+#[cfg(test)]
+mod tests_converter {
+    use super::*;
+    use crate::progress::ProgressObserver;
+    use crate::report::ColumnReport;
+    use arrow::array::{Array, Int64Array, RecordBatchReader, StringArray};
+    use arrow::compute::concat_batches;
+    use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+    use std::fs::{self, File};
+    use std::path::Path;
+    use std::sync::Mutex;
+
+    fn schema_path() -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("res/test_converter_schema.json")
+    }
+
+    fn envelope_schema_path() -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("../evolution-schema/res/test_valid_envelope_schema.json")
+    }
+
+    /// Write a fixed-length file of the test schema, where the id of each row is its index.
+    fn write_rows(name: &str, n_rows: usize) -> PathBuf {
+        let path: PathBuf = std::env::temp_dir().join(name);
+        let rows: String = (0..n_rows)
+            .map(|i| {
+                format!(
+                    "{:>10}{:<12}{:>8}\n",
+                    i,
+                    format!("name{}", i % 97),
+                    i % 1000
+                )
+            })
+            .collect();
+        fs::write(&path, rows).unwrap();
+        path
+    }
+
+    /// Convert the file with a small read buffer, so that every thread gets several
+    /// workloads, and read back the converted rows.
+    fn convert(
+        in_path: &Path,
+        n_threads: usize,
+        configure: impl FnOnce(ParquetConverterBuilder) -> ParquetConverterBuilder,
+    ) -> Result<(ConversionReport, RecordBatch)> {
+        let out_path: PathBuf = in_path.with_extension(format!("{}.parquet", n_threads));
+        if out_path.exists() {
+            fs::remove_file(&out_path)?;
+        }
+
+        let builder: ParquetConverterBuilder = ParquetConverter::builder()
+            .with_in_file(in_path.to_path_buf())
+            .with_schema(schema_path())
+            .with_out_file(out_path.clone())
+            .with_num_threads(n_threads)
+            .with_read_buffer_size(64 * 1024)
+            .with_thread_channel_capacity(Some(n_threads));
+        let result: Result<ConversionReport> = configure(builder)
+            .try_build()
+            .and_then(|mut c| c.try_convert());
+
+        let converted: Result<(ConversionReport, RecordBatch)> = result.and_then(|report| {
+            let reader =
+                ParquetRecordBatchReaderBuilder::try_new(File::open(&out_path)?)?.build()?;
+            let schema = reader.schema();
+            let batches: Vec<RecordBatch> = reader.collect::<std::result::Result<_, _>>()?;
+            Ok((report, concat_batches(&schema, &batches)?))
+        });
+        if out_path.exists() {
+            fs::remove_file(&out_path)?;
+        }
+        converted
+    }
+
+    fn field_names(batch: &RecordBatch) -> Vec<String> {
+        batch
+            .schema()
+            .fields()
+            .iter()
+            .map(|f| f.name().to_string())
+            .collect()
+    }
+
+    fn ids(batch: &RecordBatch) -> Vec<i64> {
+        batch
+            .column_by_name("id")
+            .unwrap()
+            .as_any()
+            .downcast_ref::<Int64Array>()
+            .unwrap()
+            .values()
+            .to_vec()
+    }
+
+    #[test]
+    fn test_convert_keeps_row_order() {
+        let n_rows: usize = 20_000;
+        let in_path: PathBuf = write_rows("evolution_test_converter_order.flf", n_rows);
+
+        for n_threads in [1, 2, 4] {
+            let (_, batch) = convert(&in_path, n_threads, |b| b).unwrap();
+            assert_eq!((0..n_rows as i64).collect::<Vec<i64>>(), ids(&batch));
+        }
+
+        fs::remove_file(in_path).unwrap();
+    }
+
+    #[test]
+    fn test_convert_projected_columns() {
+        let n_rows: usize = 5_000;
+        let in_path: PathBuf = write_rows("evolution_test_converter_projection.flf", n_rows);
+
+        for n_threads in [1, 3] {
+            // The filter column is built to evaluate the filter, but is not written.
+            let (report, batch) = convert(&in_path, n_threads, |b| {
+                b.with_columns(Some(vec!["name".to_string()]))
+                    .with_filter(Some("amount > 500".to_string()))
+            })
+            .unwrap();
+
+            let expected: Vec<String> = (0..n_rows)
+                .filter(|i| i % 1000 > 500)
+                .map(|i| format!("name{}", i % 97))
+                .collect();
+            let names: Vec<String> = batch
+                .column(0)
+                .as_any()
+                .downcast_ref::<StringArray>()
+                .unwrap()
+                .iter()
+                .map(|v| v.unwrap().to_string())
+                .collect();
+
+            assert_eq!(vec!["name"], field_names(&batch));
+            assert_eq!(expected, names);
+            assert_eq!(n_rows, report.record_count());
+            assert_eq!(expected.len(), report.output_rows());
+        }
+
+        fs::remove_file(in_path).unwrap();
+    }
+
+    #[test]
+    fn test_convert_projected_columns_with_control_totals() {
+        let in_path: PathBuf = std::env::temp_dir().join("evolution_test_converter_envelope.flf");
+        fs::write(
+            &in_path,
+            "SKIPPED\n20261018ACME      \n   1SE   100\n   2NO   -40\n00000002        60\n",
+        )
+        .unwrap();
+
+        for n_threads in [1, 2] {
+            // The control sum column is built to validate the trailer, but is not written.
+            let (report, batch) = convert(&in_path, n_threads, |b| {
+                b.with_schema(envelope_schema_path())
+                    .with_columns(Some(vec!["country".to_string()]))
+            })
+            .unwrap();
+
+            assert_eq!(vec!["country"], field_names(&batch));
+            assert_eq!(2, batch.num_rows());
+            assert_eq!(2, report.output_rows());
+        }
+
+        fs::remove_file(in_path).unwrap();
+    }
+
+    #[test]
+    fn test_invalid_filter_fails_before_creating_output() {
+        let in_path: PathBuf = write_rows("evolution_test_converter_invalid_filter.flf", 100);
+        let out_path: PathBuf = in_path.with_extension("parquet");
+
+        for expression in ["name > 5", "amount = 'abc'"] {
+            let result: Result<ParquetConverter> = ParquetConverter::builder()
+                .with_in_file(in_path.clone())
+                .with_schema(schema_path())
+                .with_out_file(out_path.clone())
+                .with_num_threads(1)
+                .with_read_buffer_size(64 * 1024)
+                .with_filter(Some(expression.to_string()))
+                .try_build();
+
+            assert!(result.err().unwrap().to_string().contains("filter"));
+            assert!(!out_path.exists());
+        }
+
+        fs::remove_file(in_path).unwrap();
+    }
+
+    #[test]
+    fn test_filter_with_out_of_range_literals() {
+        let n_rows: usize = 2_000;
+        let in_path: PathBuf = write_rows("evolution_test_converter_range_filter.flf", n_rows);
+
+        for n_threads in [1, 3] {
+            let (_, batch) = convert(&in_path, n_threads, |b| {
+                b.with_filter(Some("amount > 99999999999".to_string()))
+            })
+            .unwrap();
+            assert_eq!(0, batch.num_rows());
+
+            let (_, batch) = convert(&in_path, n_threads, |b| {
+                b.with_filter(Some("amount < 99999999999".to_string()))
+            })
+            .unwrap();
+            assert_eq!(n_rows, batch.num_rows());
+        }
+
+        fs::remove_file(in_path).unwrap();
+    }
+
+    #[test]
+    fn test_mismatching_control_totals_do_not_finish_output() {
+        let in_path: PathBuf = std::env::temp_dir().join("evolution_test_converter_mismatch.flf");
+        fs::write(
+            &in_path,
+            "SKIPPED\n20261018ACME      \n   1SE   100\n   2NO   -40\n00000002        61\n",
+        )
+        .unwrap();
+
+        for n_threads in [1, 2] {
+            let out_path: PathBuf = in_path.with_extension(format!("{}.parquet", n_threads));
+            if out_path.exists() {
+                fs::remove_file(&out_path).unwrap();
+            }
+
+            let result: Result<ConversionReport> = ParquetConverter::builder()
+                .with_in_file(in_path.clone())
+                .with_schema(envelope_schema_path())
+                .with_out_file(out_path.clone())
+                .with_num_threads(n_threads)
+                .with_read_buffer_size(64 * 1024)
+                .try_build()
+                .and_then(|mut c| c.try_convert());
+
+            assert!(result.unwrap_err().to_string().contains("Control totals"));
+            assert!(
+                ParquetRecordBatchReaderBuilder::try_new(File::open(&out_path).unwrap()).is_err()
+            );
+            fs::remove_file(out_path).unwrap();
+        }
+
+        fs::remove_file(in_path).unwrap();
+    }
+
+    /// Records the processed bytes of every progress notification, and optionally cancels
+    /// the conversion on the first notification.
+    struct RecordingObserver {
+        bytes_processed: Mutex<Vec<usize>>,
+        cancel_on_progress: Option<CancellationToken>,
+    }
+
+    impl ProgressObserver for RecordingObserver {
+        fn on_progress(&self, progress: &Progress) {
+            self.bytes_processed
+                .lock()
+                .unwrap()
+                .push(progress.bytes_processed());
+            if let Some(token) = &self.cancel_on_progress {
+                token.cancel();
+            }
+        }
+    }
+
+    #[test]
+    fn test_convert_notifies_progress() {
+        let in_path: PathBuf = write_rows("evolution_test_converter_progress.flf", 20_000);
+        let n_bytes: usize = fs::metadata(&in_path).unwrap().len() as usize;
+
+        for n_threads in [1, 3] {
+            let observer: Arc<RecordingObserver> = Arc::new(RecordingObserver {
+                bytes_processed: Mutex::new(vec![]),
+                cancel_on_progress: None,
+            });
+            let observer_ref: ProgressObserverRef = observer.clone();
+            convert(&in_path, n_threads, |b| {
+                b.with_progress_observer(observer_ref)
+            })
+            .unwrap();
+
+            let bytes_processed: Vec<usize> = observer.bytes_processed.lock().unwrap().clone();
+            assert!(bytes_processed.len() > 1);
+            assert!(bytes_processed.windows(2).all(|w| w[0] <= w[1]));
+            assert!(bytes_processed.windows(2).any(|w| w[0] < w[1]));
+            assert_eq!(Some(&n_bytes), bytes_processed.last());
+        }
+
+        fs::remove_file(in_path).unwrap();
+    }
+
+    #[test]
+    fn test_convert_stops_when_cancelled() {
+        let in_path: PathBuf = write_rows("evolution_test_converter_cancel.flf", 20_000);
+        let n_bytes: usize = fs::metadata(&in_path).unwrap().len() as usize;
+
+        for n_threads in [1, 3] {
+            let token: CancellationToken = CancellationToken::new();
+            let observer: Arc<RecordingObserver> = Arc::new(RecordingObserver {
+                bytes_processed: Mutex::new(vec![]),
+                cancel_on_progress: Some(token.clone()),
+            });
+            let observer_ref: ProgressObserverRef = observer.clone();
+            let result = convert(&in_path, n_threads, |b| {
+                b.with_progress_observer(observer_ref)
+                    .with_cancellation_token(token)
+            });
+
+            assert!(result.unwrap_err().to_string().contains("cancelled"));
+            // Only the workloads which were already built when the token was cancelled are written.
+            let bytes_processed: Vec<usize> = observer.bytes_processed.lock().unwrap().clone();
+            assert!(bytes_processed.len() <= n_threads);
+            assert!(*bytes_processed.last().unwrap() < n_bytes / 2);
+        }
+
+        fs::remove_file(in_path).unwrap();
+    }
+
+    #[test]
+    fn test_report_counts_only_real_parse_failures() {
+        let in_path: PathBuf = std::env::temp_dir().join("evolution_test_converter_failures.flf");
+        let rows: String = [
+            (0, "        "),
+            (1, "     abc"),
+            (2, "      42"),
+            (3, "        "),
+        ]
+        .iter()
+        .map(|(i, amount)| format!("{:>10}{:<12}{}\n", i, "name", amount))
+        .collect();
+        fs::write(&in_path, rows).unwrap();
+
+        for n_threads in [1, 2] {
+            let (report, batch) = convert(&in_path, n_threads, |b| b).unwrap();
+            let amount: &ColumnReport = &report.columns()[2];
+            assert_eq!(3, amount.null_count());
+            assert_eq!(1, amount.parse_failure_count());
+            assert_eq!(3, batch.column(2).null_count());
+        }
+
+        fs::remove_file(in_path).unwrap();
+    }
+}
@@ -0,0 +1,383 @@
+//
+// MIT License
+//
+// Copyright (c) 2026 Firelink Data
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//
+// File created: 2026-10-18
+// Last updated: 2026-10-18
+//
+
+use arrow::array::{AsArray, RecordBatch};
+use arrow::compute::cast;
+use arrow::datatypes::{DataType as ArrowDataType, Float64Type, Int64Type};
+use evolution_common::datatype::DataType;
+use evolution_common::error::{ExecutionError, Result, SetupError};
+use evolution_schema::record::RecordLayout;
+use evolution_schema::schema::FixedSchema;
+use evolution_slicer::slicer::FileSlicer;
+
+/// The parsed fields of the header and trailer records of a fixed-length file.
+#[derive(Clone, Debug, Default)]
+pub struct Envelope {
+    header: Vec<(String, String)>,
+    trailer: Vec<(String, String)>,
+}
+
+impl Envelope {
+    /// Try and split off the skipped lines, the header record and the trailer record declared by
+    /// the [`FixedSchema`] from the file of the [`FileSlicer`], parsing the fields of the header
+    /// and trailer. Afterwards, the slicer only reads the body records of the file.
+    ///
+    /// # Errors
+    /// If the file does not contain enough lines, or if any of the fields is not valid UTF-8.
+    pub fn try_read(slicer: &mut FileSlicer, schema: &FixedSchema) -> Result<Self> {
+        let n_leading: usize = schema.skip_lines() + schema.header().is_some() as usize;
+        let n_trailing: usize = schema.trailer().is_some() as usize;
+
+        if n_leading == 0 && n_trailing == 0 {
+            return Ok(Self::default());
+        }
+
+        let (leading, trailing) = slicer.try_split_off_lines(n_leading, n_trailing)?;
+
+        let header: Vec<(String, String)> = match schema.header() {
+            Some(layout) => layout.try_parse_fields(&leading[schema.skip_lines()])?,
+            None => Vec::new(),
+        };
+
+        let trailer: Vec<(String, String)> = match schema.trailer() {
+            Some(layout) => layout.try_parse_fields(&trailing[0])?,
+            None => Vec::new(),
+        };
+
+        Ok(Self { header, trailer })
+    }
+
+    /// Get the names and values of the header fields.
+    pub fn header(&self) -> &Vec<(String, String)> {
+        &self.header
+    }
+
+    /// Get the names and values of the trailer fields.
+    pub fn trailer(&self) -> &Vec<(String, String)> {
+        &self.trailer
+    }
+
+    /// Get the header and trailer fields as key-value metadata, where the keys are prefixed
+    /// with `header.` and `trailer.` respectively.
+    pub fn key_value_metadata(&self) -> Vec<(String, String)> {
+        let header = self
+            .header
+            .iter()
+            .map(|(k, v)| (format!("header.{}", k), v.clone()));
+        let trailer = self
+            .trailer
+            .iter()
+            .map(|(k, v)| (format!("trailer.{}", k), v.clone()));
+
+        header.chain(trailer).collect::<Vec<(String, String)>>()
+    }
+}
+
+/// A sum of a numeric column, kept as an integer for integer columns to avoid rounding.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum ControlValue {
+    Int(i128),
+    Float(f64),
+}
+
+impl ControlValue {
+    fn matches(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Int(a), Self::Int(b)) => a == b,
+            (Self::Float(a), Self::Float(b)) => {
+                (a - b).abs() <= 1e-6 * a.abs().max(b.abs()).max(1.0)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl std::fmt::Display for ControlValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Int(v) => write!(f, "{}", v),
+            Self::Float(v) => write!(f, "{}", v),
+        }
+    }
+}
+
+/// A control sum declared by a header or trailer, and the sum accumulated while converting.
+#[derive(Clone, Debug)]
+struct ControlSumCheck {
+    field: String,
+    column: String,
+    expected: ControlValue,
+    actual: ControlValue,
+}
+
+/// The control totals declared by the header and trailer of a fixed-length file, which are
+/// compared with the totals accumulated from the converted records.
+#[derive(Clone, Debug)]
+pub(crate) struct ControlTotals {
+    /// The name of the field declaring the number of records, and its value.
+    record_count: Option<(String, usize)>,
+    sums: Vec<ControlSumCheck>,
+}
+
+impl ControlTotals {
+    /// Try and create the [`ControlTotals`] declared by the header and trailer layouts of the
+    /// [`FixedSchema`], returning `None` if no control totals are declared.
+    ///
+    /// # Errors
+    /// If any of the control fields could not be parsed as a number.
+    pub(crate) fn try_new(schema: &FixedSchema, envelope: &Envelope) -> Result<Option<Self>> {
+        let mut record_count: Option<(String, usize)> = None;
+        let mut sums: Vec<ControlSumCheck> = Vec::new();
+
+        let layouts = [
+            (schema.header(), envelope.header()),
+            (schema.trailer(), envelope.trailer()),
+        ];
+
+        for (layout, fields) in layouts {
+            let layout: &RecordLayout = match layout {
+                Some(l) => l,
+                None => continue,
+            };
+
+            if let Some(field) = layout.record_count() {
+                let value: &str = try_find_field(fields, field)?;
+                let count: usize = value
+                    .parse::<usize>()
+                    .map_err(|_| invalid_control(field, value))?;
+                record_count = Some((field.to_string(), count));
+            }
+
+            for control_sum in layout.control_sums() {
+                let value: &str = try_find_field(fields, control_sum.field())?;
+                let is_integer: bool = schema
+                    .iter()
+                    .find(|c| c.name() == control_sum.column())
+                    .is_some_and(|c| {
+                        matches!(
+                            c.dtype(),
+                            DataType::Int16 | DataType::Int32 | DataType::Int64
+                        )
+                    });
+
+                let (expected, actual) = if is_integer {
+                    let expected: i128 = value
+                        .parse::<i128>()
+                        .map_err(|_| invalid_control(control_sum.field(), value))?;
+                    (ControlValue::Int(expected), ControlValue::Int(0))
+                } else {
+                    let expected: f64 = value
+                        .parse::<f64>()
+                        .map_err(|_| invalid_control(control_sum.field(), value))?;
+                    (ControlValue::Float(expected), ControlValue::Float(0.0))
+                };
+
+                sums.push(ControlSumCheck {
+                    field: control_sum.field().to_string(),
+                    column: control_sum.column().to_string(),
+                    expected,
+                    actual,
+                });
+            }
+        }
+
+        if record_count.is_none() && sums.is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some(Self { record_count, sums }))
+    }
+
+    /// Get the names of the columns that have to be built to accumulate the control sums.
+    pub(crate) fn column_names(&self) -> Vec<String> {
+        self.sums
+            .iter()
+            .map(|s| s.column.clone())
+            .collect::<Vec<String>>()
+    }
+
+    /// Try and add the values of the summed columns in the [`RecordBatch`] to the control sums.
+    ///
+    /// # Errors
+    /// If any of the summed columns is missing from the [`RecordBatch`].
+    pub(crate) fn try_update(&mut self, batch: &RecordBatch) -> Result<()> {
+        for check in self.sums.iter_mut() {
+            let array = batch.column_by_name(&check.column).ok_or_else(|| {
+                Box::new(ExecutionError::new(&format!(
+                    "Column '{}' of control sum '{}' was not built, exiting...",
+                    check.column, check.field,
+                ))) as Box<dyn std::error::Error>
+            })?;
+
+            check.actual = match check.actual {
+                ControlValue::Int(sum) => {
+                    let values = cast(array, &ArrowDataType::Int64)?;
+                    ControlValue::Int(
+                        sum + values
+                            .as_primitive::<Int64Type>()
+                            .iter()
+                            .flatten()
+                            .map(i128::from)
+                            .sum::<i128>(),
+                    )
+                }
+                ControlValue::Float(sum) => {
+                    let values = cast(array, &ArrowDataType::Float64)?;
+                    ControlValue::Float(
+                        sum + values
+                            .as_primitive::<Float64Type>()
+                            .iter()
+                            .flatten()
+                            .sum::<f64>(),
+                    )
+                }
+            };
+        }
+
+        Ok(())
+    }
+
+    /// Try and validate that the declared control totals match the converted records.
+    ///
+    /// # Errors
+    /// If the declared number of records, or any of the declared sums, does not match.
+    pub(crate) fn try_validate(&self, n_records: usize) -> Result<()> {
+        let mut mismatches: Vec<String> = Vec::new();
+
+        if let Some((field, count)) = &self.record_count {
+            if *count != n_records {
+                mismatches.push(format!(
+                    "'{}' declares {} records but {} were converted",
+                    field, count, n_records,
+                ));
+            }
+        }
+
+        for check in self.sums.iter() {
+            if !check.expected.matches(&check.actual) {
+                mismatches.push(format!(
+                    "'{}' declares a sum of {} for column '{}' but it was {}",
+                    check.field, check.expected, check.column, check.actual,
+                ));
+            }
+        }
+
+        if mismatches.is_empty() {
+            return Ok(());
+        }
+
+        Err(Box::new(ExecutionError::new(&format!(
+            "Control totals do not match, {}, exiting...",
+            mismatches.join(", "),
+        ))))
+    }
+}
+
+/// Find the value of a control field, where an empty value means zero since a zero padded
+/// field of only zeros is trimmed to nothing.
+fn try_find_field<'a>(fields: &'a [(String, String)], name: &str) -> Result<&'a str> {
+    fields
+        .iter()
+        .find(|(k, _)| k == name)
+        .map(|(_, v)| if v.is_empty() { "0" } else { v.as_str() })
+        .ok_or_else(|| {
+            Box::new(SetupError::new(&format!(
+                "Control field '{}' was not parsed, exiting...",
+                name,
+            ))) as Box<dyn std::error::Error>
+        })
+}
+
+fn invalid_control(field: &str, value: &str) -> Box<dyn std::error::Error> {
+    Box::new(SetupError::new(&format!(
+        "Control field '{}' has the value '{}' which is not a valid number, exiting...",
+        field, value,
+    )))
+}
+
+#[cfg(test)]
+mod tests_envelope {
+    use super::*;
+    use std::fs;
+    use std::io::Write;
+    use std::path::PathBuf;
+
+    fn envelope_schema() -> FixedSchema {
+        let mut path: PathBuf = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        path.push("../evolution-schema/res/test_valid_envelope_schema.json");
+        FixedSchema::from_path(path).unwrap()
+    }
+
+    fn write_file(name: &str, content: &str) -> PathBuf {
+        let path: PathBuf = std::env::temp_dir().join(name);
+        let mut file = fs::File::create(&path).unwrap();
+        file.write_all(content.as_bytes()).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_read_envelope_and_validate_control_totals() {
+        let schema: FixedSchema = envelope_schema();
+        let path: PathBuf = write_file(
+            "evolution_test_envelope.flf",
+            "SKIPPED\n20261018ACME      \n   1SE   100\n   2NO   -40\n00000002        60\n",
+        );
+
+        let mut slicer: FileSlicer = FileSlicer::try_from_path(path.clone()).unwrap();
+        let envelope: Envelope = Envelope::try_read(&mut slicer, &schema).unwrap();
+        fs::remove_file(path).unwrap();
+
+        assert_eq!(slicer.bytes_to_read(), 26);
+        assert_eq!(
+            envelope.key_value_metadata(),
+            vec![
+                ("header.file_date".to_string(), "20261018".to_string()),
+                ("header.sender".to_string(), "ACME".to_string()),
+                ("trailer.record_count".to_string(), "2".to_string()),
+                ("trailer.amount_sum".to_string(), "60".to_string()),
+            ],
+        );
+
+        let mut totals: ControlTotals =
+            ControlTotals::try_new(&schema, &envelope).unwrap().unwrap();
+        assert_eq!(totals.column_names(), vec!["amount".to_string()]);
+
+        let batch: RecordBatch = RecordBatch::try_from_iter(vec![(
+            "amount",
+            std::sync::Arc::new(arrow::array::Int64Array::from(vec![Some(100), Some(-40)]))
+                as arrow::array::ArrayRef,
+        )])
+        .unwrap();
+        totals.try_update(&batch).unwrap();
+
+        assert!(totals.try_validate(2).is_ok());
+        assert!(totals.try_validate(3).is_err());
+
+        totals.try_update(&batch).unwrap();
+        assert!(totals.try_validate(2).is_err());
+    }
+}
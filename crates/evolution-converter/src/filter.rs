@@ -0,0 +1,658 @@
+//
+// MIT License
+//
+// Copyright (c) 2026 Firelink Data
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//
+// File created: 2026-10-18
+// Last updated: 2026-10-18
+//
+
+use arrow::array::{
+    Array, ArrayRef, BooleanArray, Float64Array, Int64Array, RecordBatch, Scalar, StringArray,
+};
+use arrow::buffer::BooleanBuffer;
+use arrow::compute::kernels::cmp::{eq, gt, gt_eq, lt, lt_eq, neq};
+use arrow::compute::{
+    and_kleene, cast, cast_with_options, filter_record_batch, is_not_null, is_null, not, or_kleene,
+    CastOptions,
+};
+use arrow::datatypes::{DataType as ArrowDataType, SchemaRef as ArrowSchemaRef};
+use evolution_common::error::{ExecutionError, Result, SetupError};
+
+use std::iter::Peekable;
+use std::str::Chars;
+use std::sync::Arc;
+
+/// A comparison operator between a column and a literal value.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Operator {
+    Eq,
+    NotEq,
+    Lt,
+    LtEq,
+    Gt,
+    GtEq,
+}
+
+/// A literal value that a column can be compared with.
+#[derive(Clone, Debug, PartialEq)]
+enum Literal {
+    Utf8(String),
+    Int(i64),
+    Float(f64),
+    Boolean(bool),
+}
+
+/// A single token of a filter expression.
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Identifier(String),
+    Literal(Literal),
+    Operator(Operator),
+    LeftParen,
+    RightParen,
+    And,
+    Or,
+    Not,
+    Is,
+    Null,
+}
+
+/// The parsed syntax tree of a filter expression.
+#[derive(Clone, Debug, PartialEq)]
+enum Expr {
+    Compare {
+        column: String,
+        operator: Operator,
+        value: Literal,
+    },
+    IsNull {
+        column: String,
+        negated: bool,
+    },
+    And(Box<Expr>, Box<Expr>),
+    Or(Box<Expr>, Box<Expr>),
+    Not(Box<Expr>),
+}
+
+/// A predicate which is evaluated against the parsed columns of a [`RecordBatch`], keeping
+/// only the rows for which it is true. Rows for which it is null, e.g., when comparing a null
+/// value, are discarded.
+///
+/// The predicate is written as a SQL-like expression, where columns are compared with
+/// literal values using any of `=`, `!=`, `<>`, `<`, `<=`, `>` and `>=`, or checked with
+/// `IS NULL` and `IS NOT NULL`. Comparisons can be combined with `AND`, `OR`, `NOT` and
+/// parentheses. Keywords are case-insensitive, and column names containing other characters
+/// than letters, digits and underscores can be wrapped in double quotes.
+///
+/// # Examples
+///
+/// ```
+/// use evolution_converter::filter::RowFilter;
+///
+/// let filter = RowFilter::try_parse("country = 'SE' AND (amount > 0 OR note IS NOT NULL)").unwrap();
+/// assert_eq!(vec!["country", "amount", "note"], filter.column_names());
+/// ```
+#[derive(Clone, Debug)]
+pub struct RowFilter {
+    expr: Expr,
+}
+
+impl RowFilter {
+    /// Try and parse a [`RowFilter`] from the provided expression.
+    ///
+    /// # Errors
+    /// If the expression is not a valid filter expression.
+    pub fn try_parse(source: &str) -> Result<Self> {
+        let tokens: Vec<Token> = tokenize(source)?;
+        let mut parser: Parser = Parser { tokens, idx: 0 };
+        let expr: Expr = parser.try_parse_or()?;
+
+        match parser.peek() {
+            None => Ok(Self { expr }),
+            Some(token) => Err(Box::new(SetupError::new(&format!(
+                "Unexpected token {:?} in filter expression, exiting...",
+                token,
+            )))),
+        }
+    }
+
+    /// Get the names of the columns used in the filter expression, in order of first appearance.
+    pub fn column_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = Vec::new();
+        collect_column_names(&self.expr, &mut names);
+        names
+    }
+
+    /// Try and validate the filter expression against the schema of the [`RecordBatch`]es that
+    /// it will be evaluated against, by evaluating it against an empty batch of the schema.
+    ///
+    /// # Errors
+    /// If any of the columns are missing from the schema, or if a column can not be compared
+    /// with its literal value, see [`RowFilter::try_evaluate`].
+    pub fn try_validate(&self, schema: ArrowSchemaRef) -> Result<()> {
+        self.try_evaluate(&RecordBatch::new_empty(schema))
+            .map(|_| ())
+            .map_err(|e| {
+                Box::new(SetupError::new(&format!(
+                    "Invalid filter expression: {}",
+                    e
+                ))) as Box<dyn std::error::Error>
+            })
+    }
+
+    /// Try and evaluate the filter expression against the [`RecordBatch`], producing a mask
+    /// which is true for every row that should be kept.
+    ///
+    /// # Errors
+    /// If any of the columns are missing from the [`RecordBatch`], or if a column can not be
+    /// compared with its literal value, e.g., a string column with a number.
+    pub fn try_evaluate(&self, batch: &RecordBatch) -> Result<BooleanArray> {
+        evaluate(&self.expr, batch)
+    }
+
+    /// Try and filter the [`RecordBatch`], keeping only the rows for which the filter
+    /// expression is true.
+    ///
+    /// # Errors
+    /// See [`RowFilter::try_evaluate`].
+    pub fn try_filter(&self, batch: &RecordBatch) -> Result<RecordBatch> {
+        let mask: BooleanArray = self.try_evaluate(batch)?;
+        Ok(filter_record_batch(batch, &mask)?)
+    }
+}
+
+fn collect_column_names(expr: &Expr, names: &mut Vec<String>) {
+    match expr {
+        Expr::Compare { column, .. } | Expr::IsNull { column, .. } => {
+            if !names.contains(column) {
+                names.push(column.clone());
+            }
+        }
+        Expr::And(left, right) | Expr::Or(left, right) => {
+            collect_column_names(left, names);
+            collect_column_names(right, names);
+        }
+        Expr::Not(inner) => collect_column_names(inner, names),
+    }
+}
+
+fn evaluate(expr: &Expr, batch: &RecordBatch) -> Result<BooleanArray> {
+    match expr {
+        Expr::Compare {
+            column,
+            operator,
+            value,
+        } => {
+            let array: ArrayRef = try_column(batch, column)?;
+            if let Some(mask) = compare_out_of_range(&array, *operator, value) {
+                return Ok(mask);
+            }
+
+            let (lhs, rhs) = try_coerce(column, array, value)?;
+            let rhs = Scalar::new(rhs);
+            Ok(match operator {
+                Operator::Eq => eq(&lhs, &rhs)?,
+                Operator::NotEq => neq(&lhs, &rhs)?,
+                Operator::Lt => lt(&lhs, &rhs)?,
+                Operator::LtEq => lt_eq(&lhs, &rhs)?,
+                Operator::Gt => gt(&lhs, &rhs)?,
+                Operator::GtEq => gt_eq(&lhs, &rhs)?,
+            })
+        }
+        Expr::IsNull { column, negated } => {
+            let array: ArrayRef = try_column(batch, column)?;
+            Ok(match negated {
+                true => is_not_null(&array)?,
+                false => is_null(&array)?,
+            })
+        }
+        Expr::And(left, right) => Ok(and_kleene(
+            &evaluate(left, batch)?,
+            &evaluate(right, batch)?,
+        )?),
+        Expr::Or(left, right) => Ok(or_kleene(
+            &evaluate(left, batch)?,
+            &evaluate(right, batch)?,
+        )?),
+        Expr::Not(inner) => Ok(not(&evaluate(inner, batch)?)?),
+    }
+}
+
+fn try_column(batch: &RecordBatch, column: &str) -> Result<ArrayRef> {
+    batch.column_by_name(column).cloned().ok_or_else(|| {
+        Box::new(ExecutionError::new(&format!(
+            "Column '{}' used in the filter expression was not parsed, exiting...",
+            column,
+        ))) as Box<dyn std::error::Error>
+    })
+}
+
+/// Compare an integer column with an integer literal outside of the range of its datatype,
+/// e.g., an `Int32` column with `99999999999`, where the comparison has the same result for
+/// every non-null value. Returns `None` if the literal is not such an integer.
+fn compare_out_of_range(
+    array: &ArrayRef,
+    operator: Operator,
+    value: &Literal,
+) -> Option<BooleanArray> {
+    let (min, max): (i64, i64) = match array.data_type() {
+        ArrowDataType::Int8 => (i8::MIN as i64, i8::MAX as i64),
+        ArrowDataType::Int16 => (i16::MIN as i64, i16::MAX as i64),
+        ArrowDataType::Int32 => (i32::MIN as i64, i32::MAX as i64),
+        ArrowDataType::UInt8 => (0, u8::MAX as i64),
+        ArrowDataType::UInt16 => (0, u16::MAX as i64),
+        ArrowDataType::UInt32 => (0, u32::MAX as i64),
+        ArrowDataType::UInt64 => (0, i64::MAX),
+        _ => return None,
+    };
+
+    let is_above: bool = match value {
+        Literal::Int(v) if *v > max => true,
+        Literal::Int(v) if *v < min => false,
+        _ => return None,
+    };
+
+    let result: bool = match operator {
+        Operator::Eq => false,
+        Operator::NotEq => true,
+        Operator::Lt | Operator::LtEq => is_above,
+        Operator::Gt | Operator::GtEq => !is_above,
+    };
+
+    let values: BooleanBuffer = match result {
+        true => BooleanBuffer::new_set(array.len()),
+        false => BooleanBuffer::new_unset(array.len()),
+    };
+
+    Some(BooleanArray::new(values, array.nulls().cloned()))
+}
+
+/// Try and coerce the column and the literal value into arrays of the same datatype, so that
+/// they can be compared. The literal value is cast to the datatype of the column, except when
+/// comparing an integer column with a floating point value, where the column is cast instead.
+fn try_coerce(column: &str, array: ArrayRef, value: &Literal) -> Result<(ArrayRef, ArrayRef)> {
+    let dtype: ArrowDataType = array.data_type().clone();
+    let literal: ArrayRef = match value {
+        Literal::Utf8(v) => Arc::new(StringArray::from(vec![v.as_str()])),
+        Literal::Int(v) => Arc::new(Int64Array::from(vec![*v])),
+        Literal::Float(v) => Arc::new(Float64Array::from(vec![*v])),
+        Literal::Boolean(v) => Arc::new(BooleanArray::from(vec![*v])),
+    };
+
+    let is_comparable: bool = match value {
+        Literal::Utf8(_) => true,
+        Literal::Int(_) | Literal::Float(_) => dtype.is_numeric(),
+        Literal::Boolean(_) => dtype == ArrowDataType::Boolean,
+    };
+
+    if !is_comparable {
+        return Err(Box::new(ExecutionError::new(&format!(
+            "Column '{}' of type {} can not be compared with {:?}, exiting...",
+            column, dtype, value,
+        ))));
+    }
+
+    if matches!(value, Literal::Float(_)) && dtype.is_integer() {
+        return Ok((cast(&array, &ArrowDataType::Float64)?, literal));
+    }
+
+    let options: CastOptions = CastOptions {
+        safe: false,
+        ..Default::default()
+    };
+
+    let literal: ArrayRef = cast_with_options(&literal, &dtype, &options).map_err(|_| {
+        Box::new(ExecutionError::new(&format!(
+            "Could not cast {:?} to the type {} of column '{}', exiting...",
+            value, dtype, column,
+        ))) as Box<dyn std::error::Error>
+    })?;
+
+    Ok((array, literal))
+}
+
+fn tokenize(source: &str) -> Result<Vec<Token>> {
+    let mut tokens: Vec<Token> = Vec::new();
+    let mut chars: Peekable<Chars> = source.chars().peekable();
+
+    while let Some(&c) = chars.peek() {
+        match c {
+            c if c.is_whitespace() => {
+                chars.next();
+            }
+            '(' => {
+                chars.next();
+                tokens.push(Token::LeftParen);
+            }
+            ')' => {
+                chars.next();
+                tokens.push(Token::RightParen);
+            }
+            '=' => {
+                chars.next();
+                tokens.push(Token::Operator(Operator::Eq));
+            }
+            '!' => {
+                chars.next();
+                match chars.next() {
+                    Some('=') => tokens.push(Token::Operator(Operator::NotEq)),
+                    _ => return Err(invalid_expression("expected '=' after '!'")),
+                }
+            }
+            '<' => {
+                chars.next();
+                match chars.peek() {
+                    Some('=') => {
+                        chars.next();
+                        tokens.push(Token::Operator(Operator::LtEq));
+                    }
+                    Some('>') => {
+                        chars.next();
+                        tokens.push(Token::Operator(Operator::NotEq));
+                    }
+                    _ => tokens.push(Token::Operator(Operator::Lt)),
+                }
+            }
+            '>' => {
+                chars.next();
+                match chars.peek() {
+                    Some('=') => {
+                        chars.next();
+                        tokens.push(Token::Operator(Operator::GtEq));
+                    }
+                    _ => tokens.push(Token::Operator(Operator::Gt)),
+                }
+            }
+            '\'' | '"' => {
+                chars.next();
+                let value: String = read_quoted(&mut chars, c)?;
+                tokens.push(match c {
+                    '\'' => Token::Literal(Literal::Utf8(value)),
+                    _ => Token::Identifier(value),
+                });
+            }
+            c if c.is_ascii_digit() || c == '-' || c == '.' => {
+                let mut number: String = String::new();
+                while let Some(&c) = chars.peek() {
+                    if c.is_ascii_alphanumeric() || c == '-' || c == '.' || c == '+' {
+                        number.push(c);
+                        chars.next();
+                    } else {
+                        break;
+                    }
+                }
+                tokens.push(Token::Literal(try_parse_number(&number)?));
+            }
+            c if c.is_alphabetic() || c == '_' => {
+                let mut word: String = String::new();
+                while let Some(&c) = chars.peek() {
+                    if c.is_alphanumeric() || c == '_' {
+                        word.push(c);
+                        chars.next();
+                    } else {
+                        break;
+                    }
+                }
+                tokens.push(match word.to_ascii_uppercase().as_str() {
+                    "AND" => Token::And,
+                    "OR" => Token::Or,
+                    "NOT" => Token::Not,
+                    "IS" => Token::Is,
+                    "NULL" => Token::Null,
+                    "TRUE" => Token::Literal(Literal::Boolean(true)),
+                    "FALSE" => Token::Literal(Literal::Boolean(false)),
+                    _ => Token::Identifier(word),
+                });
+            }
+            c => {
+                return Err(invalid_expression(&format!("unexpected character '{}'", c)));
+            }
+        }
+    }
+
+    Ok(tokens)
+}
+
+/// Read until the closing quote, where two consecutive quotes are read as one quote.
+fn read_quoted(chars: &mut Peekable<Chars>, quote: char) -> Result<String> {
+    let mut value: String = String::new();
+    loop {
+        match chars.next() {
+            Some(c) if c == quote => {
+                if chars.peek() == Some(&quote) {
+                    chars.next();
+                    value.push(quote);
+                } else {
+                    return Ok(value);
+                }
+            }
+            Some(c) => value.push(c),
+            None => return Err(invalid_expression("unterminated quote")),
+        }
+    }
+}
+
+fn try_parse_number(number: &str) -> Result<Literal> {
+    if let Ok(v) = number.parse::<i64>() {
+        return Ok(Literal::Int(v));
+    }
+
+    match number.parse::<f64>() {
+        Ok(v) => Ok(Literal::Float(v)),
+        Err(_) => Err(invalid_expression(&format!("invalid number '{}'", number))),
+    }
+}
+
+fn invalid_expression(reason: &str) -> Box<dyn std::error::Error> {
+    Box::new(SetupError::new(&format!(
+        "Invalid filter expression, {}, exiting...",
+        reason,
+    )))
+}
+
+/// A recursive descent parser over the tokens of a filter expression, where `NOT` binds
+/// tighter than `AND`, which in turn binds tighter than `OR`.
+struct Parser {
+    tokens: Vec<Token>,
+    idx: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.idx)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token: Option<Token> = self.tokens.get(self.idx).cloned();
+        self.idx += 1;
+        token
+    }
+
+    fn try_parse_or(&mut self) -> Result<Expr> {
+        let mut expr: Expr = self.try_parse_and()?;
+        while self.peek() == Some(&Token::Or) {
+            self.next();
+            expr = Expr::Or(Box::new(expr), Box::new(self.try_parse_and()?));
+        }
+        Ok(expr)
+    }
+
+    fn try_parse_and(&mut self) -> Result<Expr> {
+        let mut expr: Expr = self.try_parse_not()?;
+        while self.peek() == Some(&Token::And) {
+            self.next();
+            expr = Expr::And(Box::new(expr), Box::new(self.try_parse_not()?));
+        }
+        Ok(expr)
+    }
+
+    fn try_parse_not(&mut self) -> Result<Expr> {
+        if self.peek() == Some(&Token::Not) {
+            self.next();
+            return Ok(Expr::Not(Box::new(self.try_parse_not()?)));
+        }
+        self.try_parse_primary()
+    }
+
+    fn try_parse_primary(&mut self) -> Result<Expr> {
+        match self.next() {
+            Some(Token::LeftParen) => {
+                let expr: Expr = self.try_parse_or()?;
+                match self.next() {
+                    Some(Token::RightParen) => Ok(expr),
+                    _ => Err(invalid_expression("expected ')'")),
+                }
+            }
+            Some(Token::Identifier(column)) => match self.next() {
+                Some(Token::Operator(operator)) => match self.next() {
+                    Some(Token::Literal(value)) => Ok(Expr::Compare {
+                        column,
+                        operator,
+                        value,
+                    }),
+                    _ => Err(invalid_expression(&format!(
+                        "expected a literal value to compare column '{}' with",
+                        column,
+                    ))),
+                },
+                Some(Token::Is) => {
+                    let negated: bool = self.peek() == Some(&Token::Not);
+                    if negated {
+                        self.next();
+                    }
+                    match self.next() {
+                        Some(Token::Null) => Ok(Expr::IsNull { column, negated }),
+                        _ => Err(invalid_expression("expected NULL after IS")),
+                    }
+                }
+                _ => Err(invalid_expression(&format!(
+                    "expected a comparison after column '{}'",
+                    column,
+                ))),
+            },
+            Some(token) => Err(invalid_expression(&format!("unexpected token {:?}", token))),
+            None => Err(invalid_expression("unexpected end of expression")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests_filter {
+    use super::*;
+    use arrow::array::{Float32Array, Int32Array};
+    use arrow::datatypes::{Field, Schema};
+
+    fn batch() -> RecordBatch {
+        let schema = Schema::new(vec![
+            Field::new("country", ArrowDataType::Utf8, true),
+            Field::new("amount", ArrowDataType::Int32, true),
+            Field::new("rate", ArrowDataType::Float32, false),
+        ]);
+        RecordBatch::try_new(
+            Arc::new(schema),
+            vec![
+                Arc::new(StringArray::from(vec![
+                    Some("SE"),
+                    Some("NO"),
+                    Some("SE"),
+                    None,
+                ])),
+                Arc::new(Int32Array::from(vec![Some(10), Some(5), None, Some(-3)])),
+                Arc::new(Float32Array::from(vec![0.5, 1.5, 2.5, 3.5])),
+            ],
+        )
+        .unwrap()
+    }
+
+    fn n_rows(expression: &str) -> usize {
+        RowFilter::try_parse(expression)
+            .unwrap()
+            .try_filter(&batch())
+            .unwrap()
+            .num_rows()
+    }
+
+    #[test]
+    fn test_filter_rows() {
+        assert_eq!(1, n_rows("country = 'SE' AND amount > 0"));
+        assert_eq!(2, n_rows("country = 'SE'"));
+        assert_eq!(2, n_rows("country <> 'SE' OR amount IS NULL"));
+        assert_eq!(1, n_rows("NOT (country = 'SE' OR country IS NULL)"));
+        assert_eq!(1, n_rows("country IS NULL"));
+        assert_eq!(3, n_rows("amount IS NOT NULL"));
+        assert_eq!(2, n_rows("amount >= 4.5"));
+        assert_eq!(2, n_rows("rate < 2"));
+        assert_eq!(1, n_rows("\"amount\" = -3"));
+    }
+
+    #[test]
+    fn test_invalid_filters() {
+        assert!(RowFilter::try_parse("country = ").is_err());
+        assert!(RowFilter::try_parse("country = 'SE").is_err());
+        assert!(RowFilter::try_parse("(amount > 0").is_err());
+        assert!(RowFilter::try_parse("amount > 0 amount").is_err());
+        assert!(RowFilter::try_parse("amount IS 0").is_err());
+
+        let filter: RowFilter = RowFilter::try_parse("amount = 'SE'").unwrap();
+        assert!(filter.try_filter(&batch()).is_err());
+
+        let filter: RowFilter = RowFilter::try_parse("missing = 1").unwrap();
+        assert!(filter.try_filter(&batch()).is_err());
+    }
+
+    #[test]
+    fn test_validate_filters() {
+        let schema: ArrowSchemaRef = batch().schema();
+
+        assert!(RowFilter::try_parse("country = 'SE' AND amount > 0")
+            .unwrap()
+            .try_validate(schema.clone())
+            .is_ok());
+        assert!(RowFilter::try_parse("country > 5")
+            .unwrap()
+            .try_validate(schema.clone())
+            .is_err());
+        assert!(RowFilter::try_parse("amount = 'SE'")
+            .unwrap()
+            .try_validate(schema.clone())
+            .is_err());
+        assert!(RowFilter::try_parse("missing IS NULL")
+            .unwrap()
+            .try_validate(schema.clone())
+            .is_err());
+        assert!(RowFilter::try_parse("amount > 99999999999")
+            .unwrap()
+            .try_validate(schema)
+            .is_ok());
+    }
+
+    #[test]
+    fn test_filter_out_of_range_literals() {
+        assert_eq!(0, n_rows("amount > 99999999999"));
+        assert_eq!(0, n_rows("amount = 99999999999"));
+        assert_eq!(3, n_rows("amount < 99999999999"));
+        assert_eq!(3, n_rows("amount != -99999999999"));
+        assert_eq!(3, n_rows("amount >= -99999999999"));
+        assert_eq!(0, n_rows("amount <= -99999999999"));
+        assert_eq!(1, n_rows("amount < 99999999999 AND country = 'NO'"));
+    }
+}
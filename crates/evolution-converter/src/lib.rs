@@ -22,7 +22,14 @@
 // SOFTWARE.
 //
 // File created: 2024-05-28
-// Last updated: 2024-05-28
+// Last updated: 2026-10-18
 //
 
 pub mod converter;
+pub mod envelope;
+pub mod filter;
+pub mod multi;
+pub mod progress;
+pub mod reader;
+pub mod report;
+mod worker;
@@ -0,0 +1,582 @@
+//
+// MIT License
+//
+// Copyright (c) 2026 Firelink Data
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//
+// File created: 2026-10-18
+// Last updated: 2026-10-18
+//
+
+use evolution_builder::builder::MultiParquetBuilder;
+use evolution_common::cancellation::CancellationToken;
+use evolution_common::error::{ExecutionError, Result, SetupError};
+use evolution_common::thread::estimate_best_thread_channel_capacity;
+use evolution_common::NUM_BYTES_FOR_NEWLINE;
+use evolution_schema::multi::MultiFixedSchema;
+use evolution_slicer::slicer::{FileSlicer, Slicer};
+use evolution_writer::parquet::ParquetWriter;
+use log::info;
+use parquet::file::properties::WriterProperties as ArrowWriterProperties;
+use parquet::format::FileMetaData;
+
+use std::collections::BTreeMap;
+use std::fs::{self, File};
+use std::io::BufWriter;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Instant;
+
+use crate::report::ConversionReport;
+use crate::worker::try_build_and_write_in_order;
+
+/// Struct for converting a fixed-length file which interleaves several record layouts into
+/// the parquet file format, writing one output file for each of the layouts. The output
+/// files are named after the layouts, e.g., `customer.parquet`.
+pub struct MultiParquetConverter {
+    slicer: FileSlicer,
+    schema: MultiFixedSchema,
+    builder: MultiParquetBuilder,
+    // The writer of each layout, keyed by record type code.
+    writers: BTreeMap<String, ParquetWriter>,
+    // The size of the buffer that reads the input file (in bytes).
+    read_buffer_size: usize,
+    // The number of threads to use when converting.
+    n_threads: usize,
+    // The maximum number of active messages allowed in the thread channels.
+    thread_channel_capacity: usize,
+    // The summary of the conversion of each layout, keyed by record type code.
+    reports: BTreeMap<String, ConversionReport>,
+    // The path to write the json summary of the conversion to, if any.
+    report_path: Option<PathBuf>,
+    // The token used to request that the conversion stops, if any.
+    cancellation_token: Option<CancellationToken>,
+}
+
+impl MultiParquetConverter {
+    /// Create a new instance of a [`MultiParquetConverterBuilder`] with default values.
+    pub fn builder() -> MultiParquetConverterBuilder {
+        MultiParquetConverterBuilder {
+            ..Default::default()
+        }
+    }
+
+    /// Try and convert the provided fixed-length file to one parquet file for each layout,
+    /// returning a [`ConversionReport`] for each layout, keyed by record type code. If a report
+    /// path was set, then the reports are also written as json to that path.
+    ///
+    /// # Errors
+    /// This function might return an error for the following reasons:
+    /// * If the [`FileSlicer`] fails to read the expected amount of bytes to the buffer.
+    /// * If the buffer did not contain any line-break characters at all.
+    /// * If a record has a record type code which does not match any of the layouts.
+    /// * If any of the [`ParquetWriter`]s failed writing or flushing the RowGroups to file.
+    /// * If cancellation was requested on the [`CancellationToken`], in which case the output
+    ///   files are left incomplete.
+    pub fn try_convert(&mut self) -> Result<BTreeMap<String, ConversionReport>> {
+        let start: Instant = Instant::now();
+        let mode: &str = if self.n_threads > 1 {
+            "multithreaded"
+        } else {
+            "single-threaded"
+        };
+
+        info!(
+            "Converting flf with {} layouts to parquet in {} mode.",
+            self.schema.layouts().len(),
+            mode,
+        );
+
+        let mut buffer_capacity: usize = self.read_buffer_size;
+        let mut thread_workloads: Vec<(usize, usize)> =
+            Vec::with_capacity(self.n_threads.max(2) - 1);
+
+        loop {
+            if self.slicer.is_done() {
+                break;
+            }
+
+            self.try_check_cancelled()?;
+
+            let mut remaining_bytes: usize = self.slicer.remaining_bytes();
+            let mut bytes_processed: usize = self.slicer.bytes_processed();
+            let mut bytes_overlapped: usize = self.slicer.bytes_overlapped();
+
+            if remaining_bytes < buffer_capacity {
+                buffer_capacity = remaining_bytes;
+            }
+
+            let mut buffer: Vec<u8> = vec![0u8; buffer_capacity];
+            self.slicer.try_read_to_buffer(&mut buffer)?;
+
+            let n_bytes_to_build: usize = if self.n_threads > 1 {
+                self.slicer
+                    .try_distribute_buffer_chunks_on_workers(&buffer, &mut thread_workloads)?;
+                self.spawn_converter_threads(&buffer, &thread_workloads)?;
+                thread_workloads[thread_workloads.len() - 1].1 + NUM_BYTES_FOR_NEWLINE
+            } else {
+                let n_bytes: usize =
+                    self.slicer.try_find_last_line_break(&buffer)? + NUM_BYTES_FOR_NEWLINE;
+                // Only build the complete rows, the bytes after the last line break are read again.
+                self.builder.try_build_from_slice(&buffer[..n_bytes])?;
+                Self::try_write_from_builder(
+                    &mut self.writers,
+                    &mut self.reports,
+                    &mut self.builder,
+                )?;
+                n_bytes
+            };
+
+            let n_bytes_left_after_last_line_break: usize = buffer_capacity - n_bytes_to_build;
+            self.slicer
+                .try_seek_relative(-(n_bytes_left_after_last_line_break as i64))?;
+
+            bytes_processed += n_bytes_to_build;
+            bytes_overlapped += n_bytes_left_after_last_line_break;
+            remaining_bytes -= n_bytes_to_build;
+
+            self.slicer.set_remaining_bytes(remaining_bytes);
+            self.slicer.set_bytes_processed(bytes_processed);
+            self.slicer.set_bytes_overlapped(bytes_overlapped);
+
+            thread_workloads.clear();
+
+            info!(
+                "Estimated progress: {:.2}%",
+                100.0 * bytes_processed as f32 / self.slicer.bytes_to_read() as f32,
+            );
+        }
+
+        for (code, writer) in self.writers.iter_mut() {
+            let now: Instant = Instant::now();
+            let metadata: FileMetaData = writer.try_finish()?;
+            let report: &mut ConversionReport = self.reports.get_mut(code).unwrap();
+            report.complete(&metadata, now.elapsed(), start.elapsed());
+
+            info!(
+                "Converted {} records of type '{}' to {} rows.",
+                report.record_count(),
+                code,
+                report.output_rows(),
+            );
+        }
+
+        info!("Done converting flf to parquet in {} mode!", mode);
+
+        if let Some(path) = &self.report_path {
+            info!("Writing conversion reports to {:?}.", path);
+            let file: File = File::create(path)?;
+            serde_json::to_writer_pretty(BufWriter::new(file), &self.reports)?;
+        }
+
+        Ok(self.reports.clone())
+    }
+
+    /// Try and finish the layout builders of the [`MultiParquetBuilder`], and write the built
+    /// columns of each layout with the [`ParquetWriter`] of that layout.
+    ///
+    /// # Errors
+    /// If a [`RecordBatch`] could not be created from the column builders, or if a
+    /// [`ParquetWriter`] failed to write it.
+    ///
+    /// [`RecordBatch`]: arrow::array::RecordBatch
+    fn try_write_from_builder(
+        writers: &mut BTreeMap<String, ParquetWriter>,
+        reports: &mut BTreeMap<String, ConversionReport>,
+        builder: &mut MultiParquetBuilder,
+    ) -> Result<()> {
+        for (code, layout_builder) in builder.builders_mut() {
+            let n_parse_failures: Vec<usize> = layout_builder.n_parse_failures();
+            let record_batch = layout_builder.try_finish()?;
+            if record_batch.num_rows() == 0 {
+                continue;
+            }
+
+            let report: &mut ConversionReport = reports.get_mut(code).unwrap();
+            report.update_from_batch(&record_batch, &n_parse_failures);
+
+            let now: Instant = Instant::now();
+            writers
+                .get_mut(code)
+                .unwrap()
+                .try_write_batch(&record_batch)?;
+            report.add_write_time(now.elapsed());
+        }
+
+        Ok(())
+    }
+
+    /// Spawn the threads which perform the conversion, where each worker thread routes the
+    /// records of its chunk to the layout builders of its own [`MultiParquetBuilder`], and the
+    /// current thread writes the built columns of each layout as they are received.
+    ///
+    /// # Panics
+    /// If a thread not being able to communicate through the channel due to it being disconnected.
+    ///
+    /// # Errors
+    /// If the thread scope closure returned an error, which will propagate an [`ExecutionError`],
+    /// if a record could not be built, e.g., if its record type code has no layout, if any of the
+    /// [`ParquetWriter`]s could not write the built columns, or if cancellation was requested on
+    /// the [`CancellationToken`] while the threads were working.
+    fn spawn_converter_threads(
+        &mut self,
+        buffer: &[u8],
+        thread_workloads: &[(usize, usize)],
+    ) -> Result<()> {
+        let builders: Vec<MultiParquetBuilder> = thread_workloads
+            .iter()
+            .map(|_| {
+                let mut t_builder: MultiParquetBuilder = self.schema.clone().into_builder();
+                if let Some(token) = &self.cancellation_token {
+                    t_builder.set_cancellation_token(token.clone());
+                }
+                t_builder
+            })
+            .collect::<Vec<MultiParquetBuilder>>();
+
+        try_build_and_write_in_order(
+            buffer,
+            thread_workloads,
+            builders,
+            self.thread_channel_capacity,
+            |mut builder, _| {
+                Self::try_write_from_builder(&mut self.writers, &mut self.reports, &mut builder)
+            },
+        )?;
+
+        self.try_check_cancelled()?;
+
+        Ok(())
+    }
+
+    /// Try and check whether cancellation has been requested on the [`CancellationToken`], if any.
+    ///
+    /// # Errors
+    /// If cancellation has been requested.
+    fn try_check_cancelled(&self) -> Result<()> {
+        if self
+            .cancellation_token
+            .as_ref()
+            .is_some_and(|t| t.is_cancelled())
+        {
+            return Err(Box::new(ExecutionError::new(
+                "Conversion was cancelled, exiting...",
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// A helper struct for building an instance of a [`MultiParquetConverter`] struct.
+#[derive(Default)]
+pub struct MultiParquetConverterBuilder {
+    in_path: Option<PathBuf>,
+    schema_path: Option<PathBuf>,
+    out_dir: Option<PathBuf>,
+    n_threads: Option<usize>,
+    read_buffer_size: Option<usize>,
+    thread_channel_capacity: Option<usize>,
+    write_properties: Option<ArrowWriterProperties>,
+    report_path: Option<PathBuf>,
+    cancellation_token: Option<CancellationToken>,
+}
+
+impl MultiParquetConverterBuilder {
+    /// Set the relative or absolute path to the input file to convert.
+    pub fn with_in_file(mut self, in_path: PathBuf) -> Self {
+        self.in_path = Some(in_path);
+        self
+    }
+
+    /// Set the relative or absolute path to the json multi-layout schema file to use.
+    pub fn with_schema(mut self, schema_path: PathBuf) -> Self {
+        self.schema_path = Some(schema_path);
+        self
+    }
+
+    /// Set the relative or absolute path to the directory to write the output files to.
+    /// The directory is created if it does not exist.
+    pub fn with_out_dir(mut self, out_dir: PathBuf) -> Self {
+        self.out_dir = Some(out_dir);
+        self
+    }
+
+    /// Set the number of threads (logical cores) to use.
+    pub fn with_num_threads(mut self, n_threads: usize) -> Self {
+        self.n_threads = Some(n_threads);
+        self
+    }
+
+    /// Set the buffer size for reading the input file (in bytes).
+    pub fn with_read_buffer_size(mut self, buffer_size: usize) -> Self {
+        self.read_buffer_size = Some(buffer_size);
+        self
+    }
+
+    /// Set the properties of the [`ArrowWriter`]s which write to parquet.
+    ///
+    /// [`ArrowWriter`]: parquet::arrow::ArrowWriter
+    pub fn with_write_properties(mut self, properties: ArrowWriterProperties) -> Self {
+        self.write_properties = Some(properties);
+        self
+    }
+
+    /// Set the relative or absolute path to write a json summary of the conversion to.
+    pub fn with_report_file(mut self, report_path: Option<PathBuf>) -> Self {
+        self.report_path = report_path;
+        self
+    }
+
+    /// Set the token used to request that the conversion stops.
+    pub fn with_cancellation_token(mut self, token: CancellationToken) -> Self {
+        self.cancellation_token = Some(token);
+        self
+    }
+
+    /// Set the maximum message capacity on the multithreaded converter thread channels.
+    pub fn with_thread_channel_capacity(mut self, capacity: Option<usize>) -> Self {
+        self.thread_channel_capacity = capacity;
+        self
+    }
+
+    /// Try creating a new [`MultiParquetConverter`] from the previously set values.
+    ///
+    /// # Errors
+    /// This function might return an error for the following reasons:
+    /// * If any of the required fields are `None`.
+    /// * If the schema deserialization or validation failed.
+    /// * If any I/O error occured when trying to open the input file, or when creating the
+    ///   output directory and files.
+    /// * If any of the layouts contains unsupported datatypes.
+    pub fn try_build(self) -> Result<MultiParquetConverter> {
+        let start: Instant = Instant::now();
+        let in_file: PathBuf = self.in_path.ok_or_else(|| {
+            Box::new(SetupError::new(
+                "Required field 'in_path' was not provided, exiting...",
+            ))
+        })?;
+
+        let schema_path: PathBuf = self.schema_path.ok_or_else(|| {
+            Box::new(SetupError::new(
+                "Required field 'schema_path' was not provided, exiting...",
+            ))
+        })?;
+
+        let out_dir: PathBuf = self.out_dir.ok_or_else(|| {
+            Box::new(SetupError::new(
+                "Required field 'out_dir' was not provided, exiting...",
+            ))
+        })?;
+
+        let n_threads: usize = self.n_threads.ok_or_else(|| {
+            Box::new(SetupError::new(
+                "Required field 'n_threads' was not provided, exiting...",
+            ))
+        })?;
+
+        let read_buffer_size: usize = self.read_buffer_size.ok_or_else(|| {
+            Box::new(SetupError::new(
+                "Required field 'read_buffer_size' was not provided, exiting...",
+            ))
+        })?;
+
+        let thread_channel_capacity: usize = self
+            .thread_channel_capacity
+            .unwrap_or(estimate_best_thread_channel_capacity(n_threads.max(2)));
+
+        let slicer: FileSlicer = FileSlicer::try_from_path(in_file)?;
+        let schema: MultiFixedSchema = MultiFixedSchema::from_path(schema_path)?;
+
+        fs::create_dir_all(&out_dir)?;
+
+        let mut writers: BTreeMap<String, ParquetWriter> = BTreeMap::new();
+        let mut reports: BTreeMap<String, ConversionReport> = BTreeMap::new();
+        for (code, layout) in schema.layouts().iter() {
+            let writer: ParquetWriter = ParquetWriter::builder()
+                .with_out_path(out_dir.join(format!("{}.parquet", layout.name())))
+                .with_arrow_schema(Arc::new(layout.clone().into_arrow_schema()))
+                .with_properties(self.write_properties.clone())
+                .try_build()?;
+
+            let mut report: ConversionReport =
+                ConversionReport::new(layout, slicer.bytes_to_read());
+            report.set_setup_time(start.elapsed());
+
+            writers.insert(code.clone(), writer);
+            reports.insert(code.clone(), report);
+        }
+
+        let mut builder: MultiParquetBuilder = schema.clone().into_builder();
+        if let Some(token) = &self.cancellation_token {
+            builder.set_cancellation_token(token.clone());
+        }
+
+        Ok(MultiParquetConverter {
+            slicer,
+            schema,
+            builder,
+            writers,
+            read_buffer_size,
+            n_threads,
+            thread_channel_capacity,
+            reports,
+            report_path: self.report_path,
+            cancellation_token: self.cancellation_token,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests_multi {
+    use super::*;
+    use arrow::array::{Array, Int32Array, Int64Array, RecordBatch, RecordBatchReader};
+    use arrow::compute::concat_batches;
+    use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+    use std::path::Path;
+
+    fn schema_path() -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("res/test_converter_multi_schema.json")
+    }
+
+    /// Write a fixed-length file where every third record is an account and all other records
+    /// are customers, and where the customer id of each record is its index.
+    fn write_records(name: &str, n_records: usize) -> PathBuf {
+        let path: PathBuf = std::env::temp_dir().join(name);
+        let records: String = (0..n_records)
+            .map(|i| match i % 3 {
+                2 => format!("02{:>5}{:>7}\n", i, i * 3),
+                _ => format!("01{:>5}{:<9}\n", i, format!("name{}", i % 100)),
+            })
+            .collect();
+        fs::write(&path, records).unwrap();
+        path
+    }
+
+    fn read_parquet(path: &Path) -> RecordBatch {
+        let reader = ParquetRecordBatchReaderBuilder::try_new(File::open(path).unwrap())
+            .unwrap()
+            .build()
+            .unwrap();
+        let schema = reader.schema();
+        let batches: Vec<RecordBatch> = reader.map(|b| b.unwrap()).collect();
+        concat_batches(&schema, &batches).unwrap()
+    }
+
+    /// Convert the file with a small read buffer, so that every thread gets several workloads,
+    /// and read back the converted records of each layout, keyed by layout name.
+    fn convert(
+        in_path: &Path,
+        n_threads: usize,
+    ) -> Result<(
+        BTreeMap<String, ConversionReport>,
+        BTreeMap<String, RecordBatch>,
+    )> {
+        let out_dir: PathBuf = in_path.with_extension(format!("{}.out", n_threads));
+        if out_dir.exists() {
+            fs::remove_dir_all(&out_dir)?;
+        }
+
+        let result: Result<BTreeMap<String, ConversionReport>> = MultiParquetConverter::builder()
+            .with_in_file(in_path.to_path_buf())
+            .with_schema(schema_path())
+            .with_out_dir(out_dir.clone())
+            .with_num_threads(n_threads)
+            .with_read_buffer_size(4 * 1024)
+            .with_thread_channel_capacity(Some(n_threads))
+            .try_build()
+            .and_then(|mut c| c.try_convert());
+
+        let batches: Option<BTreeMap<String, RecordBatch>> = result.is_ok().then(|| {
+            ["customer", "account"]
+                .iter()
+                .map(|name| {
+                    let path: PathBuf = out_dir.join(format!("{}.parquet", name));
+                    (name.to_string(), read_parquet(&path))
+                })
+                .collect()
+        });
+        if out_dir.exists() {
+            fs::remove_dir_all(&out_dir)?;
+        }
+        Ok((result?, batches.unwrap()))
+    }
+
+    fn int32_values(batch: &RecordBatch, name: &str) -> Vec<i32> {
+        batch
+            .column_by_name(name)
+            .unwrap()
+            .as_any()
+            .downcast_ref::<Int32Array>()
+            .unwrap()
+            .values()
+            .to_vec()
+    }
+
+    #[test]
+    fn test_convert_layouts_to_separate_files() {
+        let n_records: usize = 5_000;
+        let in_path: PathBuf = write_records("evolution_test_multi_layouts.flf", n_records);
+
+        let customer_ids: Vec<i32> = (0..n_records as i32).filter(|i| i % 3 != 2).collect();
+        let account_ids: Vec<i32> = (0..n_records as i32).filter(|i| i % 3 == 2).collect();
+
+        for n_threads in [1, 2, 4] {
+            let (reports, batches) = convert(&in_path, n_threads).unwrap();
+
+            assert_eq!(customer_ids.len(), reports["01"].record_count());
+            assert_eq!(account_ids.len(), reports["02"].output_rows());
+
+            let customers: &RecordBatch = &batches["customer"];
+            assert_eq!(3, customers.num_columns());
+            assert_eq!(customer_ids, int32_values(customers, "customer_id"));
+
+            let accounts: &RecordBatch = &batches["account"];
+            assert_eq!(account_ids, int32_values(accounts, "customer_id"));
+            let balances = accounts
+                .column_by_name("balance")
+                .unwrap()
+                .as_any()
+                .downcast_ref::<Int64Array>()
+                .unwrap();
+            assert_eq!(0, balances.null_count());
+            assert_eq!(6, balances.value(0));
+            assert_eq!(account_ids[1] as i64 * 3, balances.value(1));
+        }
+
+        fs::remove_file(in_path).unwrap();
+    }
+
+    #[test]
+    fn test_convert_unknown_record_type() {
+        let in_path: PathBuf = write_records("evolution_test_multi_unknown.flf", 5_000);
+        let mut records: String = fs::read_to_string(&in_path).unwrap();
+        let idx: usize = records.len() / 2;
+        let idx: usize = idx + records[idx..].find('\n').unwrap() + 1;
+        records.insert_str(idx, "03    1whatever \n");
+        fs::write(&in_path, records).unwrap();
+
+        for n_threads in [1, 2, 4] {
+            let error: String = convert(&in_path, n_threads).err().unwrap().to_string();
+            assert!(error.contains("Unknown record type '03'"), "{}", error);
+        }
+
+        fs::remove_file(in_path).unwrap();
+    }
+}
@@ -0,0 +1,127 @@
+//
+// MIT License
+//
+// Copyright (c) 2026 Firelink Data
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//
+// File created: 2026-10-18
+// Last updated: 2026-10-18
+//
+
+use std::sync::Arc;
+use std::time::Duration;
+
+/// A snapshot of how far a running conversion has come.
+#[derive(Clone, Copy, Debug)]
+pub struct Progress {
+    /// The number of bytes of the input file that have been converted.
+    bytes_processed: usize,
+    /// The total number of bytes of the input file.
+    bytes_total: usize,
+    /// The number of records that have been converted.
+    records: usize,
+    /// The wall time since the conversion started.
+    elapsed: Duration,
+}
+
+impl Progress {
+    /// Create a new [`Progress`] from the provided field values.
+    pub fn new(
+        bytes_processed: usize,
+        bytes_total: usize,
+        records: usize,
+        elapsed: Duration,
+    ) -> Self {
+        Self {
+            bytes_processed,
+            bytes_total,
+            records,
+            elapsed,
+        }
+    }
+
+    /// Get the number of bytes of the input file that have been converted.
+    pub fn bytes_processed(&self) -> usize {
+        self.bytes_processed
+    }
+
+    /// Get the total number of bytes of the input file.
+    pub fn bytes_total(&self) -> usize {
+        self.bytes_total
+    }
+
+    /// Get the number of records that have been converted.
+    pub fn records(&self) -> usize {
+        self.records
+    }
+
+    /// Get the wall time since the conversion started.
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Get the ratio of the input file that has been converted, in the range [0, 1].
+    pub fn ratio(&self) -> f64 {
+        if self.bytes_total == 0 {
+            return 1.0;
+        }
+        (self.bytes_processed as f64 / self.bytes_total as f64).min(1.0)
+    }
+
+    /// Get the estimated time remaining, extrapolated from the throughput so far.
+    /// Returns `None` if nothing has been converted yet.
+    pub fn eta(&self) -> Option<Duration> {
+        if self.bytes_processed == 0 {
+            return None;
+        }
+        let remaining_bytes: usize = self.bytes_total.saturating_sub(self.bytes_processed);
+        Some(
+            self.elapsed
+                .mul_f64(remaining_bytes as f64 / self.bytes_processed as f64),
+        )
+    }
+}
+
+/// A trait for observing the progress of a running conversion.
+///
+/// # Note
+/// The observer is called from the thread which writes the output, so any work done in
+/// [`ProgressObserver::on_progress`] will delay the conversion, keep it short.
+pub trait ProgressObserver: Send + Sync {
+    fn on_progress(&self, progress: &Progress);
+}
+
+/// A short-hand notation for a shared progress observer implementation.
+pub type ProgressObserverRef = Arc<dyn ProgressObserver>;
+
+#[cfg(test)]
+mod tests_progress {
+    use super::*;
+
+    #[test]
+    fn test_progress_eta() {
+        let a: Progress = Progress::new(0, 100, 0, Duration::from_secs(1));
+        assert_eq!(None, a.eta());
+
+        let b: Progress = Progress::new(25, 100, 10, Duration::from_secs(1));
+        assert_eq!(0.25, b.ratio());
+        assert_eq!(Some(Duration::from_secs(3)), b.eta());
+    }
+}
@@ -0,0 +1,499 @@
+//
+// MIT License
+//
+// Copyright (c) 2026 Firelink Data
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//
+// File created: 2026-10-18
+// Last updated: 2026-10-18
+//
+
+use arrow::array::{RecordBatch, RecordBatchReader};
+use arrow::datatypes::SchemaRef as ArrowSchemaRef;
+use arrow::error::ArrowError;
+use evolution_builder::builder::ParquetBuilder;
+use evolution_common::error::Result as EvolutionResult;
+use evolution_schema::schema::FixedSchema;
+
+use std::io::{ErrorKind, Read};
+use std::iter::FusedIterator;
+use std::sync::Arc;
+
+/// The default number of rows in each [`RecordBatch`] produced by a [`FixedRecordBatchReader`].
+pub static DEFAULT_BATCH_SIZE: usize = 8192;
+
+/// The default number of bytes read at a time by a [`FixedRecordBatchReader`].
+pub static DEFAULT_READ_BUFFER_SIZE: usize = 8 * 1024 * 1024;
+
+/// An in-memory reader which parses fixed-length data from any [`Read`] implementation, e.g.,
+/// a [`File`] or a `&[u8]`, and produces arrow [`RecordBatch`]es of the parsed rows. Any skipped
+/// lines, header, and trailer records declared by the [`FixedSchema`] are not parsed as rows.
+///
+/// # Examples
+///
+/// ```no_run
+/// use evolution_converter::reader::FixedRecordBatchReader;
+/// use evolution_schema::schema::FixedSchema;
+/// use std::path::PathBuf;
+///
+/// let schema: FixedSchema = FixedSchema::from_path(PathBuf::from(r"/path/to/my/schema.json")).unwrap();
+/// let data: &[u8] = "    1Kaylin\n    2Nils  \n".as_bytes();
+///
+/// for batch in FixedRecordBatchReader::new(schema, data).with_batch_size(1) {
+///     println!("{:?}", batch.unwrap());
+/// }
+/// ```
+///
+/// [`File`]: std::fs::File
+pub struct FixedRecordBatchReader<R: Read> {
+    inner: R,
+    builder: ParquetBuilder,
+    schema: ArrowSchemaRef,
+    /// Bytes which have been read but not yet built into a [`RecordBatch`].
+    buffer: Vec<u8>,
+    /// The number of rows to include in each [`RecordBatch`].
+    batch_size: usize,
+    /// The number of bytes to read from the inner reader at a time.
+    read_buffer_size: usize,
+    /// The byte index in the buffer up until which line breaks have been counted.
+    scanned_idx: usize,
+    /// The number of line breaks found in the buffer so far.
+    n_line_breaks: usize,
+    /// The byte index right after the line break which ends the batch, once it has been found.
+    batch_end_idx: Option<usize>,
+    /// The number of non-blank lines found after the end of the batch so far.
+    n_lookahead_lines: usize,
+    /// Whether or not the line currently being scanned only contains whitespace so far.
+    is_blank_line: bool,
+    /// The number of leading lines, i.e., skipped lines and the header, left to discard.
+    n_leading_lines: usize,
+    /// The number of trailing lines, i.e., the trailer, which are never parsed as rows.
+    n_trailing_lines: usize,
+    /// Whether or not the inner reader has been read to its end.
+    is_eof: bool,
+    /// Whether or not an error has been returned, after which the reader is exhausted.
+    has_failed: bool,
+}
+
+impl<R: Read> FixedRecordBatchReader<R> {
+    /// Create a new [`FixedRecordBatchReader`] which reads fixed-length rows, adhering to the
+    /// provided [`FixedSchema`], from the inner reader.
+    pub fn new(schema: FixedSchema, inner: R) -> Self {
+        let arrow_schema: ArrowSchemaRef = Arc::new(schema.clone().into_arrow_schema());
+        let envelope: (usize, usize) = envelope_lines(&schema);
+        let builder: ParquetBuilder = schema.into_builder::<ParquetBuilder>();
+
+        Self::from_parts(arrow_schema, builder, envelope, inner)
+    }
+
+    /// Try and create a new [`FixedRecordBatchReader`] which only parses the columns with the
+    /// provided indices, in the order in which they appear in the [`FixedSchema`]. The fields of
+    /// all other columns are skipped without being parsed.
+    ///
+    /// # Errors
+    /// If the projection is empty or any of the indices are out of bounds for the schema.
+    pub fn try_new_projected(
+        schema: FixedSchema,
+        projection: &[usize],
+        inner: R,
+    ) -> EvolutionResult<Self> {
+        let arrow_schema: ArrowSchemaRef =
+            Arc::new(schema.clone().try_into_projected_arrow_schema(projection)?);
+        let envelope: (usize, usize) = envelope_lines(&schema);
+        let builder: ParquetBuilder = schema.try_into_projected_builder(projection)?;
+
+        Ok(Self::from_parts(arrow_schema, builder, envelope, inner))
+    }
+
+    fn from_parts(
+        schema: ArrowSchemaRef,
+        builder: ParquetBuilder,
+        (n_leading_lines, n_trailing_lines): (usize, usize),
+        inner: R,
+    ) -> Self {
+        Self {
+            inner,
+            builder,
+            schema,
+            buffer: Vec::new(),
+            batch_size: DEFAULT_BATCH_SIZE,
+            read_buffer_size: DEFAULT_READ_BUFFER_SIZE,
+            scanned_idx: 0,
+            n_line_breaks: 0,
+            batch_end_idx: None,
+            n_lookahead_lines: 0,
+            is_blank_line: true,
+            n_leading_lines,
+            n_trailing_lines,
+            is_eof: false,
+            has_failed: false,
+        }
+    }
+
+    /// Set the number of rows to include in each [`RecordBatch`].
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    /// Set the number of bytes to read from the inner reader at a time.
+    pub fn with_read_buffer_size(mut self, read_buffer_size: usize) -> Self {
+        self.read_buffer_size = read_buffer_size.max(1);
+        self
+    }
+
+    /// Find the byte index right after the line break which ends the batch, counting line
+    /// breaks from where the previous search stopped. If the data has trailing lines, then the
+    /// batch only ends once that many non-blank lines have been found after it, so that the
+    /// trailing lines are never part of a batch.
+    fn find_end_of_batch(&mut self) -> Option<usize> {
+        if self.batch_end_idx.is_some() && self.n_lookahead_lines == self.n_trailing_lines {
+            return self.batch_end_idx;
+        }
+
+        while self.scanned_idx < self.buffer.len() {
+            let byte: u8 = self.buffer[self.scanned_idx];
+            self.scanned_idx += 1;
+
+            if byte != 0x0a {
+                self.is_blank_line &= byte.is_ascii_whitespace();
+                continue;
+            }
+
+            match self.batch_end_idx {
+                Some(_) => self.n_lookahead_lines += !self.is_blank_line as usize,
+                None => {
+                    self.n_line_breaks += 1;
+                    if self.n_line_breaks == self.batch_size {
+                        self.batch_end_idx = Some(self.scanned_idx);
+                    }
+                }
+            }
+            self.is_blank_line = true;
+
+            if self.batch_end_idx.is_some() && self.n_lookahead_lines == self.n_trailing_lines {
+                return self.batch_end_idx;
+            }
+        }
+        None
+    }
+
+    /// Try and discard the leading lines from the buffer, reading more bytes when needed.
+    ///
+    /// # Errors
+    /// If the data ends before all of the leading lines, or if the inner reader failed.
+    fn try_skip_leading_lines(&mut self) -> Result<(), ArrowError> {
+        while self.n_leading_lines > 0 {
+            match self.buffer.iter().position(|b| *b == 0x0a) {
+                Some(idx) => {
+                    self.buffer.drain(..=idx);
+                    self.n_leading_lines -= 1;
+                }
+                None if self.is_eof => {
+                    // The last leading line does not have to end with a line break.
+                    if self.n_leading_lines > 1 || self.buffer.is_empty() {
+                        return Err(too_few_lines());
+                    }
+                    self.buffer.clear();
+                    self.n_leading_lines = 0;
+                }
+                None => {
+                    if self.try_fill_buffer()? == 0 {
+                        self.is_eof = true;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Try and find the byte index in the buffer where the trailing lines start, once the
+    /// inner reader has been read to its end. Blank lines at the very end are not considered
+    /// lines.
+    ///
+    /// # Errors
+    /// If the buffer does not contain all of the trailing lines.
+    fn try_find_start_of_trailing_lines(&self) -> Result<usize, ArrowError> {
+        let mut end: usize = self.buffer.len();
+        for _ in 0..self.n_trailing_lines {
+            end -= self.buffer[..end]
+                .iter()
+                .rev()
+                .take_while(|b| b.is_ascii_whitespace())
+                .count();
+            if end == 0 {
+                return Err(too_few_lines());
+            }
+            end = self.buffer[..end]
+                .iter()
+                .rposition(|b| *b == 0x0a)
+                .map_or(0, |idx| idx + 1);
+        }
+        Ok(end)
+    }
+
+    /// Try and read the next chunk of bytes from the inner reader into the buffer,
+    /// returning the number of bytes that were read.
+    fn try_fill_buffer(&mut self) -> Result<usize, ArrowError> {
+        let n_bytes_before: usize = self.buffer.len();
+        self.buffer
+            .resize(n_bytes_before + self.read_buffer_size, 0u8);
+
+        let n_bytes_read: usize = loop {
+            match self.inner.read(&mut self.buffer[n_bytes_before..]) {
+                Ok(n) => break n,
+                Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+                Err(e) => {
+                    self.buffer.truncate(n_bytes_before);
+                    return Err(ArrowError::from(e));
+                }
+            }
+        };
+
+        self.buffer.truncate(n_bytes_before + n_bytes_read);
+        Ok(n_bytes_read)
+    }
+
+    /// Try and build a [`RecordBatch`] from the buffered bytes up until the provided index,
+    /// and then discard those bytes from the buffer.
+    fn try_build_batch(&mut self, end_byte_idx: usize) -> Result<RecordBatch, ArrowError> {
+        let result: Result<RecordBatch, ArrowError> = self
+            .builder
+            .try_build_from_slice(&self.buffer[..end_byte_idx])
+            .and_then(|_| self.builder.try_finish())
+            .map_err(|e| ArrowError::ParseError(e.to_string()));
+
+        self.buffer.drain(..end_byte_idx);
+        self.scanned_idx = 0;
+        self.n_line_breaks = 0;
+        self.batch_end_idx = None;
+        self.n_lookahead_lines = 0;
+        self.is_blank_line = true;
+
+        result
+    }
+
+    /// Read and build the next [`RecordBatch`], or `None` if all of the rows have been read.
+    fn next_batch(&mut self) -> Option<Result<RecordBatch, ArrowError>> {
+        if let Err(e) = self.try_skip_leading_lines() {
+            return Some(Err(e));
+        }
+
+        loop {
+            if let Some(end_byte_idx) = self.find_end_of_batch() {
+                return Some(self.try_build_batch(end_byte_idx));
+            }
+
+            if self.is_eof {
+                let end_byte_idx: usize = match self.try_find_start_of_trailing_lines() {
+                    Ok(idx) => idx,
+                    Err(e) => return Some(Err(e)),
+                };
+
+                // The last row does not have to end with a line break.
+                if self.buffer[..end_byte_idx]
+                    .iter()
+                    .all(|b| b.is_ascii_whitespace())
+                {
+                    self.buffer.clear();
+                    return None;
+                }
+                return Some(self.try_build_batch(end_byte_idx));
+            }
+
+            match self.try_fill_buffer() {
+                Ok(0) => self.is_eof = true,
+                Ok(_) => {}
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+}
+
+/// Get the number of leading lines, i.e., skipped lines and the header, and the number of
+/// trailing lines, i.e., the trailer, declared by the [`FixedSchema`].
+fn envelope_lines(schema: &FixedSchema) -> (usize, usize) {
+    (
+        schema.skip_lines() + schema.header().is_some() as usize,
+        schema.trailer().is_some() as usize,
+    )
+}
+
+fn too_few_lines() -> ArrowError {
+    ArrowError::ParseError(String::from(
+        "The data contains fewer lines than the header and trailer records of the schema",
+    ))
+}
+
+impl<R: Read> Iterator for FixedRecordBatchReader<R> {
+    type Item = Result<RecordBatch, ArrowError>;
+
+    /// Get the next [`RecordBatch`] of parsed rows.
+    ///
+    /// # Note
+    /// The reader is fused after the first error, i.e., it returns `None` from then on, since
+    /// the rows that were partially built when the error occurred can not be recovered.
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.has_failed {
+            return None;
+        }
+
+        let result: Option<Self::Item> = self.next_batch();
+        if matches!(result, Some(Err(_))) {
+            self.has_failed = true;
+            self.buffer.clear();
+        }
+        result
+    }
+}
+
+impl<R: Read> FusedIterator for FixedRecordBatchReader<R> {}
+
+impl<R: Read> RecordBatchReader for FixedRecordBatchReader<R> {
+    fn schema(&self) -> ArrowSchemaRef {
+        self.schema.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests_reader {
+    use super::*;
+
+    use arrow::array::{Array, Int32Array, StringArray};
+    use evolution_common::datatype::DataType;
+    use evolution_schema::column::FixedColumn;
+    use padder::{Alignment, Symbol};
+    use std::path::PathBuf;
+
+    fn schema() -> FixedSchema {
+        FixedSchema::new(
+            String::from("ReaderTestSchema"),
+            1,
+            vec![
+                FixedColumn::new(
+                    String::from("id"),
+                    0,
+                    3,
+                    DataType::Int32,
+                    Alignment::Right,
+                    Symbol::Whitespace,
+                    false,
+                ),
+                FixedColumn::new(
+                    String::from("city"),
+                    3,
+                    6,
+                    DataType::Utf8,
+                    Alignment::Left,
+                    Symbol::Whitespace,
+                    true,
+                ),
+            ],
+        )
+    }
+
+    #[test]
+    fn test_read_batches_from_slice() {
+        let schema: FixedSchema = schema();
+
+        let data: &[u8] = "  1Malmö \n 22Umeå  \n333Kiruna".as_bytes();
+        let reader = FixedRecordBatchReader::new(schema, data)
+            .with_batch_size(2)
+            .with_read_buffer_size(4);
+
+        assert_eq!(2, reader.schema().fields().len());
+
+        let batches: Vec<RecordBatch> = reader.map(|b| b.unwrap()).collect();
+        assert_eq!(2, batches.len());
+        assert_eq!(2, batches[0].num_rows());
+        assert_eq!(1, batches[1].num_rows());
+
+        let ids = batches[0]
+            .column(0)
+            .as_any()
+            .downcast_ref::<Int32Array>()
+            .unwrap();
+        let cities = batches[1]
+            .column(1)
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+
+        assert_eq!(&[1, 22], ids.values().as_ref());
+        assert_eq!("Kiruna", cities.value(0));
+    }
+
+    #[test]
+    fn test_reader_is_fused_after_parse_error() {
+        let data: &[u8] = "  1Malmö \n 22Umeå  \n  xLund  \n444Visby \n555Kiruna".as_bytes();
+        let mut reader = FixedRecordBatchReader::new(schema(), data)
+            .with_batch_size(2)
+            .with_read_buffer_size(4);
+
+        assert_eq!(2, reader.next().unwrap().unwrap().num_rows());
+        assert!(reader.next().unwrap().is_err());
+        assert!(reader.next().is_none());
+        assert!(reader.next().is_none());
+    }
+
+    #[test]
+    fn test_reader_skips_header_and_trailer() {
+        let schema: FixedSchema = FixedSchema::from_path(
+            PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+                .join("../evolution-schema/res/test_valid_envelope_schema.json"),
+        )
+        .unwrap();
+
+        let rows: String = (0..7)
+            .map(|i| format!("{:>4}SE{:>6}\n", i, i * 10))
+            .collect::<String>();
+        let trailers = [
+            "00000007       210",
+            "00000007       210\n",
+            "00000007       210\n\n",
+        ];
+
+        for trailer in trailers {
+            let data: String = format!("SKIPPED\n20261018ACME      \n{}{}", rows, trailer);
+            for batch_size in [1, 2, 7, 100] {
+                let reader = FixedRecordBatchReader::new(schema.clone(), data.as_bytes())
+                    .with_batch_size(batch_size)
+                    .with_read_buffer_size(5);
+
+                let ids: Vec<i32> = reader
+                    .map(|b| b.unwrap())
+                    .flat_map(|b| {
+                        b.column(0)
+                            .as_any()
+                            .downcast_ref::<Int32Array>()
+                            .unwrap()
+                            .values()
+                            .to_vec()
+                    })
+                    .collect();
+                assert_eq!((0..7).collect::<Vec<i32>>(), ids);
+            }
+        }
+
+        let mut reader = FixedRecordBatchReader::new(schema, "SKIPPED\n".as_bytes());
+        assert!(reader.next().unwrap().is_err());
+        assert!(reader.next().is_none());
+    }
+}
@@ -0,0 +1,632 @@
+//
+// MIT License
+//
+// Copyright (c) 2026 Firelink Data
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//
+// File created: 2026-10-18
+// Last updated: 2026-10-18
+//
+
+use arrow::array::{
+    Array, ArrayRef, Date32Array, Decimal128Array, Float16Array, Float32Array, Float64Array,
+    Int16Array, Int32Array, Int64Array, RecordBatch,
+};
+use arrow::compute::{max, min};
+use arrow::datatypes::{DataType as ArrowDataType, Decimal128Type, DecimalType};
+use arrow::temporal_conversions::date32_to_datetime;
+use evolution_common::datatype::DataType;
+use evolution_common::error::Result;
+use evolution_schema::schema::FixedSchema;
+use parquet::format::FileMetaData;
+use serde::{Serialize, Serializer};
+
+use std::fs::File;
+use std::io::BufWriter;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// A numeric value, used to represent the smallest and largest value found in a column.
+///
+/// Decimals are serialized as strings formatted with their scale, to not lose any precision,
+/// and dates are serialized as strings in the ISO 8601 format.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum NumericValue {
+    Int(i64),
+    Float(f64),
+    Decimal {
+        value: i128,
+        precision: u8,
+        scale: i8,
+    },
+    Date(i32),
+}
+
+impl Serialize for NumericValue {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Self::Int(v) => serializer.serialize_i64(*v),
+            Self::Float(v) => serializer.serialize_f64(*v),
+            Self::Decimal {
+                value,
+                precision,
+                scale,
+            } => serializer
+                .serialize_str(&Decimal128Type::format_decimal(*value, *precision, *scale)),
+            Self::Date(days) => match date32_to_datetime(*days) {
+                Some(datetime) => serializer.serialize_str(&datetime.date().to_string()),
+                None => serializer.serialize_i32(*days),
+            },
+        }
+    }
+}
+
+impl NumericValue {
+    /// Get the smallest of the two values, values of different variants are never compared.
+    fn min(self, other: Self) -> Self {
+        match (self, other) {
+            (Self::Int(a), Self::Int(b)) => Self::Int(a.min(b)),
+            (Self::Float(a), Self::Float(b)) => Self::Float(a.min(b)),
+            (
+                Self::Decimal {
+                    value: a,
+                    precision,
+                    scale,
+                },
+                Self::Decimal { value: b, .. },
+            ) => Self::Decimal {
+                value: a.min(b),
+                precision,
+                scale,
+            },
+            (Self::Date(a), Self::Date(b)) => Self::Date(a.min(b)),
+            (a, _) => a,
+        }
+    }
+
+    /// Get the largest of the two values, values of different variants are never compared.
+    fn max(self, other: Self) -> Self {
+        match (self, other) {
+            (Self::Int(a), Self::Int(b)) => Self::Int(a.max(b)),
+            (Self::Float(a), Self::Float(b)) => Self::Float(a.max(b)),
+            (
+                Self::Decimal {
+                    value: a,
+                    precision,
+                    scale,
+                },
+                Self::Decimal { value: b, .. },
+            ) => Self::Decimal {
+                value: a.max(b),
+                precision,
+                scale,
+            },
+            (Self::Date(a), Self::Date(b)) => Self::Date(a.max(b)),
+            (a, _) => a,
+        }
+    }
+}
+
+/// Summary statistics of a single converted column.
+#[derive(Clone, Debug, Serialize)]
+pub struct ColumnReport {
+    /// The symbolic name of the column.
+    name: String,
+    /// The datatype of the column.
+    dtype: DataType,
+    /// The number of null values written to the column.
+    null_count: usize,
+    /// The number of fields that could not be parsed as the datatype of the column.
+    parse_failure_count: usize,
+    /// The smallest value of the column, only set for numeric, decimal, and date columns.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    min: Option<NumericValue>,
+    /// The largest value of the column, only set for numeric, decimal, and date columns.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max: Option<NumericValue>,
+}
+
+impl ColumnReport {
+    /// Get the name of the column.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Get the datatype of the column.
+    pub fn dtype(&self) -> DataType {
+        self.dtype
+    }
+
+    /// Get the number of null values written to the column.
+    pub fn null_count(&self) -> usize {
+        self.null_count
+    }
+
+    /// Get the number of fields that could not be parsed as the datatype of the column.
+    ///
+    /// # Note
+    /// A field which could not be parsed is either replaced by the default value of the
+    /// column, or by a null value, hence this is not necessarily equal to the null count.
+    pub fn parse_failure_count(&self) -> usize {
+        self.parse_failure_count
+    }
+
+    /// Get the smallest value of the column, if it is numeric, a decimal, or a date, and contains any values.
+    pub fn min(&self) -> Option<NumericValue> {
+        self.min
+    }
+
+    /// Get the largest value of the column, if it is numeric, a decimal, or a date, and contains any values.
+    pub fn max(&self) -> Option<NumericValue> {
+        self.max
+    }
+
+    /// Update the statistics of the column with the values of the next converted array.
+    fn update(&mut self, array: &ArrayRef, n_parse_failures: usize) {
+        self.null_count += array.null_count();
+        self.parse_failure_count += n_parse_failures;
+
+        if let Some((lower, upper)) = numeric_bounds(array) {
+            self.min = Some(self.min.map_or(lower, |v| v.min(lower)));
+            self.max = Some(self.max.map_or(upper, |v| v.max(upper)));
+        }
+    }
+}
+
+/// Find the smallest and largest values of the array, if it is a numeric, decimal, or date array.
+fn numeric_bounds(array: &ArrayRef) -> Option<(NumericValue, NumericValue)> {
+    match array.data_type() {
+        ArrowDataType::Float16 => {
+            let a: &Float16Array = array.as_any().downcast_ref::<Float16Array>()?;
+            Some((
+                NumericValue::Float(min(a)?.to_f64()),
+                NumericValue::Float(max(a)?.to_f64()),
+            ))
+        }
+        ArrowDataType::Float32 => {
+            let a: &Float32Array = array.as_any().downcast_ref::<Float32Array>()?;
+            Some((
+                NumericValue::Float(min(a)? as f64),
+                NumericValue::Float(max(a)? as f64),
+            ))
+        }
+        ArrowDataType::Float64 => {
+            let a: &Float64Array = array.as_any().downcast_ref::<Float64Array>()?;
+            Some((NumericValue::Float(min(a)?), NumericValue::Float(max(a)?)))
+        }
+        ArrowDataType::Int16 => {
+            let a: &Int16Array = array.as_any().downcast_ref::<Int16Array>()?;
+            Some((
+                NumericValue::Int(min(a)? as i64),
+                NumericValue::Int(max(a)? as i64),
+            ))
+        }
+        ArrowDataType::Int32 => {
+            let a: &Int32Array = array.as_any().downcast_ref::<Int32Array>()?;
+            Some((
+                NumericValue::Int(min(a)? as i64),
+                NumericValue::Int(max(a)? as i64),
+            ))
+        }
+        ArrowDataType::Int64 => {
+            let a: &Int64Array = array.as_any().downcast_ref::<Int64Array>()?;
+            Some((NumericValue::Int(min(a)?), NumericValue::Int(max(a)?)))
+        }
+        ArrowDataType::Decimal128(precision, scale) => {
+            let a: &Decimal128Array = array.as_any().downcast_ref::<Decimal128Array>()?;
+            let decimal = |value: i128| NumericValue::Decimal {
+                value,
+                precision: *precision,
+                scale: *scale,
+            };
+            Some((decimal(min(a)?), decimal(max(a)?)))
+        }
+        ArrowDataType::Date32 => {
+            let a: &Date32Array = array.as_any().downcast_ref::<Date32Array>()?;
+            Some((NumericValue::Date(min(a)?), NumericValue::Date(max(a)?)))
+        }
+        _ => None,
+    }
+}
+
+/// Serialize a [`Duration`] as a floating point number of seconds.
+fn serialize_seconds<S>(duration: &Duration, serializer: S) -> std::result::Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_f64(duration.as_secs_f64())
+}
+
+/// The wall time spent in each stage of a conversion (serialized in seconds).
+///
+/// # Note
+/// In multithreaded mode the worker threads convert while the master thread writes, so
+/// the convert time is the time spent waiting for the workers that was not spent writing.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct StageTimings {
+    /// Time spent setting up the converter, i.e., opening files and parsing the schema.
+    #[serde(serialize_with = "serialize_seconds")]
+    setup: Duration,
+    /// Time spent reading the input file into buffers.
+    #[serde(serialize_with = "serialize_seconds")]
+    read: Duration,
+    /// Time spent parsing the buffers and building the columns.
+    #[serde(serialize_with = "serialize_seconds")]
+    convert: Duration,
+    /// Time spent writing the built columns to the output file.
+    #[serde(serialize_with = "serialize_seconds")]
+    write: Duration,
+    /// Time spent flushing and closing the output file.
+    #[serde(serialize_with = "serialize_seconds")]
+    finish: Duration,
+    /// The total time of the conversion, excluding the setup.
+    #[serde(serialize_with = "serialize_seconds")]
+    total: Duration,
+}
+
+impl StageTimings {
+    /// Get the time spent setting up the converter.
+    pub fn setup(&self) -> Duration {
+        self.setup
+    }
+
+    /// Get the time spent reading the input file into buffers.
+    pub fn read(&self) -> Duration {
+        self.read
+    }
+
+    /// Get the time spent parsing the buffers and building the columns.
+    pub fn convert(&self) -> Duration {
+        self.convert
+    }
+
+    /// Get the time spent writing the built columns to the output file.
+    pub fn write(&self) -> Duration {
+        self.write
+    }
+
+    /// Get the time spent flushing and closing the output file.
+    pub fn finish(&self) -> Duration {
+        self.finish
+    }
+
+    /// Get the total time of the conversion, excluding the setup.
+    pub fn total(&self) -> Duration {
+        self.total
+    }
+}
+
+/// A summary of a finished conversion, used to verify that a file was loaded as expected.
+#[derive(Clone, Debug, Serialize)]
+pub struct ConversionReport {
+    /// The name of the schema used for the conversion.
+    schema_name: String,
+    /// The size of the input file (in bytes).
+    input_bytes: usize,
+    /// The number of records parsed from the input file.
+    record_count: usize,
+    /// The number of rows written to the output file.
+    output_rows: usize,
+    /// The number of row groups written to the output file.
+    row_groups: usize,
+    /// Summary statistics for each of the columns.
+    columns: Vec<ColumnReport>,
+    /// The wall time spent in each stage of the conversion.
+    timings: StageTimings,
+    /// The number of input bytes converted per second.
+    bytes_per_second: f64,
+    /// The number of records converted per second.
+    records_per_second: f64,
+}
+
+impl ConversionReport {
+    /// Create a new empty [`ConversionReport`] for the columns of the [`FixedSchema`].
+    pub(crate) fn new(schema: &FixedSchema, input_bytes: usize) -> Self {
+        let columns = schema
+            .iter()
+            .map(|c| ColumnReport {
+                name: c.name().clone(),
+                dtype: c.dtype(),
+                null_count: 0,
+                parse_failure_count: 0,
+                min: None,
+                max: None,
+            })
+            .collect::<Vec<ColumnReport>>();
+
+        Self {
+            schema_name: schema.name().to_string(),
+            input_bytes,
+            record_count: 0,
+            output_rows: 0,
+            row_groups: 0,
+            columns,
+            timings: StageTimings::default(),
+            bytes_per_second: 0.0,
+            records_per_second: 0.0,
+        }
+    }
+
+    /// Get the name of the schema used for the conversion.
+    pub fn schema_name(&self) -> &str {
+        &self.schema_name
+    }
+
+    /// Get the size of the input file (in bytes).
+    pub fn input_bytes(&self) -> usize {
+        self.input_bytes
+    }
+
+    /// Get the number of records parsed from the input file.
+    pub fn record_count(&self) -> usize {
+        self.record_count
+    }
+
+    /// Get the number of rows written to the output file.
+    pub fn output_rows(&self) -> usize {
+        self.output_rows
+    }
+
+    /// Get the number of row groups written to the output file.
+    pub fn row_groups(&self) -> usize {
+        self.row_groups
+    }
+
+    /// Get the summary statistics for each of the columns.
+    pub fn columns(&self) -> &Vec<ColumnReport> {
+        &self.columns
+    }
+
+    /// Get the wall time spent in each stage of the conversion.
+    pub fn timings(&self) -> &StageTimings {
+        &self.timings
+    }
+
+    /// Get the number of input bytes converted per second.
+    pub fn bytes_per_second(&self) -> f64 {
+        self.bytes_per_second
+    }
+
+    /// Get the number of records converted per second.
+    pub fn records_per_second(&self) -> f64 {
+        self.records_per_second
+    }
+
+    /// Update the report with the next converted [`RecordBatch`] and the number of fields,
+    /// per column, that could not be parsed when building it.
+    pub(crate) fn update_from_batch(&mut self, batch: &RecordBatch, n_parse_failures: &[usize]) {
+        self.record_count += batch.num_rows();
+        for (idx, column) in self.columns.iter_mut().enumerate() {
+            column.update(batch.column(idx), n_parse_failures[idx]);
+        }
+    }
+
+    /// Only keep the summaries of the columns with the provided (sorted) indices.
+    pub(crate) fn retain_columns(&mut self, projection: &[usize]) {
+        let mut idx: usize = 0;
+        self.columns.retain(|_| {
+            idx += 1;
+            projection.binary_search(&(idx - 1)).is_ok()
+        });
+    }
+
+    /// Set the time spent setting up the converter.
+    pub(crate) fn set_setup_time(&mut self, duration: Duration) {
+        self.timings.setup = duration;
+    }
+
+    /// Add to the time spent reading the input file.
+    pub(crate) fn add_read_time(&mut self, duration: Duration) {
+        self.timings.read += duration;
+    }
+
+    /// Add to the time spent parsing and building columns.
+    pub(crate) fn add_convert_time(&mut self, duration: Duration) {
+        self.timings.convert += duration;
+    }
+
+    /// Add to the time spent writing to the output file.
+    pub(crate) fn add_write_time(&mut self, duration: Duration) {
+        self.timings.write += duration;
+    }
+
+    /// Complete the report with the metadata of the written file and the time it took
+    /// to finish it, as well as the total time of the conversion.
+    pub(crate) fn complete(&mut self, metadata: &FileMetaData, finish: Duration, total: Duration) {
+        self.output_rows = metadata.num_rows as usize;
+        self.row_groups = metadata.row_groups.len();
+        self.timings.finish = finish;
+        self.timings.total = total;
+
+        let seconds: f64 = total.as_secs_f64();
+        if seconds > 0.0 {
+            self.bytes_per_second = self.input_bytes as f64 / seconds;
+            self.records_per_second = self.record_count as f64 / seconds;
+        }
+    }
+
+    /// Try and write the report as json to a file at the provided path.
+    ///
+    /// # Errors
+    /// If the file could not be created, or if the report could not be serialized.
+    pub fn try_write_to_path(&self, path: PathBuf) -> Result<()> {
+        let file: File = File::create(path)?;
+        serde_json::to_writer_pretty(BufWriter::new(file), self)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests_report {
+    use super::*;
+
+    use evolution_schema::column::FixedColumn;
+    use padder::{Alignment, Symbol};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_update_report_from_batches() {
+        let schema: FixedSchema = FixedSchema::new(
+            String::from("ReportTestSchema"),
+            1,
+            vec![
+                FixedColumn::new(
+                    String::from("amount"),
+                    0,
+                    5,
+                    DataType::Int32,
+                    Alignment::Right,
+                    Symbol::Whitespace,
+                    true,
+                ),
+                FixedColumn::new(
+                    String::from("code"),
+                    5,
+                    3,
+                    DataType::Utf8,
+                    Alignment::Right,
+                    Symbol::Whitespace,
+                    false,
+                ),
+            ],
+        );
+
+        let mut report: ConversionReport = ConversionReport::new(&schema, 18);
+
+        let a: RecordBatch = RecordBatch::try_from_iter(vec![
+            (
+                "amount",
+                Arc::new(Int32Array::from(vec![Some(-3), None])) as ArrayRef,
+            ),
+            (
+                "code",
+                Arc::new(arrow::array::StringArray::from(vec!["SE", "NO"])) as ArrayRef,
+            ),
+        ])
+        .unwrap();
+        let b: RecordBatch = RecordBatch::try_from_iter(vec![
+            (
+                "amount",
+                Arc::new(Int32Array::from(vec![Some(42)])) as ArrayRef,
+            ),
+            (
+                "code",
+                Arc::new(arrow::array::StringArray::from(vec!["DK"])) as ArrayRef,
+            ),
+        ])
+        .unwrap();
+
+        report.update_from_batch(&a, &[1, 0]);
+        report.update_from_batch(&b, &[0, 0]);
+
+        assert_eq!(3, report.record_count());
+        assert_eq!(1, report.columns()[0].null_count());
+        assert_eq!(1, report.columns()[0].parse_failure_count());
+        assert_eq!(Some(NumericValue::Int(-3)), report.columns()[0].min());
+        assert_eq!(Some(NumericValue::Int(42)), report.columns()[0].max());
+        assert_eq!(None, report.columns()[1].min());
+    }
+
+    #[test]
+    fn test_decimal_and_date_bounds() {
+        let schema: FixedSchema = FixedSchema::new(
+            String::from("ReportTestSchema"),
+            1,
+            vec![
+                FixedColumn::new(
+                    String::from("price"),
+                    0,
+                    10,
+                    DataType::Decimal128,
+                    Alignment::Right,
+                    Symbol::Whitespace,
+                    true,
+                )
+                .with_precision_and_scale(10, 2),
+                FixedColumn::new(
+                    String::from("booked"),
+                    10,
+                    10,
+                    DataType::Date32,
+                    Alignment::Right,
+                    Symbol::Whitespace,
+                    true,
+                ),
+            ],
+        );
+
+        let mut report: ConversionReport = ConversionReport::new(&schema, 42);
+
+        let batch = |prices: Vec<Option<i128>>, days: Vec<Option<i32>>| {
+            RecordBatch::try_from_iter(vec![
+                (
+                    "price",
+                    Arc::new(
+                        Decimal128Array::from(prices)
+                            .with_precision_and_scale(10, 2)
+                            .unwrap(),
+                    ) as ArrayRef,
+                ),
+                ("booked", Arc::new(Date32Array::from(days)) as ArrayRef),
+            ])
+            .unwrap()
+        };
+
+        report.update_from_batch(
+            &batch(vec![Some(1999), None], vec![Some(19_723), Some(0)]),
+            &[0, 0],
+        );
+        report.update_from_batch(
+            &batch(vec![Some(-5), Some(123_456)], vec![None, Some(20_000)]),
+            &[0, 0],
+        );
+
+        let price: &ColumnReport = &report.columns()[0];
+        assert_eq!(
+            Some(NumericValue::Decimal {
+                value: -5,
+                precision: 10,
+                scale: 2
+            }),
+            price.min()
+        );
+        assert_eq!(
+            Some(NumericValue::Decimal {
+                value: 123_456,
+                precision: 10,
+                scale: 2
+            }),
+            price.max()
+        );
+
+        let booked: &ColumnReport = &report.columns()[1];
+        assert_eq!(Some(NumericValue::Date(0)), booked.min());
+        assert_eq!(Some(NumericValue::Date(20_000)), booked.max());
+
+        let json: serde_json::Value = serde_json::to_value(&report).unwrap();
+        assert_eq!("-0.05", json["columns"][0]["min"]);
+        assert_eq!("1234.56", json["columns"][0]["max"]);
+        assert_eq!("1970-01-01", json["columns"][1]["min"]);
+        assert_eq!("2024-10-04", json["columns"][1]["max"]);
+    }
+}
@@ -0,0 +1,166 @@
+//
+// MIT License
+//
+// Copyright (c) 2026 Firelink Data
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//
+// File created: 2026-10-18
+// Last updated: 2026-10-18
+//
+
+use crossbeam::channel;
+use crossbeam::thread::scope;
+use crossbeam::thread::ScopedJoinHandle;
+use evolution_builder::builder::{MultiParquetBuilder, ParquetBuilder};
+use evolution_common::error::{ExecutionError, Result};
+
+use std::collections::BTreeMap;
+use std::thread;
+
+/// The builder of a worker thread together with the number of bytes it was built from, or
+/// the message of the error that stopped it.
+type WorkerResult<B> = std::result::Result<(B, usize), String>;
+
+/// A builder which builds the records of a chunk of the read buffer on a worker thread.
+pub(crate) trait WorkerBuilder: Send {
+    /// Try and build all of the records in the buffer.
+    fn try_build_from_slice(&mut self, buffer: &[u8]) -> Result<()>;
+
+    /// Get whether or not the builder stopped because cancellation was requested.
+    fn is_cancelled(&self) -> bool;
+}
+
+impl WorkerBuilder for ParquetBuilder {
+    fn try_build_from_slice(&mut self, buffer: &[u8]) -> Result<()> {
+        ParquetBuilder::try_build_from_slice(self, buffer)
+    }
+
+    fn is_cancelled(&self) -> bool {
+        ParquetBuilder::is_cancelled(self)
+    }
+}
+
+impl WorkerBuilder for MultiParquetBuilder {
+    fn try_build_from_slice(&mut self, buffer: &[u8]) -> Result<()> {
+        MultiParquetBuilder::try_build_from_slice(self, buffer)
+    }
+
+    fn is_cancelled(&self) -> bool {
+        MultiParquetBuilder::is_cancelled(self)
+    }
+}
+
+/// Build each of the workloads of the buffer with its own builder on a scoped worker thread,
+/// and write the built builders on the current thread, together with the number of bytes that
+/// each of them was built from.
+///
+/// The builders are written in the order of their workloads, regardless of which worker thread
+/// finishes first, so that the records keep the order of the file. A builder which stopped
+/// because cancellation was requested is discarded together with its partially built columns.
+///
+/// # Panics
+/// If a thread not being able to communicate through the channel due to it being disconnected.
+///
+/// # Errors
+/// This function might return an error for the following reasons:
+/// * If any of the builders could not build its workload, e.g., if a record could not be parsed.
+/// * If any of the built builders could not be written.
+/// * If any worker thread panicked, or if the thread scope closure returned an error, which
+///   will propagate an [`ExecutionError`].
+///
+/// After the first error, the remaining builders are discarded without being written.
+pub(crate) fn try_build_and_write_in_order<B, W>(
+    buffer: &[u8],
+    thread_workloads: &[(usize, usize)],
+    builders: Vec<B>,
+    thread_channel_capacity: usize,
+    mut write: W,
+) -> Result<()>
+where
+    B: WorkerBuilder,
+    W: FnMut(B, usize) -> Result<()>,
+{
+    let (sender, receiver) = channel::bounded(thread_channel_capacity);
+
+    let thread_result: thread::Result<Result<()>> = scope(|s| {
+        let threads = thread_workloads
+            .iter()
+            .zip(builders)
+            .enumerate()
+            .map(|(t_idx, ((from, to), mut t_builder))| {
+                let t_sender: channel::Sender<(usize, WorkerResult<B>)> = sender.clone();
+                let t_buffer_slice: &[u8] = &buffer[*from..*to];
+
+                s.spawn(move |_| {
+                    let t_result: WorkerResult<B> =
+                        match t_builder.try_build_from_slice(t_buffer_slice) {
+                            Ok(()) => Ok((t_builder, t_buffer_slice.len())),
+                            Err(_) if t_builder.is_cancelled() => return,
+                            // The error itself can not be sent between threads, only its message.
+                            Err(e) => Err(e.to_string()),
+                        };
+                    t_sender.send((t_idx, t_result)).unwrap();
+                    drop(t_sender);
+                })
+            })
+            .collect::<Vec<ScopedJoinHandle<()>>>();
+
+        drop(sender);
+        let mut pending: BTreeMap<usize, WorkerResult<B>> = BTreeMap::new();
+        let mut next_t_idx: usize = 0;
+        // After the first error the remaining builders are still received, so that no
+        // worker thread is left blocking on a full channel.
+        let mut write_result: Result<()> = Ok(());
+        for (t_idx, t_result) in receiver {
+            if write_result.is_err() {
+                continue;
+            }
+
+            pending.insert(t_idx, t_result);
+            while let Some(t_result) = pending.remove(&next_t_idx) {
+                write_result = match t_result {
+                    Ok((builder, n_bytes)) => write(builder, n_bytes),
+                    Err(e) => Err(Box::new(ExecutionError::new(&e))),
+                };
+                if write_result.is_err() {
+                    break;
+                }
+                next_t_idx += 1;
+            }
+        }
+
+        for handle in threads {
+            if handle.join().is_err() && write_result.is_ok() {
+                write_result = Err(Box::new(ExecutionError::new(
+                    "Could not join worker thread handle, exiting...",
+                )));
+            }
+        }
+
+        write_result
+    });
+
+    match thread_result {
+        Ok(write_result) => write_result,
+        Err(e) => Err(Box::new(ExecutionError::new(
+            format!("One of the scoped threads returned an error: {:?}", e).as_str(),
+        ))),
+    }
+}
@@ -0,0 +1,231 @@
+//
+// MIT License
+//
+// Copyright (c) 2026 Firelink Data
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//
+// File created: 2026-10-18
+// Last updated: 2026-10-18
+//
+
+use arrow::array::RecordBatch;
+use arrow::datatypes::SchemaRef as ArrowSchemaRef;
+use datafusion::error::{DataFusionError, Result as DataFusionResult};
+use datafusion::execution::TaskContext;
+use datafusion::physical_expr::EquivalenceProperties;
+use datafusion::physical_plan::stream::{
+    RecordBatchReceiverStream, RecordBatchReceiverStreamBuilder,
+};
+use datafusion::physical_plan::{
+    DisplayAs, DisplayFormatType, ExecutionMode, ExecutionPlan, Partitioning, PlanProperties,
+    SendableRecordBatchStream,
+};
+use evolution_converter::reader::FixedRecordBatchReader;
+use evolution_schema::schema::FixedSchema;
+
+use std::any::Any;
+use std::fmt;
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::PathBuf;
+use std::sync::Arc;
+
+/// A DataFusion [`ExecutionPlan`] which scans a fixed-length file. Each partition of the plan
+/// reads its own row aligned byte range of the file, and only the projected columns are parsed.
+#[derive(Debug)]
+pub struct FixedLengthExec {
+    path: PathBuf,
+    schema: FixedSchema,
+    /// The sorted and deduplicated indices of the columns to parse from the file.
+    file_projection: Vec<usize>,
+    /// The indices used to reorder the parsed columns into the requested projection.
+    output_projection: Vec<usize>,
+    projected_schema: ArrowSchemaRef,
+    /// The start (inclusive) and end (exclusive) byte indices of each partition.
+    ranges: Vec<(usize, usize)>,
+    properties: PlanProperties,
+}
+
+impl FixedLengthExec {
+    /// Try and create a new [`FixedLengthExec`] which produces the columns with the provided
+    /// indices, in the requested order, with one partition for each of the byte ranges. The
+    /// ranges may only contain body records, i.e., no skipped lines, header, or trailer.
+    ///
+    /// # Errors
+    /// If any of the column indices are out of bounds for the schema, or if a column which
+    /// a projected column depends on does not exist in the schema.
+    pub fn try_new(
+        path: PathBuf,
+        schema: FixedSchema,
+        projection: Vec<usize>,
+        ranges: Vec<(usize, usize)>,
+    ) -> DataFusionResult<Self> {
+        // The byte ranges only contain body records, any header and trailer records have
+        // already been split off from them.
+        let schema: FixedSchema = schema.without_envelope();
+        let arrow_schema: ArrowSchemaRef = Arc::new(schema.clone().into_arrow_schema());
+        let projected_schema: ArrowSchemaRef = Arc::new(arrow_schema.project(&projection)?);
+
+        // At least one column has to be parsed to know the number of rows, e.g., when
+        // only counting the rows of the file.
+        let mut file_projection: Vec<usize> = projection.clone();
+        file_projection.sort_unstable();
+        file_projection.dedup();
+        if file_projection.is_empty() {
+            file_projection.push(0);
+        }
+
+        // The columns which the projected columns depend on, i.e., the count of a repeating
+        // column or the discriminator of alternative layouts, are parsed but not output.
+        let dependencies: Vec<String> = schema.dependency_column_names(&file_projection);
+        file_projection.extend(
+            schema
+                .try_column_indices(&dependencies)
+                .map_err(|e| DataFusionError::Plan(e.to_string()))?,
+        );
+        file_projection.sort_unstable();
+        file_projection.dedup();
+
+        let output_projection: Vec<usize> = projection
+            .iter()
+            .map(|idx| file_projection.binary_search(idx).unwrap())
+            .collect::<Vec<usize>>();
+
+        // An empty file still has to produce one (empty) partition.
+        let ranges: Vec<(usize, usize)> = if ranges.is_empty() {
+            vec![(0, 0)]
+        } else {
+            ranges
+        };
+
+        let properties: PlanProperties = PlanProperties::new(
+            EquivalenceProperties::new(projected_schema.clone()),
+            Partitioning::UnknownPartitioning(ranges.len()),
+            ExecutionMode::Bounded,
+        );
+
+        Ok(Self {
+            path,
+            schema,
+            file_projection,
+            output_projection,
+            projected_schema,
+            ranges,
+            properties,
+        })
+    }
+
+    /// Get the byte ranges of the file that each partition reads.
+    pub fn ranges(&self) -> &Vec<(usize, usize)> {
+        &self.ranges
+    }
+}
+
+impl DisplayAs for FixedLengthExec {
+    fn fmt_as(&self, _: DisplayFormatType, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "FixedLengthExec: path={}, partitions={}, projection={:?}",
+            self.path.display(),
+            self.ranges.len(),
+            self.projected_schema
+                .fields()
+                .iter()
+                .map(|f| f.name())
+                .collect::<Vec<&String>>(),
+        )
+    }
+}
+
+impl ExecutionPlan for FixedLengthExec {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn properties(&self) -> &PlanProperties {
+        &self.properties
+    }
+
+    fn children(&self) -> Vec<Arc<dyn ExecutionPlan>> {
+        vec![]
+    }
+
+    fn with_new_children(
+        self: Arc<Self>,
+        _: Vec<Arc<dyn ExecutionPlan>>,
+    ) -> DataFusionResult<Arc<dyn ExecutionPlan>> {
+        Ok(self)
+    }
+
+    fn execute(
+        &self,
+        partition: usize,
+        context: Arc<TaskContext>,
+    ) -> DataFusionResult<SendableRecordBatchStream> {
+        let (start, end) = *self.ranges.get(partition).ok_or_else(|| {
+            DataFusionError::Internal(format!(
+                "Partition {} is out of bounds for {} partitions",
+                partition,
+                self.ranges.len(),
+            ))
+        })?;
+
+        let path: PathBuf = self.path.clone();
+        let schema: FixedSchema = self.schema.clone();
+        let file_projection: Vec<usize> = self.file_projection.clone();
+        let output_projection: Vec<usize> = self.output_projection.clone();
+        let batch_size: usize = context.session_config().batch_size();
+
+        // Reading and parsing the file is blocking, so it is done on a blocking thread which
+        // sends the batches to the stream through a channel.
+        let mut builder: RecordBatchReceiverStreamBuilder =
+            RecordBatchReceiverStream::builder(self.projected_schema.clone(), 2);
+        let sender = builder.tx();
+        builder.spawn_blocking(move || {
+            let mut file: File = File::open(&path)?;
+            file.seek(SeekFrom::Start(start as u64))?;
+
+            let reader = FixedRecordBatchReader::try_new_projected(
+                schema,
+                &file_projection,
+                file.take((end - start) as u64),
+            )
+            .map_err(|e| DataFusionError::Execution(e.to_string()))?
+            .with_batch_size(batch_size);
+
+            for batch in reader {
+                let batch: DataFusionResult<RecordBatch> = batch
+                    .and_then(|b| b.project(&output_projection))
+                    .map_err(DataFusionError::from);
+                let is_err: bool = batch.is_err();
+
+                // The stream has been dropped if the batch could not be sent, e.g., when
+                // the query has a limit, so there is no reason to keep reading.
+                if sender.blocking_send(batch).is_err() || is_err {
+                    break;
+                }
+            }
+
+            Ok(())
+        });
+
+        Ok(builder.build())
+    }
+}
@@ -0,0 +1,382 @@
+//
+// MIT License
+//
+// Copyright (c) 2026 Firelink Data
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//
+// File created: 2026-10-18
+// Last updated: 2026-10-18
+//
+
+use arrow::datatypes::SchemaRef as ArrowSchemaRef;
+use async_trait::async_trait;
+use datafusion::datasource::{TableProvider, TableType};
+use datafusion::error::{DataFusionError, Result as DataFusionResult};
+use datafusion::execution::context::SessionState;
+use datafusion::logical_expr::Expr;
+use datafusion::physical_plan::ExecutionPlan;
+use evolution_converter::envelope::Envelope;
+use evolution_schema::schema::FixedSchema;
+use evolution_slicer::slicer::FileSlicer;
+
+use std::any::Any;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use crate::exec::FixedLengthExec;
+
+/// A DataFusion [`TableProvider`] for a fixed-length file, which makes it possible to query
+/// the file with SQL or the DataFrame API without first converting it.
+///
+/// Scans of the table only parse the projected columns, and the file is split into row
+/// aligned byte ranges, one for each of the target partitions of the session. Skipped lines,
+/// header, and trailer records declared by the schema are not part of the table.
+///
+/// # Examples
+///
+/// ```no_run
+/// use datafusion::prelude::SessionContext;
+/// use evolution_datafusion::table::FixedLengthTable;
+/// use evolution_schema::schema::FixedSchema;
+/// use std::path::PathBuf;
+/// use std::sync::Arc;
+///
+/// # async fn example() -> datafusion::error::Result<()> {
+/// let schema: FixedSchema = FixedSchema::from_path(PathBuf::from(r"/path/to/my/schema.json")).unwrap();
+/// let table = FixedLengthTable::new(PathBuf::from(r"/path/to/my/file.flf"), schema);
+///
+/// let ctx = SessionContext::new();
+/// ctx.register_table("people", Arc::new(table))?;
+/// ctx.sql("SELECT name FROM people").await?.show().await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct FixedLengthTable {
+    path: PathBuf,
+    schema: FixedSchema,
+    arrow_schema: ArrowSchemaRef,
+}
+
+impl FixedLengthTable {
+    /// Create a new [`FixedLengthTable`] for the fixed-length file at the path, which
+    /// adheres to the provided [`FixedSchema`].
+    pub fn new(path: PathBuf, schema: FixedSchema) -> Self {
+        let arrow_schema: ArrowSchemaRef = Arc::new(schema.clone().into_arrow_schema());
+        Self {
+            path,
+            schema,
+            arrow_schema,
+        }
+    }
+
+    /// Get the path to the fixed-length file.
+    pub fn path(&self) -> &PathBuf {
+        &self.path
+    }
+
+    /// Get the [`FixedSchema`] of the fixed-length file.
+    pub fn fixed_schema(&self) -> &FixedSchema {
+        &self.schema
+    }
+}
+
+#[async_trait]
+impl TableProvider for FixedLengthTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema(&self) -> ArrowSchemaRef {
+        self.arrow_schema.clone()
+    }
+
+    fn table_type(&self) -> TableType {
+        TableType::Base
+    }
+
+    async fn scan(
+        &self,
+        state: &SessionState,
+        projection: Option<&Vec<usize>>,
+        _filters: &[Expr],
+        _limit: Option<usize>,
+    ) -> DataFusionResult<Arc<dyn ExecutionPlan>> {
+        let projection: Vec<usize> = match projection {
+            Some(p) => p.clone(),
+            None => (0..self.schema.num_columns()).collect::<Vec<usize>>(),
+        };
+
+        // Any skipped lines, header, and trailer records are split off, so that the partitions
+        // only read the body records of the file.
+        let ranges: Vec<(usize, usize)> = FileSlicer::try_from_path(self.path.clone())
+            .and_then(|mut s| {
+                Envelope::try_read(&mut s, &self.schema)?;
+                s.try_find_row_aligned_ranges(state.config().target_partitions())
+            })
+            .map_err(|e| DataFusionError::Execution(e.to_string()))?;
+
+        Ok(Arc::new(FixedLengthExec::try_new(
+            self.path.clone(),
+            self.schema.clone(),
+            projection,
+            ranges,
+        )?))
+    }
+}
+
+#[cfg(test)]
+mod tests_table {
+    use super::*;
+    use arrow::array::{Array, Int32Array, ListArray, RecordBatch, StringArray};
+    use datafusion::prelude::{SessionConfig, SessionContext};
+    use evolution_common::datatype::DataType;
+    use evolution_schema::column::{FixedColumn, Occurs};
+    use padder::{Alignment, Symbol};
+
+    use std::fs;
+
+    #[tokio::test]
+    async fn test_query_fixed_length_table() {
+        let columns: Vec<FixedColumn> = vec![
+            FixedColumn::new(
+                String::from("id"),
+                0,
+                4,
+                DataType::Int32,
+                Alignment::Right,
+                Symbol::Whitespace,
+                false,
+            ),
+            FixedColumn::new(
+                String::from("city"),
+                4,
+                6,
+                DataType::Utf8,
+                Alignment::Left,
+                Symbol::Whitespace,
+                false,
+            ),
+            FixedColumn::new(
+                String::from("amount"),
+                10,
+                5,
+                DataType::Int64,
+                Alignment::Right,
+                Symbol::Whitespace,
+                true,
+            ),
+        ];
+        let schema: FixedSchema = FixedSchema::new(String::from("Cities"), 1, columns);
+
+        let mut path: PathBuf = std::env::temp_dir();
+        path.push("evolution_datafusion_test_query_fixed_length_table.flf");
+        let data: String = (0..100)
+            .map(|i| {
+                format!(
+                    "{:>4}{:<6}{:>5}\n",
+                    i,
+                    if i % 2 == 0 { "Malmö" } else { "Luleå" },
+                    i * 10
+                )
+            })
+            .collect::<String>();
+        fs::write(&path, data).unwrap();
+
+        let ctx = SessionContext::new_with_config(SessionConfig::new().with_target_partitions(3));
+        ctx.register_table(
+            "cities",
+            Arc::new(FixedLengthTable::new(path.clone(), schema)),
+        )
+        .unwrap();
+
+        let batches: Vec<RecordBatch> = ctx
+            .sql("SELECT city, id FROM cities WHERE id >= 95 ORDER BY id")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+
+        let batch: &RecordBatch = &batches[0];
+        assert_eq!(2, batch.num_columns());
+        assert_eq!(5, batch.num_rows());
+
+        let cities = batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        let ids = batch
+            .column(1)
+            .as_any()
+            .downcast_ref::<Int32Array>()
+            .unwrap();
+        assert_eq!("Luleå", cities.value(0));
+        assert_eq!(95, ids.value(0));
+        assert_eq!("Malmö", cities.value(1));
+        assert_eq!(99, ids.value(4));
+
+        let counts: Vec<RecordBatch> = ctx
+            .sql("SELECT COUNT(*) AS n FROM cities")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(
+            "100",
+            arrow::util::display::array_value_to_string(counts[0].column(0), 0).unwrap(),
+        );
+
+        fs::remove_file(path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_query_list_without_its_count_column() {
+        let columns: Vec<FixedColumn> = vec![
+            FixedColumn::new(
+                String::from("id"),
+                0,
+                3,
+                DataType::Int32,
+                Alignment::Right,
+                Symbol::Whitespace,
+                false,
+            ),
+            FixedColumn::new(
+                String::from("n_amounts"),
+                3,
+                1,
+                DataType::Int16,
+                Alignment::Right,
+                Symbol::Whitespace,
+                false,
+            ),
+            FixedColumn::new(
+                String::from("amounts"),
+                4,
+                4,
+                DataType::Int32,
+                Alignment::Right,
+                Symbol::Whitespace,
+                true,
+            )
+            .with_occurs(Occurs::new(3).with_depending_on(String::from("n_amounts"))),
+        ];
+        let schema: FixedSchema = FixedSchema::new(String::from("Amounts"), 1, columns);
+
+        let mut path: PathBuf = std::env::temp_dir();
+        path.push("evolution_datafusion_test_query_list_without_its_count_column.flf");
+        let data: String = (0..10)
+            .map(|i| {
+                let amounts: String = (0..3)
+                    .map(|k| match k < i % 4 {
+                        true => format!("{:>4}", i * 10 + k),
+                        false => String::from("    "),
+                    })
+                    .collect::<String>();
+                format!("{:>3}{}{}\n", i, i % 4, amounts)
+            })
+            .collect::<String>();
+        fs::write(&path, data).unwrap();
+
+        let ctx = SessionContext::new_with_config(SessionConfig::new().with_target_partitions(2));
+        ctx.register_table(
+            "amounts",
+            Arc::new(FixedLengthTable::new(path.clone(), schema)),
+        )
+        .unwrap();
+
+        let batches: Vec<RecordBatch> = ctx
+            .sql("SELECT amounts FROM amounts ORDER BY id")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+
+        let mut lists: Vec<Vec<i32>> = Vec::new();
+        for batch in batches.iter() {
+            assert_eq!(1, batch.num_columns());
+            let amounts = batch
+                .column(0)
+                .as_any()
+                .downcast_ref::<ListArray>()
+                .unwrap();
+            for idx in 0..amounts.len() {
+                let list = amounts.value(idx);
+                let values = list.as_any().downcast_ref::<Int32Array>().unwrap();
+                lists.push(values.values().to_vec());
+            }
+        }
+
+        let expected: Vec<Vec<i32>> = (0..10)
+            .map(|i: i32| (0..i % 4).map(|k| i * 10 + k).collect::<Vec<i32>>())
+            .collect();
+        assert_eq!(expected, lists);
+
+        fs::remove_file(path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_query_table_without_header_and_trailer() {
+        let schema: FixedSchema = FixedSchema::from_path(
+            PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+                .join("../evolution-schema/res/test_valid_envelope_schema.json"),
+        )
+        .unwrap();
+
+        let mut path: PathBuf = std::env::temp_dir();
+        path.push("evolution_datafusion_test_query_table_without_header_and_trailer.flf");
+        let rows: String = (0..50)
+            .map(|i| format!("{:>4}SE{:>6}\n", i, i * 10))
+            .collect::<String>();
+        fs::write(
+            &path,
+            format!("SKIPPED\n20261018ACME      \n{}00000050     12250\n", rows),
+        )
+        .unwrap();
+
+        let ctx = SessionContext::new_with_config(SessionConfig::new().with_target_partitions(3));
+        ctx.register_table(
+            "amounts",
+            Arc::new(FixedLengthTable::new(path.clone(), schema)),
+        )
+        .unwrap();
+
+        let batches: Vec<RecordBatch> = ctx
+            .sql("SELECT COUNT(*), MIN(id), MAX(id), SUM(amount) FROM amounts")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+
+        let values: Vec<String> = (0..4)
+            .map(|idx| {
+                arrow::util::display::array_value_to_string(batches[0].column(idx), 0).unwrap()
+            })
+            .collect();
+        assert_eq!(vec!["50", "0", "49", "12250"], values);
+
+        fs::remove_file(path).unwrap();
+    }
+}
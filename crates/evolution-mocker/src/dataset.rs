@@ -0,0 +1,722 @@
+//
+// MIT License
+//
+// Copyright (c) 2026 Firelink Data
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//
+// File created: 2026-10-18
+// Last updated: 2026-10-18
+//
+
+//! Mocking of datasets of several fixed-length files whose columns relate by foreign keys,
+//! e.g. an `accounts.flf` file whose `customer_id` column references the `customers.flf` file.
+//!
+//! A dataset is specified in a json file, where relative paths are relative to the directory
+//! of the file, e.g.
+//!
+//! ```json
+//! {
+//!     "files": [
+//!         {
+//!             "name": "customers",
+//!             "schema": "customers.json",
+//!             "out_file": "customers.flf",
+//!             "n_rows": 1000
+//!         },
+//!         {
+//!             "name": "accounts",
+//!             "schema": "accounts.json",
+//!             "out_file": "accounts.flf",
+//!             "n_rows": 5000,
+//!             "config": "accounts_config.json",
+//!             "null_ratio": 0.1
+//!         }
+//!     ],
+//!     "foreign_keys": [
+//!         {
+//!             "column": { "file": "accounts", "column": "customer_id" },
+//!             "references": { "file": "customers", "column": "customer_id" }
+//!         }
+//!     ]
+//! }
+//! ```
+//!
+//! The referenced columns are mocked as sequences of keys, by their `sequence` generator if
+//! they have one and from 0 by 1 otherwise, and are never left blank. The foreign key columns
+//! are mocked by `keys` generators, choosing from the keys of the columns they reference. Since
+//! every key is known from the number of rows of its file, all files are mocked at the same
+//! time, independently of each other.
+
+use evolution_common::datatype::DataType;
+use evolution_common::error::{ExecutionError, Result, SetupError};
+use evolution_schema::column::FixedColumn;
+use evolution_schema::schema::FixedSchema;
+use log::info;
+use rand::rngs::StdRng;
+use rand::{RngCore, SeedableRng};
+use serde::{Deserialize, Serialize};
+
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+
+use crate::find_column;
+use crate::generator::{GeneratorSpec, MockConfig};
+use crate::mocker::FixedLengthFileMocker;
+
+/// The specification of a dataset of mocked files and the foreign keys between them.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct DatasetSpec {
+    files: Vec<DatasetFileSpec>,
+    #[serde(default)]
+    foreign_keys: Vec<ForeignKey>,
+}
+
+impl DatasetSpec {
+    /// Create a new [`DatasetSpec`] by reading a .json file at the provided path, resolving
+    /// the relative paths of its files against the directory of the file.
+    ///
+    /// # Errors
+    /// If the file could not be read, or if it is not a valid dataset specification.
+    pub fn from_path(path: PathBuf) -> Result<Self> {
+        let mut spec: Self = serde_json::from_slice(&fs::read(&path)?)?;
+        let dir: &Path = path.parent().unwrap_or(Path::new(""));
+        for file in spec.files.iter_mut() {
+            file.schema = dir.join(&file.schema);
+            file.out_file = dir.join(&file.out_file);
+            file.config = file.config.as_ref().map(|c| dir.join(c));
+        }
+        Ok(spec)
+    }
+
+    /// Get the specifications of the files of the dataset.
+    pub fn files(&self) -> &[DatasetFileSpec] {
+        &self.files
+    }
+
+    /// Get the foreign keys between the files of the dataset.
+    pub fn foreign_keys(&self) -> &[ForeignKey] {
+        &self.foreign_keys
+    }
+}
+
+/// The specification of a file of a dataset.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct DatasetFileSpec {
+    /// The name that foreign keys refer to the file by.
+    name: String,
+    /// The json schema of the file.
+    schema: PathBuf,
+    /// The file to write the mocked rows to.
+    out_file: PathBuf,
+    /// The number of rows to mock.
+    n_rows: usize,
+    /// The mock config of the file, if any.
+    #[serde(default)]
+    config: Option<PathBuf>,
+    /// The ratio of blank fields of the nullable columns of the file, if any.
+    #[serde(default)]
+    null_ratio: Option<f64>,
+}
+
+impl DatasetFileSpec {
+    /// Get the name of the file.
+    pub fn name(&self) -> &String {
+        &self.name
+    }
+
+    /// Get the path of the mocked file.
+    pub fn out_file(&self) -> &PathBuf {
+        &self.out_file
+    }
+
+    /// Get the number of rows to mock.
+    pub fn n_rows(&self) -> usize {
+        self.n_rows
+    }
+}
+
+/// A column whose values are keys of another column, possibly of the same file.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ForeignKey {
+    column: ColumnRef,
+    references: ColumnRef,
+}
+
+/// A column of a file of a dataset.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[serde(deny_unknown_fields)]
+pub struct ColumnRef {
+    file: String,
+    column: String,
+}
+
+/// The mocker of all the files of a dataset.
+pub struct DatasetMocker {
+    /// The mockers of the files, with the names of the files.
+    mockers: Vec<(String, FixedLengthFileMocker)>,
+}
+
+impl DatasetMocker {
+    /// Create a new instance of a [`DatasetMockerBuilder`] with default values.
+    pub fn builder() -> DatasetMockerBuilder {
+        DatasetMockerBuilder {
+            ..Default::default()
+        }
+    }
+
+    /// Try and mock all the files of the dataset, each in a thread of its own.
+    ///
+    /// # Errors
+    /// If any of the files could not be mocked, see [`FixedLengthFileMocker::try_mock`].
+    pub fn try_mock(&mut self) -> Result<()> {
+        let results: Vec<(&String, std::result::Result<(), String>)> = thread::scope(|s| {
+            let handles = self
+                .mockers
+                .iter_mut()
+                .map(|(name, mocker)| {
+                    info!("Mocking file '{}' of the dataset.", name);
+                    // The errors are not `Send`, so only their messages leave the threads.
+                    (
+                        &*name,
+                        s.spawn(move || mocker.try_mock().map_err(|e| e.to_string())),
+                    )
+                })
+                .collect::<Vec<_>>();
+
+            handles
+                .into_iter()
+                .map(|(name, handle)| {
+                    let result = handle.join().unwrap_or_else(|_| {
+                        panic!(
+                            "The thread of file '{}' could not join the master thread!",
+                            name
+                        )
+                    });
+                    (name, result)
+                })
+                .collect()
+        });
+
+        for (name, result) in results {
+            if let Err(e) = result {
+                return Err(Box::new(ExecutionError::new(&format!(
+                    "Could not mock file '{}' of the dataset: {}",
+                    name, e,
+                ))));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// A helper struct for building an instance of a [`DatasetMocker`] struct.
+#[derive(Default)]
+pub struct DatasetMockerBuilder {
+    spec_path: Option<PathBuf>,
+    n_threads: Option<usize>,
+    write_buffer_size: Option<usize>,
+    thread_channel_capacity: Option<usize>,
+    seed: Option<u64>,
+
+    // File descriptor properties.
+    force_create_new: Option<bool>,
+    truncate_existing: Option<bool>,
+}
+
+impl DatasetMockerBuilder {
+    /// Set the relative or absolute path to the json dataset specification file to use.
+    pub fn with_spec(mut self, spec_path: PathBuf) -> Self {
+        self.spec_path = Some(spec_path);
+        self
+    }
+
+    /// Set the number of threads (logical cores) to use, which are shared equally by the
+    /// files, with at least one thread per file.
+    pub fn with_num_threads(mut self, n_threads: usize) -> Self {
+        self.n_threads = Some(n_threads);
+        self
+    }
+
+    /// Set the buffer size for writing to file (in number of rows).
+    pub fn with_write_buffer_size(mut self, buffer_size: usize) -> Self {
+        self.write_buffer_size = Some(buffer_size);
+        self
+    }
+
+    pub fn with_thread_channel_capacity(mut self, capacity: Option<usize>) -> Self {
+        self.thread_channel_capacity = capacity;
+        self
+    }
+
+    /// Set the seed to derive the seeds of each of the files from, producing the same dataset
+    /// for the same seed (with the same version of evolution).
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Set the writer option to return an error if any of the files already exists.
+    pub fn with_force_create_new(mut self, force_create_new: bool) -> Self {
+        self.force_create_new = Some(force_create_new);
+        self
+    }
+
+    /// Set the writer option to truncate any of the files if it already exists.
+    pub fn with_truncate_existing(mut self, truncate_existing: bool) -> Self {
+        self.truncate_existing = Some(truncate_existing);
+        self
+    }
+
+    /// Set default values for the optional configuration fields.
+    pub fn with_default_values(mut self) -> Self {
+        self.n_threads = Some(1);
+        self.write_buffer_size = Some(1000);
+        self.force_create_new = Some(true);
+        self.truncate_existing = Some(true);
+        self.thread_channel_capacity = Some(1);
+        self
+    }
+
+    /// Try creating a new [`DatasetMocker`] from the previously set values.
+    ///
+    /// # Errors
+    /// If any of the required fields are `None`, if the dataset specification is invalid, e.g.
+    /// it has no files or files with the same name or output file, if a foreign key refers to a
+    /// file or column which does not exist, if a referenced column has a generator which is not
+    /// a sequence, if a foreign key column already has a generator, if the keys do not fit
+    /// their columns, or if any of the mockers of the files could not be built, see
+    /// [`FixedLengthFileMockerBuilder::try_build`].
+    ///
+    /// [`FixedLengthFileMockerBuilder::try_build`]: crate::mocker::FixedLengthFileMockerBuilder::try_build
+    pub fn try_build(self) -> Result<DatasetMocker> {
+        let spec: DatasetSpec = match self.spec_path {
+            Some(p) => DatasetSpec::from_path(p)?,
+            None => {
+                return Err(Box::new(SetupError::new(
+                    "Required field 'spec_path' was not provided, exiting...",
+                )))
+            }
+        };
+
+        let n_threads: usize = self.n_threads.ok_or_else(|| {
+            Box::new(SetupError::new(
+                "Required field 'n_threads' was not provided, exiting...",
+            ))
+        })?;
+
+        let write_buffer_size: usize = self.write_buffer_size.ok_or_else(|| {
+            Box::new(SetupError::new(
+                "Required field 'write_buffer_size' was not provided, exiting...",
+            ))
+        })?;
+
+        let force_create_new: bool = self.force_create_new.ok_or_else(|| {
+            Box::new(SetupError::new(
+                "Required field 'force_create_new' was not provided, exiting...",
+            ))
+        })?;
+
+        let truncate_existing: bool = self.truncate_existing.ok_or_else(|| {
+            Box::new(SetupError::new(
+                "Required field 'truncate_existing' was not provided, exiting...",
+            ))
+        })?;
+
+        if spec.files.is_empty() {
+            return Err(Box::new(SetupError::new(
+                "The dataset does not have any files, exiting...",
+            )));
+        }
+
+        let mut names: HashSet<&String> = HashSet::new();
+        let mut out_files: HashSet<&PathBuf> = HashSet::new();
+        for file in spec.files.iter() {
+            if !names.insert(&file.name) || !out_files.insert(&file.out_file) {
+                return Err(Box::new(SetupError::new(&format!(
+                    "The dataset has several files with the name '{}' or output file '{}', exiting...",
+                    file.name,
+                    file.out_file.display(),
+                ))));
+            }
+        }
+
+        let schemas: HashMap<&String, FixedSchema> = spec
+            .files
+            .iter()
+            .map(|f| Ok((&f.name, FixedSchema::from_path(f.schema.clone())?)))
+            .collect::<Result<_>>()?;
+
+        let mut generator_specs: HashMap<&String, HashMap<String, GeneratorSpec>> = spec
+            .files
+            .iter()
+            .map(|f| {
+                let specs: HashMap<String, GeneratorSpec> = match &f.config {
+                    Some(p) => MockConfig::from_path(p.clone())?.into_columns(),
+                    None => HashMap::new(),
+                };
+                Ok((&f.name, specs))
+            })
+            .collect::<Result<_>>()?;
+
+        let mut key_columns: HashSet<&ColumnRef> = HashSet::new();
+        for foreign_key in spec.foreign_keys.iter() {
+            let (referenced_file, referenced_column) =
+                try_find_column(&spec, &schemas, &foreign_key.references)?;
+            let (_, column) = try_find_column(&spec, &schemas, &foreign_key.column)?;
+
+            let n_keys: usize = referenced_file.n_rows;
+            if n_keys == 0 {
+                return Err(Box::new(SetupError::new(&format!(
+                    "Column '{}' of file '{}' references file '{}' which does not have any rows, exiting...",
+                    foreign_key.column.column, foreign_key.column.file, referenced_file.name,
+                ))));
+            }
+
+            // The referenced keys are the first keys of the sequence of the referenced column.
+            let referenced_specs = generator_specs
+                .get_mut(&referenced_file.name)
+                .expect("The generators of every file were created above");
+            let (start, step): (i64, i64) = match referenced_specs.get(referenced_column.name()) {
+                Some(GeneratorSpec::Sequence { start, step }) => (*start, *step),
+                Some(_) => {
+                    return Err(Box::new(SetupError::new(&format!(
+                        "Column '{}' of file '{}' is referenced by a foreign key but its generator is not a sequence, exiting...",
+                        foreign_key.references.column, foreign_key.references.file,
+                    ))))
+                }
+                None => {
+                    let (start, step): (i64, i64) = (0, 1);
+                    referenced_specs.insert(
+                        referenced_column.name().clone(),
+                        GeneratorSpec::Sequence { start, step },
+                    );
+                    (start, step)
+                }
+            };
+
+            let last: Option<i64> = step
+                .checked_mul(n_keys as i64 - 1)
+                .and_then(|d| start.checked_add(d));
+            try_check_keys_fit(referenced_column, &foreign_key.references, start, last)?;
+            try_check_keys_fit(column, &foreign_key.column, start, last)?;
+
+            let specs = generator_specs
+                .get_mut(&foreign_key.column.file)
+                .expect("The generators of every file were created above");
+            if specs.contains_key(&foreign_key.column.column) {
+                return Err(Box::new(SetupError::new(&format!(
+                    "Column '{}' of file '{}' is a foreign key but already has a generator, exiting...",
+                    foreign_key.column.column, foreign_key.column.file,
+                ))));
+            }
+            specs.insert(
+                foreign_key.column.column.clone(),
+                GeneratorSpec::Keys {
+                    n: n_keys as u64,
+                    start,
+                    step,
+                },
+            );
+
+            if referenced_column.is_nullable() {
+                key_columns.insert(&foreign_key.references);
+            }
+        }
+
+        // Every file is mocked from a seed of its own, derived from the seed of the dataset in
+        // the order of the files, so that the files do not depend on the order they finish in.
+        let mut seed_rng: Option<StdRng> = self.seed.map(StdRng::seed_from_u64);
+        let n_file_threads: usize = (n_threads / spec.files.len()).max(1);
+
+        let mockers: Vec<(String, FixedLengthFileMocker)> = spec
+            .files
+            .iter()
+            .map(|file| {
+                let mut builder = FixedLengthFileMocker::builder()
+                    .with_schema(file.schema.clone())
+                    .with_out_file(file.out_file.clone())
+                    .with_num_rows(file.n_rows)
+                    .with_num_threads(n_file_threads)
+                    .with_write_buffer_size(write_buffer_size)
+                    .with_thread_channel_capacity(self.thread_channel_capacity)
+                    .with_force_create_new(force_create_new)
+                    .with_truncate_existing(truncate_existing);
+
+                if let Some(rng) = seed_rng.as_mut() {
+                    builder = builder.with_seed(rng.next_u64());
+                }
+
+                if let Some(null_ratio) = file.null_ratio {
+                    builder = builder.with_null_ratio(null_ratio);
+                }
+
+                // The referenced keys must all be in their files.
+                for key_column in key_columns.iter().filter(|c| c.file == file.name) {
+                    builder = builder.with_column_null_ratio(key_column.column.clone(), 0.0);
+                }
+
+                for (column, generator_spec) in generator_specs[&file.name].iter() {
+                    builder = builder.with_generator(column.clone(), generator_spec.clone());
+                }
+
+                Ok((file.name.clone(), builder.try_build()?))
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(DatasetMocker { mockers })
+    }
+
+    /// Creates a new [`DatasetMocker`] from the previously set values.
+    ///
+    /// # Panics
+    /// If any of the required fields are `None`, or if the dataset is invalid, see
+    /// [`DatasetMockerBuilder::try_build`].
+    pub fn build(self) -> DatasetMocker {
+        self.try_build().unwrap()
+    }
+}
+
+/// Try and find the file and the column of the dataset that the [`ColumnRef`] refers to.
+///
+/// # Errors
+/// If the dataset does not have the file, or if the schema of the file does not have the column.
+fn try_find_column<'a>(
+    spec: &'a DatasetSpec,
+    schemas: &'a HashMap<&String, FixedSchema>,
+    column_ref: &ColumnRef,
+) -> Result<(&'a DatasetFileSpec, &'a FixedColumn)> {
+    let file: &DatasetFileSpec = match spec.files.iter().find(|f| f.name == column_ref.file) {
+        Some(f) => f,
+        None => {
+            return Err(Box::new(SetupError::new(&format!(
+                "A foreign key refers to the file '{}' which is not in the dataset, exiting...",
+                column_ref.file,
+            ))))
+        }
+    };
+
+    match find_column(schemas[&file.name].columns(), &column_ref.column) {
+        Some(column) => Ok((file, column)),
+        None => Err(Box::new(SetupError::new(&format!(
+            "A foreign key refers to the column '{}' which is not in the schema of file '{}', exiting...",
+            column_ref.column, column_ref.file,
+        )))),
+    }
+}
+
+/// Check that the keys of a sequence, from `start` to `last`, all fit the column, i.e. that it
+/// is an integer or string column which every key can be written to without being cut.
+///
+/// # Errors
+/// If the column is neither an integer nor a string column, or if any key does not fit it.
+fn try_check_keys_fit(
+    column: &FixedColumn,
+    column_ref: &ColumnRef,
+    start: i64,
+    last: Option<i64>,
+) -> Result<()> {
+    let max: i64 = match column.dtype() {
+        DataType::Int16 => i16::MAX as i64,
+        DataType::Int32 => i32::MAX as i64,
+        DataType::Int64 | DataType::Utf8 | DataType::LargeUtf8 => i64::MAX,
+        dtype => {
+            return Err(Box::new(SetupError::new(&format!(
+                "Column '{}' of file '{}' is a key of the dtype {:?}, but keys must be integer or string columns, exiting...",
+                column_ref.column, column_ref.file, dtype,
+            ))))
+        }
+    };
+
+    let fits = |key: i64| key.abs() <= max && key.to_string().len() <= column.element_length();
+    match last {
+        Some(last) if fits(start) && fits(last) => Ok(()),
+        _ => Err(Box::new(SetupError::new(&format!(
+            "The keys of column '{}' of file '{}' do not fit its length of {}, exiting...",
+            column_ref.column,
+            column_ref.file,
+            column.element_length(),
+        )))),
+    }
+}
+
+#[cfg(test)]
+mod tests_dataset {
+    use super::*;
+    use serde_json::{json, Value};
+
+    /// Write a dataset of customers and their accounts, with the foreign keys, to a file of
+    /// its own in the temporary directory, where the mocked files are also written.
+    fn write_spec(name: &str, n_customers: usize, foreign_keys: Value) -> PathBuf {
+        let res: PathBuf = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("res");
+        let spec: Value = json!({
+            "files": [
+                {
+                    "name": "customers",
+                    "schema": res.join("test_customers_schema.json"),
+                    "out_file": format!("evolution_test_{}_customers.flf", name),
+                    "n_rows": n_customers,
+                    "null_ratio": 0.3
+                },
+                {
+                    "name": "accounts",
+                    "schema": res.join("test_accounts_schema.json"),
+                    "out_file": format!("evolution_test_{}_accounts.flf", name),
+                    "n_rows": 3000,
+                    "null_ratio": 0.3
+                }
+            ],
+            "foreign_keys": foreign_keys,
+        });
+
+        let spec_path: PathBuf = std::env::temp_dir().join(format!("evolution_test_{}.json", name));
+        fs::write(&spec_path, spec.to_string()).unwrap();
+        spec_path
+    }
+
+    fn mock(spec_path: &Path) -> Result<()> {
+        DatasetMocker::builder()
+            .with_spec(spec_path.to_path_buf())
+            .with_default_values()
+            .with_force_create_new(false)
+            .with_num_threads(2)
+            .with_seed(3)
+            .try_build()?
+            .try_mock()
+    }
+
+    /// Read the trimmed fields of a column from a mocked file.
+    fn read_column(path: &PathBuf, offset: usize, length: usize) -> Vec<String> {
+        fs::read_to_string(path)
+            .unwrap()
+            .lines()
+            .map(|l| l[offset..offset + length].trim().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_mock_dataset() {
+        let spec_path: PathBuf = write_spec(
+            "dataset",
+            500,
+            json!([
+                {
+                    "column": { "file": "accounts", "column": "customer_id" },
+                    "references": { "file": "customers", "column": "customer_id" }
+                },
+                {
+                    "column": { "file": "accounts", "column": "parent_account_id" },
+                    "references": { "file": "accounts", "column": "account_id" }
+                }
+            ]),
+        );
+        let spec: DatasetSpec = DatasetSpec::from_path(spec_path.clone()).unwrap();
+        let customers_path: &PathBuf = spec.files()[0].out_file();
+        let accounts_path: &PathBuf = spec.files()[1].out_file();
+
+        mock(&spec_path).unwrap();
+        let customers: Vec<u8> = fs::read(customers_path).unwrap();
+        let accounts: Vec<u8> = fs::read(accounts_path).unwrap();
+
+        let customer_ids: Vec<String> = read_column(customers_path, 0, 8);
+        let account_ids: Vec<String> = read_column(accounts_path, 0, 12);
+        let foreign_customer_ids: Vec<String> = read_column(accounts_path, 12, 10);
+        let parent_account_ids: Vec<String> = read_column(accounts_path, 22, 12);
+
+        // The referenced keys are never blank, and all unique.
+        assert_eq!(500, customer_ids.iter().collect::<HashSet<_>>().len());
+        assert!(customer_ids.iter().all(|id| !id.is_empty()));
+
+        for (foreign_keys, keys) in [
+            (&foreign_customer_ids, &customer_ids),
+            (&parent_account_ids, &account_ids),
+        ] {
+            assert!(foreign_keys.iter().any(|id| id.is_empty()));
+            assert!(foreign_keys
+                .iter()
+                .filter(|id| !id.is_empty())
+                .all(|id| keys.contains(id)));
+        }
+
+        // The same seed always produces the same dataset.
+        mock(&spec_path).unwrap();
+        assert_eq!(customers, fs::read(customers_path).unwrap());
+        assert_eq!(accounts, fs::read(accounts_path).unwrap());
+
+        fs::remove_file(customers_path).unwrap();
+        fs::remove_file(accounts_path).unwrap();
+        fs::remove_file(spec_path).unwrap();
+    }
+
+    #[test]
+    fn test_invalid_datasets() {
+        let foreign_key = |column: &str, file: &str, referenced: &str| {
+            json!({
+                "column": { "file": "accounts", "column": column },
+                "references": { "file": file, "column": referenced }
+            })
+        };
+
+        for (name, n_customers, foreign_keys) in [
+            (
+                "missing_column",
+                500,
+                json!([foreign_key("customer_id", "customers", "id")]),
+            ),
+            (
+                "missing_file",
+                500,
+                json!([foreign_key("customer_id", "clients", "customer_id")]),
+            ),
+            (
+                "keys_do_not_fit",
+                1_000_000_000,
+                json!([foreign_key("customer_id", "customers", "customer_id")]),
+            ),
+            (
+                "no_keys",
+                0,
+                json!([foreign_key("customer_id", "customers", "customer_id")]),
+            ),
+            (
+                "not_a_key",
+                500,
+                json!([foreign_key("balance", "customers", "customer_id")]),
+            ),
+            (
+                "not_a_sequence",
+                500,
+                json!([
+                    foreign_key("account_id", "customers", "customer_id"),
+                    foreign_key("parent_account_id", "accounts", "account_id"),
+                ]),
+            ),
+        ] {
+            let spec_path: PathBuf = write_spec(name, n_customers, foreign_keys);
+            assert!(mock(&spec_path).is_err(), "{}", name);
+            fs::remove_file(spec_path).unwrap();
+        }
+    }
+}
@@ -0,0 +1,508 @@
+//
+// MIT License
+//
+// Copyright (c) 2026 Firelink Data
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//
+// File created: 2026-10-18
+// Last updated: 2026-10-18
+//
+
+//! Fault injection of the mocker, which corrupts some of the mocked rows with the kinds of bad
+//! data found in real fixed-length files, and records exactly which rows were corrupted and
+//! how in a [`FaultManifest`].
+
+use evolution_common::datatype::DataType;
+use evolution_common::error::{Result, SetupError};
+use evolution_common::newline;
+use evolution_schema::column::FixedColumn;
+use evolution_schema::schema::FixedSchema;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use crate::pad_and_push_runes_to_buffer;
+
+/// The value written to the fields of unparseable values, which none of the non-string
+/// datatypes can be parsed from.
+pub static UNPARSEABLE_VALUE: &str = "?";
+/// The byte written to the fields with invalid UTF-8, which never occurs in valid UTF-8.
+pub static INVALID_UTF8_BYTE: u8 = 0xFF;
+/// The maximum number of characters appended to over-long lines.
+pub static MAX_OVERLONG_CHARS: usize = 8;
+
+/// The kinds of faults that can be injected into a mocked file.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum FaultKind {
+    /// The field of a non-nullable, non-string column holds a value that can not be parsed.
+    UnparseableValue,
+    /// The line is shorter than the row length of the schema.
+    TruncatedLine,
+    /// The line is longer than the row length of the schema.
+    OverlongLine,
+    /// The first byte of a field is not valid UTF-8.
+    InvalidUtf8,
+    /// The line is terminated by the newline of the other platform, `\r\n` instead of `\n` or
+    /// the other way around.
+    WrongLineTerminator,
+    /// The last line of the file is not terminated by a newline. Its rate is the probability of
+    /// the file having this fault.
+    MissingTrailingNewline,
+}
+
+impl FromStr for FaultKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        serde_json::from_value(serde_json::Value::String(s.to_string()))
+            .map_err(|_| format!("unknown fault kind '{}'", s))
+    }
+}
+
+/// A fault injected into a row of a mocked file.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct Fault {
+    /// The index of the row in the file, starting from 0.
+    row: usize,
+    /// The kind of fault.
+    kind: FaultKind,
+    /// The name of the column whose field has the fault, if it is not the entire line.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    column: Option<String>,
+}
+
+impl Fault {
+    /// Get the index of the row in the file, starting from 0.
+    pub fn row(&self) -> usize {
+        self.row
+    }
+
+    /// Get the kind of fault.
+    pub fn kind(&self) -> FaultKind {
+        self.kind
+    }
+
+    /// Get the name of the column whose field has the fault, if it is not the entire line.
+    pub fn column(&self) -> Option<&str> {
+        self.column.as_deref()
+    }
+}
+
+/// The manifest of all of the faults injected into a mocked file, in order of their rows.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct FaultManifest {
+    /// The number of rows of the file.
+    n_rows: usize,
+    /// The injected faults.
+    faults: Vec<Fault>,
+}
+
+impl FaultManifest {
+    /// Create a new [`FaultManifest`] from the provided field values.
+    pub fn new(n_rows: usize, faults: Vec<Fault>) -> Self {
+        Self { n_rows, faults }
+    }
+
+    /// Create a new [`FaultManifest`] by reading a .json file at the provided path.
+    ///
+    /// # Errors
+    /// If the file could not be read, or if it is not a valid fault manifest.
+    pub fn from_path(path: PathBuf) -> Result<Self> {
+        let manifest: Self = serde_json::from_slice(&fs::read(path)?)?;
+        Ok(manifest)
+    }
+
+    /// Get the number of rows of the file.
+    pub fn n_rows(&self) -> usize {
+        self.n_rows
+    }
+
+    /// Get the injected faults.
+    pub fn faults(&self) -> &Vec<Fault> {
+        &self.faults
+    }
+
+    /// Try and write the [`FaultManifest`] as json to a file at the provided path.
+    ///
+    /// # Errors
+    /// If the manifest could not be serialized or the file could not be written.
+    pub fn try_write(&self, path: PathBuf) -> Result<()> {
+        fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+}
+
+/// A column of the schema that faults can be injected into.
+#[derive(Clone, Debug)]
+struct FaultColumn {
+    name: String,
+    offset: usize,
+    length: usize,
+    column: FixedColumn,
+}
+
+/// An injector of faults into the rows of a mocked file, which also terminates each row.
+#[derive(Clone, Debug, Default)]
+pub struct FaultInjector {
+    /// The rates of each of the kinds of faults.
+    rates: BTreeMap<FaultKind, f64>,
+    /// The non-nullable, non-string columns without a default value, for unparseable values.
+    parsed_columns: Vec<FaultColumn>,
+    /// All of the columns which are not redefinitions, for invalid UTF-8.
+    columns: Vec<FaultColumn>,
+    /// The number of runes in a row.
+    row_length: usize,
+}
+
+impl FaultInjector {
+    /// Try and create a new [`FaultInjector`] for the schema, with the provided rates of each of
+    /// the kinds of faults.
+    ///
+    /// # Errors
+    /// If any of the rates is not between 0 and 1, if the sum of the rates of the kinds of faults
+    /// of single rows is larger than 1, or if unparseable values are to be injected but the
+    /// schema does not have any non-nullable, non-string columns without a default value.
+    pub fn try_new(schema: &FixedSchema, rates: BTreeMap<FaultKind, f64>) -> Result<Self> {
+        if let Some((kind, rate)) = rates.iter().find(|(_, r)| !(0.0..=1.0).contains(*r)) {
+            return Err(Box::new(SetupError::new(&format!(
+                "The fault rate {} of '{:?}' is not between 0 and 1, exiting...",
+                rate, kind,
+            ))));
+        }
+
+        let row_rate: f64 = rates
+            .iter()
+            .filter(|(k, _)| **k != FaultKind::MissingTrailingNewline)
+            .map(|(_, r)| r)
+            .sum();
+        if row_rate > 1.0 {
+            return Err(Box::new(SetupError::new(&format!(
+                "The fault rates of rows sum to {} which is larger than 1, exiting...",
+                row_rate,
+            ))));
+        }
+
+        let columns: Vec<FaultColumn> = schema
+            .iter()
+            .enumerate()
+            .filter(|(idx, _)| !schema.is_redefinition(*idx))
+            .map(|(_, c)| FaultColumn {
+                name: c.name().clone(),
+                offset: c.offset(),
+                length: c.element_length(),
+                column: c.clone(),
+            })
+            .collect();
+
+        let parsed_columns: Vec<FaultColumn> = columns
+            .iter()
+            .filter(|c| {
+                !c.column.is_nullable()
+                    && c.column.default().is_none()
+                    && !matches!(
+                        c.column.dtype(),
+                        DataType::Utf8 | DataType::LargeUtf8 | DataType::Struct
+                    )
+            })
+            .cloned()
+            .collect();
+
+        if parsed_columns.is_empty()
+            && rates
+                .get(&FaultKind::UnparseableValue)
+                .is_some_and(|r| *r > 0.0)
+        {
+            return Err(Box::new(SetupError::new(
+                "Can not inject unparseable values without any non-nullable, non-string columns without a default value, exiting...",
+            )));
+        }
+
+        Ok(Self {
+            rates,
+            parsed_columns,
+            columns,
+            row_length: schema.row_length(),
+        })
+    }
+
+    /// Whether no faults are injected at all, i.e. none of the kinds of faults has a rate.
+    pub fn is_empty(&self) -> bool {
+        self.rates.values().all(|r| *r == 0.0)
+    }
+
+    /// Terminate the row, which starts at the index in the buffer, possibly after injecting a
+    /// fault into it, and get the injected faults. The last row of the file can also be missing
+    /// its trailing newline, besides any other fault.
+    pub fn finish_row(
+        &self,
+        row_idx: usize,
+        row_start: usize,
+        is_last_row: bool,
+        rng: &mut impl Rng,
+        buffer: &mut Vec<u8>,
+    ) -> Vec<Fault> {
+        // Only draw from the generator when there are faults to inject, so that files mocked
+        // from a seed without any faults stay the same.
+        let kind: Option<FaultKind> = match self.is_empty() {
+            true => None,
+            false => {
+                let draw: f64 = rng.gen();
+                let mut cumulative: f64 = 0.0;
+                self.rates
+                    .iter()
+                    .filter(|(k, _)| **k != FaultKind::MissingTrailingNewline)
+                    .find(|(_, r)| {
+                        cumulative += **r;
+                        draw < cumulative
+                    })
+                    .map(|(k, _)| *k)
+            }
+        };
+
+        let mut column: Option<String> = None;
+        match kind {
+            Some(FaultKind::UnparseableValue) => {
+                let c: &FaultColumn =
+                    &self.parsed_columns[rng.gen_range(0..self.parsed_columns.len())];
+                let (start, end) = byte_range(buffer, row_start, c.offset, c.length);
+
+                let mut field: Vec<u8> = Vec::with_capacity(c.length);
+                pad_and_push_runes_to_buffer(
+                    UNPARSEABLE_VALUE,
+                    c.length,
+                    c.column.alignment(),
+                    c.column.pad_symbol(),
+                    &mut field,
+                );
+                buffer.splice(start..end, field);
+                column = Some(c.name.clone());
+            }
+            Some(FaultKind::TruncatedLine) => {
+                let length: usize = rng.gen_range(0..self.row_length);
+                let (start, _) = byte_range(buffer, row_start, length, 0);
+                buffer.truncate(start);
+            }
+            Some(FaultKind::OverlongLine) => {
+                let n_chars: usize = rng.gen_range(1..=MAX_OVERLONG_CHARS);
+                buffer.resize(buffer.len() + n_chars, b'X');
+            }
+            Some(FaultKind::InvalidUtf8) => {
+                let c: &FaultColumn = &self.columns[rng.gen_range(0..self.columns.len())];
+                let (start, _) = byte_range(buffer, row_start, c.offset, c.length);
+                buffer[start] = INVALID_UTF8_BYTE;
+                column = Some(c.name.clone());
+            }
+            _ => {}
+        }
+
+        let is_missing_newline: bool = is_last_row
+            && self
+                .rates
+                .get(&FaultKind::MissingTrailingNewline)
+                .is_some_and(|r| *r > 0.0 && rng.gen_bool(*r));
+
+        match (kind, is_missing_newline) {
+            (_, true) => {}
+            (Some(FaultKind::WrongLineTerminator), _) => {
+                buffer.extend_from_slice(other_newline().as_bytes())
+            }
+            _ => buffer.extend_from_slice(newline().as_bytes()),
+        }
+
+        let mut faults: Vec<Fault> = Vec::new();
+        if let Some(kind) = kind {
+            // The wrong line terminator of a row without a trailing newline was never written.
+            if !(kind == FaultKind::WrongLineTerminator && is_missing_newline) {
+                faults.push(Fault {
+                    row: row_idx,
+                    kind,
+                    column,
+                });
+            }
+        }
+        if is_missing_newline {
+            faults.push(Fault {
+                row: row_idx,
+                kind: FaultKind::MissingTrailingNewline,
+                column: None,
+            });
+        }
+
+        faults
+    }
+}
+
+/// Get the byte range of a field of the row, which starts at the index in the buffer, from the
+/// offset and length of the field in runes.
+fn byte_range(buffer: &[u8], row_start: usize, offset: usize, length: usize) -> (usize, usize) {
+    // The row has not been corrupted yet, so it is valid UTF-8.
+    let row: &str = std::str::from_utf8(&buffer[row_start..]).unwrap_or_default();
+    let mut indices = row
+        .char_indices()
+        .map(|(i, _)| row_start + i)
+        .chain(std::iter::once(buffer.len()));
+
+    let start: usize = indices.nth(offset).unwrap_or(buffer.len());
+    let end: usize = match length {
+        0 => start,
+        n => indices.nth(n - 1).unwrap_or(buffer.len()),
+    };
+
+    (start, end)
+}
+
+/// Get the newline of the other platform than the one the mocker runs on.
+fn other_newline<'a>() -> &'a str {
+    match newline() {
+        "\n" => "\r\n",
+        _ => "\n",
+    }
+}
+
+#[cfg(test)]
+mod tests_fault {
+    use super::*;
+    use padder::{Alignment, Symbol};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn schema() -> FixedSchema {
+        FixedSchema::new(
+            "faults".to_string(),
+            1,
+            vec![
+                FixedColumn::new(
+                    "id".to_string(),
+                    0,
+                    4,
+                    DataType::Int32,
+                    Alignment::Right,
+                    Symbol::Whitespace,
+                    false,
+                ),
+                FixedColumn::new(
+                    "name".to_string(),
+                    4,
+                    6,
+                    DataType::Utf8,
+                    Alignment::Left,
+                    Symbol::Whitespace,
+                    true,
+                ),
+            ],
+        )
+    }
+
+    fn finish_row(kind: FaultKind, is_last_row: bool) -> (Vec<u8>, Vec<Fault>) {
+        let rates: BTreeMap<FaultKind, f64> = BTreeMap::from([(kind, 1.0)]);
+        let injector: FaultInjector = FaultInjector::try_new(&schema(), rates).unwrap();
+        let mut rng: StdRng = StdRng::seed_from_u64(0);
+
+        let mut buffer: Vec<u8> = "   1Åsa   ".as_bytes().to_vec();
+        let faults: Vec<Fault> = injector.finish_row(7, 0, is_last_row, &mut rng, &mut buffer);
+        (buffer, faults)
+    }
+
+    #[test]
+    fn test_inject_faults() {
+        let (row, faults) = finish_row(FaultKind::UnparseableValue, false);
+        assert_eq!("   ?Åsa   \n".as_bytes(), row);
+        assert_eq!(Some("id"), faults[0].column());
+        assert_eq!(7, faults[0].row());
+
+        let (row, _) = finish_row(FaultKind::TruncatedLine, false);
+        assert!(std::str::from_utf8(&row).unwrap().chars().count() < 11);
+
+        let (row, _) = finish_row(FaultKind::OverlongLine, false);
+        assert!(std::str::from_utf8(&row).unwrap().chars().count() > 11);
+
+        let (row, faults) = finish_row(FaultKind::InvalidUtf8, false);
+        assert!(std::str::from_utf8(&row).is_err());
+        assert!(faults[0].column().is_some());
+
+        let (row, _) = finish_row(FaultKind::WrongLineTerminator, false);
+        assert!(row.ends_with(other_newline().as_bytes()));
+
+        let (row, faults) = finish_row(FaultKind::MissingTrailingNewline, true);
+        assert_eq!("   1Åsa   ".as_bytes(), row);
+        assert_eq!(
+            vec![FaultKind::MissingTrailingNewline],
+            faults.iter().map(|f| f.kind()).collect::<Vec<FaultKind>>(),
+        );
+
+        let (_, faults) = finish_row(FaultKind::MissingTrailingNewline, false);
+        assert!(faults.is_empty());
+    }
+
+    #[test]
+    fn test_zero_fault_rates_do_not_draw() {
+        let rates: BTreeMap<FaultKind, f64> = BTreeMap::from([(FaultKind::OverlongLine, 0.0)]);
+        let injector: FaultInjector = FaultInjector::try_new(&schema(), rates).unwrap();
+        let mut rng: StdRng = StdRng::seed_from_u64(0);
+
+        let mut buffer: Vec<u8> = "   1Åsa   ".as_bytes().to_vec();
+        assert!(injector.is_empty());
+        assert!(injector
+            .finish_row(0, 0, true, &mut rng, &mut buffer)
+            .is_empty());
+        assert_eq!(StdRng::seed_from_u64(0).gen::<u64>(), rng.gen::<u64>());
+    }
+
+    #[test]
+    fn test_invalid_fault_rates() {
+        let try_new = |rates: &[(FaultKind, f64)]| {
+            FaultInjector::try_new(&schema(), rates.iter().copied().collect())
+        };
+
+        assert!(try_new(&[(FaultKind::OverlongLine, 1.5)]).is_err());
+        assert!(try_new(&[
+            (FaultKind::OverlongLine, 0.6),
+            (FaultKind::TruncatedLine, 0.6),
+        ])
+        .is_err());
+        assert!(try_new(&[
+            (FaultKind::OverlongLine, 0.5),
+            (FaultKind::MissingTrailingNewline, 1.0),
+        ])
+        .is_ok());
+
+        let schema: FixedSchema = FixedSchema::new(
+            "strings".to_string(),
+            1,
+            vec![schema().columns()[1].clone()],
+        );
+        let rates: BTreeMap<FaultKind, f64> = BTreeMap::from([(FaultKind::UnparseableValue, 0.1)]);
+        assert!(FaultInjector::try_new(&schema, rates).is_err());
+    }
+
+    #[test]
+    fn test_parse_fault_kind() {
+        assert_eq!(
+            Ok(FaultKind::InvalidUtf8),
+            "invalid_utf8".parse::<FaultKind>()
+        );
+        assert!("unknown".parse::<FaultKind>().is_err());
+    }
+}
@@ -0,0 +1,880 @@
+//
+// MIT License
+//
+// Copyright (c) 2026 Firelink Data
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//
+// File created: 2026-10-18
+// Last updated: 2026-10-18
+//
+
+//! Per-column value generators of the mocker, which replace the default uniformly random
+//! values of a column, so that mocked data can look like real workloads.
+//!
+//! The generators are specified in a json mock config file by the names of their columns,
+//! e.g.
+//!
+//! ```json
+//! {
+//!     "columns": {
+//!         "id": { "kind": "sequence", "start": 1 },
+//!         "amount": { "kind": "normal", "mean": 250.0, "std_dev": 40.0 },
+//!         "country": { "kind": "values", "values": ["SE", "NO"], "weights": [3.0, 1.0] },
+//!         "city": { "kind": "faker", "faker": "city" }
+//!     }
+//! }
+//! ```
+
+use chrono::{Days, NaiveDate};
+use evolution_common::datatype::DataType;
+use evolution_common::error::{Result, SetupError};
+use evolution_schema::column::FixedColumn;
+use evolution_schema::profile::{CharClass, ColumnProfile};
+use faker_rand::en_us::addresses::{CityName, PostalCode, StreetAddress};
+use faker_rand::en_us::company::CompanyName;
+use faker_rand::en_us::internet::{Email, Username};
+use faker_rand::en_us::names::{FirstName, FullName, LastName};
+use faker_rand::en_us::phones::PhoneNumber;
+use faker_rand::lorem::{Sentence, Word};
+use half::f16;
+use rand::distributions::{Uniform, WeightedIndex};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use rand_distr::{Normal, Zipf};
+use serde::{Deserialize, Serialize};
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+use crate::fit_float;
+use crate::text::mock_class_char;
+
+/// The date format of the ranges of date generators.
+pub static GENERATOR_DATE_FORMAT: &str = "%Y-%m-%d";
+/// The maximum number of repeats of unbounded repetitions, e.g. `*` and `+`, of patterns.
+pub static MAX_PATTERN_REPEATS: u32 = 16;
+/// The number of values of the pattern, faker and date generators of columns which are not
+/// string columns that are checked to be valid values of their columns.
+pub static NUM_VALIDATED_SAMPLES: usize = 100;
+/// The maximum number of times that a value which is not a valid value of its column is
+/// sampled again.
+pub static MAX_RESAMPLES: usize = 100;
+
+/// A mock config, with the specifications of the generators of individual columns.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct MockConfig {
+    /// The generator specifications, by the names of their columns.
+    #[serde(default)]
+    columns: HashMap<String, GeneratorSpec>,
+}
+
+impl MockConfig {
+    /// Create a new [`MockConfig`] by reading a .json file at the provided path.
+    ///
+    /// # Errors
+    /// If the file could not be read, or if it is not a valid mock config.
+    pub fn from_path(path: PathBuf) -> Result<Self> {
+        let config: Self = serde_json::from_slice(&fs::read(path)?)?;
+        Ok(config)
+    }
+
+    /// Consume the [`MockConfig`] and get its generator specifications, by the names of their
+    /// columns.
+    pub fn into_columns(self) -> HashMap<String, GeneratorSpec> {
+        self.columns
+    }
+}
+
+/// The specification of how to generate the values of a column.
+///
+/// The numbers of the numeric generators are rounded for integer columns, written with the
+/// scale of decimal columns, and are the number of days from the unix epoch for date columns.
+/// The numbers of the normal and sequence generators are clamped to the numbers that a numeric
+/// column can hold, by its datatype and length, which the numbers of the other numeric
+/// generators have to be within.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case", deny_unknown_fields)]
+pub enum GeneratorSpec {
+    /// Numbers uniformly distributed in the (inclusive) range.
+    Range { min: f64, max: f64 },
+    /// Normally distributed numbers.
+    Normal { mean: f64, std_dev: f64 },
+    /// Ranks, from 1 to `n`, Zipf distributed with the exponent, e.g. for skewed keys.
+    Zipf { n: u64, exponent: f64 },
+    /// One of the values, either uniformly chosen or by their weights.
+    Values {
+        values: Vec<String>,
+        #[serde(default)]
+        weights: Option<Vec<f64>>,
+    },
+    /// Strings matching the regular expression.
+    Pattern { pattern: String },
+    /// Consecutive numbers, by the index of the row in the file.
+    Sequence {
+        #[serde(default)]
+        start: i64,
+        #[serde(default = "default_step")]
+        step: i64,
+    },
+    /// One of the first `n` values of a sequence, uniformly chosen, e.g. foreign keys of the
+    /// sequence of keys of another file.
+    Keys {
+        n: u64,
+        #[serde(default)]
+        start: i64,
+        #[serde(default = "default_step")]
+        step: i64,
+    },
+    /// Strings of one of the lengths (in characters), of characters of the classes, each either
+    /// uniformly chosen or by their weights, e.g. like the strings of a profiled file.
+    Text {
+        lengths: Vec<usize>,
+        #[serde(default)]
+        length_weights: Option<Vec<f64>>,
+        char_classes: Vec<CharClass>,
+        #[serde(default)]
+        char_class_weights: Option<Vec<f64>>,
+    },
+    /// Dates uniformly distributed in the (inclusive) range, written as `%Y-%m-%d`.
+    Dates { min: String, max: String },
+    /// Fake values of the kind.
+    Faker { faker: FakerKind },
+}
+
+fn default_step() -> i64 {
+    1
+}
+
+impl GeneratorSpec {
+    /// Create the specification of a generator of values like the profiled values of the
+    /// column, if the profile has any values that can be mocked as the datatype of the column.
+    ///
+    /// Columns whose distinct values are all among their most frequent values are mocked from
+    /// those, by their counts. Otherwise numbers and dates are uniformly distributed between
+    /// the minimum and maximum value, and strings get the lengths and character classes of the
+    /// profiled strings.
+    pub fn from_profile(profile: &ColumnProfile, column: &FixedColumn) -> Option<Self> {
+        if profile.is_categorical() {
+            return Some(GeneratorSpec::Values {
+                values: profile
+                    .top_values()
+                    .iter()
+                    .map(|v| v.value().clone())
+                    .collect(),
+                weights: Some(
+                    profile
+                        .top_values()
+                        .iter()
+                        .map(|v| v.count() as f64)
+                        .collect(),
+                ),
+            });
+        }
+
+        match column.dtype() {
+            DataType::Int16
+            | DataType::Int32
+            | DataType::Int64
+            | DataType::Float16
+            | DataType::Float32
+            | DataType::Float64
+            | DataType::Decimal128 => {
+                let scale: f64 = 10_f64.powi(column.implied_decimals().unwrap_or(0) as i32);
+                let number = |v: &str| v.parse::<f64>().ok().map(|n| n / scale);
+                Some(GeneratorSpec::Range {
+                    min: number(profile.min()?)?,
+                    max: number(profile.max()?)?,
+                })
+            }
+            DataType::Date32 => {
+                let date = |v: &str| {
+                    NaiveDate::parse_from_str(v, column.date_format())
+                        .ok()
+                        .map(|d| d.format(GENERATOR_DATE_FORMAT).to_string())
+                };
+                Some(GeneratorSpec::Dates {
+                    min: date(profile.min()?)?,
+                    max: date(profile.max()?)?,
+                })
+            }
+            DataType::Utf8 | DataType::LargeUtf8
+                if !profile.lengths().is_empty() && !profile.char_classes().is_empty() =>
+            {
+                Some(GeneratorSpec::Text {
+                    lengths: profile.lengths().keys().copied().collect(),
+                    length_weights: Some(profile.lengths().values().map(|c| *c as f64).collect()),
+                    char_classes: profile.char_classes().keys().copied().collect(),
+                    char_class_weights: Some(
+                        profile.char_classes().values().map(|c| *c as f64).collect(),
+                    ),
+                })
+            }
+            _ => None,
+        }
+    }
+}
+
+/// The kinds of fake values.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum FakerKind {
+    FirstName,
+    LastName,
+    FullName,
+    City,
+    StreetAddress,
+    PostalCode,
+    CompanyName,
+    Email,
+    Username,
+    PhoneNumber,
+    Word,
+    Sentence,
+}
+
+/// The distribution of a [`Generator`].
+#[derive(Clone, Debug)]
+enum Sampler {
+    Uniform(Uniform<f64>),
+    Normal(Normal<f64>),
+    Zipf(Zipf<f64>),
+    Values(Vec<String>, Option<WeightedIndex<f64>>),
+    Pattern(rand_regex::Regex),
+    Sequence(i64, i64),
+    Keys(i64, i64, Uniform<u64>),
+    Text(
+        Vec<usize>,
+        WeightedIndex<f64>,
+        Vec<CharClass>,
+        WeightedIndex<f64>,
+    ),
+    Dates(NaiveDate, Uniform<u64>),
+    Faker(FakerKind),
+}
+
+/// A generator of the values of a column, from a [`GeneratorSpec`].
+#[derive(Clone, Debug)]
+pub struct Generator {
+    sampler: Sampler,
+    dtype: DataType,
+    /// The number of decimals to write numbers with, if not rounded.
+    n_decimals: Option<usize>,
+    /// Whether numbers are written without a decimal point.
+    implied_decimals: bool,
+    date_format: String,
+    /// The number of characters of (an element of) the column.
+    length: usize,
+    /// The smallest and largest number that the column can hold, if it is a numeric column.
+    bounds: Option<(f64, f64)>,
+    /// The column, if it is not a string column, which the values have to be valid values of.
+    column: Option<FixedColumn>,
+}
+
+impl Generator {
+    /// Try and create a new [`Generator`] of the values of the column from the specification.
+    ///
+    /// # Errors
+    /// If the column is a struct column, if a numeric generator is specified for a boolean
+    /// column, if a date generator is specified for a column which is neither a date nor a
+    /// string column, if the parameters of the specification are invalid, or if it generates
+    /// values which the column can not hold, see [`Generator::try_validate`].
+    pub fn try_new(spec: &GeneratorSpec, column: &FixedColumn) -> Result<Self> {
+        let invalid = |reason: &str| -> Result<Self> {
+            Err(Box::new(SetupError::new(&format!(
+                "The generator of column '{}' {}, exiting...",
+                column.name(),
+                reason,
+            ))))
+        };
+
+        let dtype: DataType = column.dtype();
+        let is_numeric: bool = matches!(
+            spec,
+            GeneratorSpec::Range { .. }
+                | GeneratorSpec::Normal { .. }
+                | GeneratorSpec::Zipf { .. }
+                | GeneratorSpec::Sequence { .. }
+                | GeneratorSpec::Keys { .. }
+        );
+
+        if dtype == DataType::Struct {
+            return invalid("is for a struct column, specify generators for its fields instead");
+        }
+        if is_numeric && dtype == DataType::Boolean {
+            return invalid("generates numbers but the column is a boolean column");
+        }
+        if matches!(spec, GeneratorSpec::Text { .. })
+            && !matches!(dtype, DataType::Utf8 | DataType::LargeUtf8)
+        {
+            return invalid("generates text but the column is not a string column");
+        }
+        if matches!(spec, GeneratorSpec::Dates { .. })
+            && !matches!(
+                dtype,
+                DataType::Date32 | DataType::Utf8 | DataType::LargeUtf8
+            )
+        {
+            return invalid("generates dates but the column is neither a date nor a string column");
+        }
+
+        let sampler: Sampler = match spec {
+            GeneratorSpec::Range { min, max } => match min <= max {
+                true => Sampler::Uniform(Uniform::new_inclusive(*min, *max)),
+                false => return invalid("has a larger minimum than maximum"),
+            },
+            GeneratorSpec::Normal { mean, std_dev } => match Normal::new(*mean, *std_dev) {
+                Ok(d) => Sampler::Normal(d),
+                Err(e) => return invalid(&format!("has an invalid normal distribution: {}", e)),
+            },
+            GeneratorSpec::Zipf { n, exponent } => match Zipf::new(*n, *exponent) {
+                Ok(d) => Sampler::Zipf(d),
+                Err(e) => return invalid(&format!("has an invalid zipf distribution: {}", e)),
+            },
+            GeneratorSpec::Values { values, weights } => {
+                if values.is_empty() {
+                    return invalid("does not have any values");
+                }
+                let weights: Option<WeightedIndex<f64>> = match weights {
+                    Some(w) if w.len() != values.len() => {
+                        return invalid("does not have the same number of weights as values")
+                    }
+                    Some(w) => match WeightedIndex::new(w) {
+                        Ok(d) => Some(d),
+                        Err(e) => return invalid(&format!("has invalid weights: {}", e)),
+                    },
+                    None => None,
+                };
+                Sampler::Values(values.clone(), weights)
+            }
+            GeneratorSpec::Pattern { pattern } => {
+                match rand_regex::Regex::compile(pattern, MAX_PATTERN_REPEATS) {
+                    Ok(r) => Sampler::Pattern(r),
+                    Err(e) => return invalid(&format!("has an invalid pattern: {}", e)),
+                }
+            }
+            GeneratorSpec::Sequence { start, step } => Sampler::Sequence(*start, *step),
+            GeneratorSpec::Keys { n, start, step } => match *n > 0 {
+                true => Sampler::Keys(*start, *step, Uniform::new(0, *n)),
+                false => return invalid("does not have any keys"),
+            },
+            GeneratorSpec::Text {
+                lengths,
+                length_weights,
+                char_classes,
+                char_class_weights,
+            } => {
+                // Without weights, every length and character class is as likely.
+                let weighted = |n: usize, weights: &Option<Vec<f64>>| match weights {
+                    Some(w) if w.len() != n => None,
+                    Some(w) => WeightedIndex::new(w).ok(),
+                    None => WeightedIndex::new(vec![1.0; n]).ok(),
+                };
+                match (
+                    weighted(lengths.len(), length_weights),
+                    weighted(char_classes.len(), char_class_weights),
+                ) {
+                    (Some(l), Some(c)) => {
+                        Sampler::Text(lengths.clone(), l, char_classes.clone(), c)
+                    }
+                    _ => {
+                        return invalid(
+                            "does not have lengths and character classes with valid weights",
+                        )
+                    }
+                }
+            }
+            GeneratorSpec::Dates { min, max } => {
+                let parse = |d: &str| NaiveDate::parse_from_str(d, GENERATOR_DATE_FORMAT).ok();
+                match (parse(min), parse(max)) {
+                    (Some(min), Some(max)) if min <= max => {
+                        let n_days: u64 = (max - min).num_days() as u64;
+                        Sampler::Dates(min, Uniform::new_inclusive(0, n_days))
+                    }
+                    (Some(_), Some(_)) => return invalid("has a later minimum than maximum date"),
+                    _ => {
+                        return invalid(&format!(
+                            "has dates which are not written as '{}'",
+                            GENERATOR_DATE_FORMAT,
+                        ))
+                    }
+                }
+            }
+            GeneratorSpec::Faker { faker } => Sampler::Faker(*faker),
+        };
+
+        let n_decimals: Option<usize> = match dtype {
+            DataType::Float16 | DataType::Float32 | DataType::Float64 => column.implied_decimals(),
+            DataType::Decimal128 => Some(column.precision_and_scale().1),
+            _ => None,
+        };
+
+        let implied_decimals: bool = column.implied_decimals().is_some();
+        let generator: Self = Self {
+            sampler,
+            dtype,
+            n_decimals,
+            implied_decimals,
+            date_format: column.date_format().to_string(),
+            length: column.element_length(),
+            bounds: number_bounds(column, n_decimals, implied_decimals),
+            column: match dtype {
+                DataType::Utf8 | DataType::LargeUtf8 => None,
+                _ => Some(column.clone()),
+            },
+        };
+        generator.try_validate(spec, column)?;
+
+        Ok(generator)
+    }
+
+    /// Try and validate that the values of the generator are valid values of the column, if
+    /// it is not a string column, whose values are cut to its length instead. The values of
+    /// the pattern, faker and date generators are validated from a sample of them.
+    ///
+    /// # Errors
+    /// If any of the values, or the minimum or maximum date, is not a valid value of the
+    /// column, see [`FixedColumn::is_valid_value`], or if the range, zipf or keys generator
+    /// generates numbers which the column can not hold.
+    fn try_validate(&self, spec: &GeneratorSpec, column: &FixedColumn) -> Result<()> {
+        if matches!(self.dtype, DataType::Utf8 | DataType::LargeUtf8) {
+            return Ok(());
+        }
+
+        let invalid_value = |value: &str| -> Result<()> {
+            Err(Box::new(SetupError::new(&format!(
+                "The generator of column '{}' generates the value '{}', which is not a valid '{:?}' of at most {} characters, exiting...",
+                column.name(),
+                value,
+                self.dtype,
+                self.length,
+            ))))
+        };
+
+        let numbers: Option<(f64, f64)> = match spec {
+            GeneratorSpec::Range { min, max } => Some((*min, *max)),
+            GeneratorSpec::Zipf { n, .. } => Some((1.0, *n as f64)),
+            GeneratorSpec::Keys { n, start, step } => {
+                let last: f64 = (*start + *step * (*n as i64 - 1)) as f64;
+                Some(((*start as f64).min(last), (*start as f64).max(last)))
+            }
+            GeneratorSpec::Values { values, .. } => {
+                match values.iter().find(|v| !column.is_valid_value(v)) {
+                    Some(value) => return invalid_value(value),
+                    None => None,
+                }
+            }
+            GeneratorSpec::Pattern { .. }
+            | GeneratorSpec::Faker { .. }
+            | GeneratorSpec::Dates { .. } => {
+                let mut samples: Vec<String> = match spec {
+                    GeneratorSpec::Dates { min, max } => vec![min, max]
+                        .into_iter()
+                        .map(|d| {
+                            NaiveDate::parse_from_str(d, GENERATOR_DATE_FORMAT)
+                                .map(|d| d.format(&self.date_format).to_string())
+                        })
+                        .collect::<std::result::Result<Vec<String>, _>>()?,
+                    _ => Vec::new(),
+                };
+                let mut rng: StdRng = StdRng::seed_from_u64(0);
+                samples.extend((0..NUM_VALIDATED_SAMPLES).map(|i| self.sample(i, &mut rng)));
+
+                match samples.iter().find(|v| !column.is_valid_value(v)) {
+                    Some(value) => return invalid_value(value),
+                    None => None,
+                }
+            }
+            _ => None,
+        };
+
+        match (numbers, self.bounds) {
+            (Some((min, max)), Some((lower, upper))) if min < lower || max > upper => {
+                Err(Box::new(SetupError::new(&format!(
+                    "The generator of column '{}' generates numbers from {} to {}, but the column can only hold numbers from {} to {}, exiting...",
+                    column.name(),
+                    min,
+                    max,
+                    lower,
+                    upper,
+                ))))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Generate the value of the column in the row with the index. The value of a column which
+    /// is not a string column is sampled again, at most [`MAX_RESAMPLES`] times, until it is a
+    /// valid value of the column, which the values of the generators whose values are not
+    /// bounded, i.e. pattern, faker and date generators, are not always.
+    ///
+    /// # Panics
+    /// If no valid value of the column was sampled.
+    pub fn generate(&self, row_idx: usize, rng: &mut impl Rng) -> String {
+        match &self.column {
+            Some(column) => (0..MAX_RESAMPLES)
+                .map(|_| self.sample(row_idx, rng))
+                .find(|v| column.is_valid_value(v))
+                .unwrap_or_else(|| {
+                    panic!(
+                        "The generator of column '{}' did not generate a valid value in {} attempts!",
+                        column.name(),
+                        MAX_RESAMPLES,
+                    )
+                }),
+            None => self.sample(row_idx, rng),
+        }
+    }
+
+    /// Sample a value of the column in the row with the index.
+    fn sample(&self, row_idx: usize, rng: &mut impl Rng) -> String {
+        match &self.sampler {
+            Sampler::Uniform(d) => self.format_number(rng.sample(d)),
+            Sampler::Normal(d) => self.format_number(rng.sample(d)),
+            Sampler::Zipf(d) => self.format_number(rng.sample(d)),
+            Sampler::Values(values, None) => values[rng.gen_range(0..values.len())].clone(),
+            Sampler::Values(values, Some(weights)) => values[rng.sample(weights)].clone(),
+            Sampler::Pattern(regex) => rng.sample::<String, _>(regex),
+            Sampler::Sequence(start, step) => {
+                self.format_number((start + step * row_idx as i64) as f64)
+            }
+            Sampler::Keys(start, step, idx) => {
+                self.format_number((start + step * rng.sample(idx) as i64) as f64)
+            }
+            Sampler::Text(lengths, length_index, classes, class_index) => (0..lengths
+                [rng.sample(length_index)])
+                .map(|_| mock_class_char(classes[rng.sample(class_index)], rng))
+                .collect::<String>(),
+            Sampler::Dates(min, days) => (*min + Days::new(rng.sample(days)))
+                .format(&self.date_format)
+                .to_string(),
+            Sampler::Faker(kind) => fake(*kind, rng),
+        }
+    }
+
+    /// Write a number as a value of the datatype of the column, clamped to the numbers that
+    /// the column can hold.
+    fn format_number(&self, number: f64) -> String {
+        let number: f64 = match self.bounds {
+            Some((lower, upper)) => number.clamp(lower, upper),
+            None => number,
+        };
+        match (self.dtype, self.n_decimals) {
+            (DataType::Date32, _) => {
+                match NaiveDate::default()
+                    .checked_add_signed(chrono::Duration::days(number.round() as i64))
+                {
+                    Some(d) => d.format(&self.date_format).to_string(),
+                    None => String::new(),
+                }
+            }
+            (DataType::Float16 | DataType::Float32 | DataType::Float64, None) => {
+                fit_float(number, self.length).unwrap_or_default()
+            }
+            (_, Some(n)) if self.implied_decimals => format!("{:.*}", n, number).replace('.', ""),
+            (_, Some(n)) => format!("{:.*}", n, number),
+            (_, None) => format!("{}", number.round() as i64),
+        }
+    }
+}
+
+/// Get the smallest and largest number that a numeric column can hold, by its datatype and by
+/// the length of the column, when written with the number of decimals, or `None` if it is not
+/// a numeric column.
+fn number_bounds(
+    column: &FixedColumn,
+    n_decimals: Option<usize>,
+    implied_decimals: bool,
+) -> Option<(f64, f64)> {
+    let (min, max): (f64, f64) = match column.dtype() {
+        DataType::Int16 => (i16::MIN as f64, i16::MAX as f64),
+        DataType::Int32 => (i32::MIN as f64, i32::MAX as f64),
+        DataType::Int64 => (i64::MIN as f64, i64::MAX as f64),
+        DataType::Float16 => (f16::MIN.to_f64(), f16::MAX.to_f64()),
+        DataType::Float32 => (f32::MIN as f64, f32::MAX as f64),
+        DataType::Float64 => (f64::MIN, f64::MAX),
+        DataType::Decimal128 => {
+            let (precision, scale) = column.precision_and_scale();
+            let max: f64 = 10_f64.powi((precision - scale) as i32) - 10_f64.powi(-(scale as i32));
+            (-max, max)
+        }
+        _ => return None,
+    };
+
+    // The digits of the integer part are the characters left of the decimals and the decimal
+    // point, if any, where the integer part is always written and negative numbers also need
+    // a character for their sign.
+    let n_decimals: usize = n_decimals.unwrap_or(0);
+    let n_point: usize = usize::from(n_decimals > 0 && !implied_decimals);
+    let n_digits: i32 = column.element_length() as i32 - (n_decimals + n_point) as i32;
+    let unit: f64 = 10_f64.powi(-(n_decimals as i32));
+    let largest = |n_digits: i32| match n_digits >= 1 {
+        true => 10_f64.powi(n_digits) - unit,
+        false => 0.0,
+    };
+
+    Some((min.max(-largest(n_digits - 1)), max.min(largest(n_digits))))
+}
+
+/// Try and create the generators of the columns from their specifications.
+///
+/// # Errors
+/// If any of the columns could not be found, or if any of the generators could not be created.
+pub(crate) fn try_new_generators(
+    specs: &HashMap<String, GeneratorSpec>,
+    columns: &[FixedColumn],
+) -> Result<HashMap<String, Generator>> {
+    specs
+        .iter()
+        .map(|(name, spec)| match crate::find_column(columns, name) {
+            Some(column) => Ok((name.clone(), Generator::try_new(spec, column)?)),
+            None => Err(Box::new(SetupError::new(&format!(
+                "A generator was specified for the column '{}' which is not in the schema, exiting...",
+                name,
+            ))) as Box<dyn std::error::Error>),
+        })
+        .collect()
+}
+
+/// Sample a fake value of the kind.
+fn fake(kind: FakerKind, rng: &mut impl Rng) -> String {
+    match kind {
+        FakerKind::FirstName => rng.gen::<FirstName>().to_string(),
+        FakerKind::LastName => rng.gen::<LastName>().to_string(),
+        FakerKind::FullName => rng.gen::<FullName>().to_string(),
+        FakerKind::City => rng.gen::<CityName>().to_string(),
+        FakerKind::StreetAddress => rng.gen::<StreetAddress>().to_string(),
+        FakerKind::PostalCode => rng.gen::<PostalCode>().to_string(),
+        FakerKind::CompanyName => rng.gen::<CompanyName>().to_string(),
+        FakerKind::Email => rng.gen::<Email>().to_string(),
+        FakerKind::Username => rng.gen::<Username>().to_string(),
+        FakerKind::PhoneNumber => rng.gen::<PhoneNumber>().to_string(),
+        FakerKind::Word => rng.gen::<Word>().to_string(),
+        FakerKind::Sentence => rng.gen::<Sentence>().to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests_generator {
+    use super::*;
+    use padder::{Alignment, Symbol};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn column(dtype: DataType) -> FixedColumn {
+        FixedColumn::new(
+            "column".to_string(),
+            0,
+            12,
+            dtype,
+            Alignment::Right,
+            Symbol::Whitespace,
+            true,
+        )
+    }
+
+    fn generate(spec: &str, column: &FixedColumn, n_values: usize) -> Vec<String> {
+        let spec: GeneratorSpec = serde_json::from_str(spec).unwrap();
+        let generator: Generator = Generator::try_new(&spec, column).unwrap();
+        let mut rng: StdRng = StdRng::seed_from_u64(0);
+        (0..n_values)
+            .map(|i| generator.generate(i, &mut rng))
+            .collect()
+    }
+
+    #[test]
+    fn test_generate_values() {
+        let int32: FixedColumn = column(DataType::Int32);
+        assert_eq!(
+            vec!["10", "12", "14"],
+            generate(r#"{"kind": "sequence", "start": 10, "step": 2}"#, &int32, 3),
+        );
+        assert!(
+            generate(r#"{"kind": "range", "min": -5, "max": 5}"#, &int32, 100)
+                .iter()
+                .all(|v| (-5..=5).contains(&v.parse::<i32>().unwrap()))
+        );
+        assert!(generate(
+            r#"{"kind": "keys", "n": 3, "start": 5, "step": 5}"#,
+            &int32,
+            100
+        )
+        .iter()
+        .all(|v| ["5", "10", "15"].contains(&v.as_str())));
+        assert!(
+            generate(r#"{"kind": "zipf", "n": 10, "exponent": 1.5}"#, &int32, 100)
+                .iter()
+                .all(|v| (1..=10).contains(&v.parse::<i32>().unwrap()))
+        );
+
+        let utf8: FixedColumn = column(DataType::Utf8);
+        assert!(generate(
+            r#"{"kind": "values", "values": ["SE", "NO"], "weights": [1.0, 0.0]}"#,
+            &utf8,
+            100,
+        )
+        .iter()
+        .all(|v| v == "SE"));
+        assert!(generate(
+            r#"{"kind": "pattern", "pattern": "[A-Z]{2}[0-9]{3}"}"#,
+            &utf8,
+            100
+        )
+        .iter()
+        .all(|v| v.len() == 5 && v[2..].parse::<u16>().is_ok()));
+
+        assert!(generate(
+            r#"{"kind": "text", "lengths": [2, 4], "char_classes": ["digit"]}"#,
+            &utf8,
+            100,
+        )
+        .iter()
+        .all(|v| [2, 4].contains(&v.len()) && v.parse::<u16>().is_ok()));
+
+        let date32: FixedColumn = column(DataType::Date32).with_format("%Y%m%d".to_string());
+        assert!(generate(
+            r#"{"kind": "dates", "min": "2024-01-30", "max": "2024-02-01"}"#,
+            &date32,
+            100,
+        )
+        .iter()
+        .all(|v| ["20240130", "20240131", "20240201"].contains(&v.as_str())));
+
+        let decimal: FixedColumn = column(DataType::Decimal128).with_precision_and_scale(10, 2);
+        assert_eq!(
+            vec!["1.00", "1.50"],
+            generate(r#"{"kind": "range", "min": 1.0, "max": 1.0}"#, &decimal, 1)
+                .into_iter()
+                .chain(generate(
+                    r#"{"kind": "values", "values": ["1.50"]}"#,
+                    &decimal,
+                    1
+                ))
+                .collect::<Vec<String>>(),
+        );
+        assert_eq!(
+            vec!["125"],
+            generate(
+                r#"{"kind": "range", "min": 1.25, "max": 1.25}"#,
+                &decimal.with_implied_decimals(2),
+                1,
+            ),
+        );
+    }
+
+    #[test]
+    fn test_invalid_generators() {
+        let try_new = |spec: &str, dtype: DataType| {
+            let spec: GeneratorSpec = serde_json::from_str(spec).unwrap();
+            Generator::try_new(&spec, &column(dtype))
+        };
+
+        assert!(try_new(r#"{"kind": "range", "min": 2, "max": 1}"#, DataType::Int32).is_err());
+        assert!(try_new(r#"{"kind": "sequence"}"#, DataType::Boolean).is_err());
+        assert!(try_new(r#"{"kind": "keys", "n": 0}"#, DataType::Int64).is_err());
+        assert!(try_new(r#"{"kind": "faker", "faker": "city"}"#, DataType::Struct).is_err());
+        assert!(try_new(
+            r#"{"kind": "values", "values": ["a", "b"], "weights": [1.0]}"#,
+            DataType::Utf8,
+        )
+        .is_err());
+        assert!(try_new(
+            r#"{"kind": "dates", "min": "2024-02-01", "max": "2024-01-01"}"#,
+            DataType::Date32,
+        )
+        .is_err());
+        assert!(try_new(r#"{"kind": "pattern", "pattern": "[a-"}"#, DataType::Utf8).is_err());
+        assert!(try_new(
+            r#"{"kind": "text", "lengths": [2], "char_classes": ["upper"]}"#,
+            DataType::Int32,
+        )
+        .is_err());
+        assert!(try_new(
+            r#"{"kind": "text", "lengths": [2], "length_weights": [1, 2], "char_classes": ["upper"]}"#,
+            DataType::Utf8,
+        )
+        .is_err());
+        assert!(try_new(r#"{"kind": "values", "values": ["abc"]}"#, DataType::Int32).is_err());
+        assert!(try_new(
+            r#"{"kind": "pattern", "pattern": "[a-z]{3}"}"#,
+            DataType::Int32
+        )
+        .is_err());
+        assert!(try_new(r#"{"kind": "faker", "faker": "city"}"#, DataType::Float64).is_err());
+        assert!(try_new(
+            r#"{"kind": "zipf", "n": 40000, "exponent": 1.5}"#,
+            DataType::Int16
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_generators_fit_column() {
+        let int16: FixedColumn = FixedColumn::new(
+            "column".to_string(),
+            0,
+            5,
+            DataType::Int16,
+            Alignment::Right,
+            Symbol::Whitespace,
+            true,
+        );
+        let spec: GeneratorSpec =
+            serde_json::from_str(r#"{"kind": "range", "min": -100000, "max": 100000}"#).unwrap();
+        assert!(Generator::try_new(&spec, &int16).is_err());
+
+        // The numbers of unbounded generators are clamped to what the column can hold, by its
+        // datatype and length.
+        for (spec, column) in [
+            (
+                r#"{"kind": "normal", "mean": 0.0, "std_dev": 100000.0}"#,
+                int16.clone(),
+            ),
+            (
+                r#"{"kind": "sequence", "start": 32760, "step": 1}"#,
+                int16.clone(),
+            ),
+            (
+                r#"{"kind": "normal", "mean": 0.0, "std_dev": 1e12}"#,
+                column(DataType::Float64).with_implied_decimals(2),
+            ),
+            (
+                r#"{"kind": "normal", "mean": 0.0, "std_dev": 1e12}"#,
+                column(DataType::Decimal128).with_precision_and_scale(8, 3),
+            ),
+        ] {
+            let values: Vec<String> = generate(spec, &column, 100);
+            assert!(
+                values.iter().all(|v| column.is_valid_value(v)),
+                "{:?}",
+                values
+            );
+        }
+        assert_eq!(
+            vec!["32766", "32767", "32767"],
+            generate(
+                r#"{"kind": "sequence", "start": 32766, "step": 1}"#,
+                &int16,
+                3
+            ),
+        );
+    }
+}
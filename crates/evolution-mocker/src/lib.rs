@@ -22,75 +22,625 @@
 // SOFTWARE.
 //
 // File created: 2024-05-25
-// Last updated: 2024-10-11
+// Last updated: 2026-10-18
 //
 
+use chrono::{Days, NaiveDate};
 use evolution_common::datatype::DataType;
 use evolution_schema::column::FixedColumn;
+use evolution_schema::schema::FixedSchema;
 use faker_rand::en_us::names::FirstName;
-use rand::rngs::ThreadRng;
+use padder::{Alignment, Symbol};
 use rand::Rng;
 
+use std::collections::HashMap;
+
+use crate::generator::Generator;
+use crate::text::{mock_text, Charset};
+
 pub static MOCKED_F16_MAX: f32 = 256.0;
 pub static MOCKED_F32_MAX: f32 = 1_000_000.0;
 pub static MOCKED_F64_MAX: f64 = 1_000_000_000.0;
 pub static MOCKED_I16_MAX: i16 = 10_000;
 pub static MOCKED_I32_MAX: i32 = 1_000_000;
 pub static MOCKED_I64_MAX: i64 = 1_000_000_000;
+pub static MOCKED_DATE_MAX_DAYS: u64 = 50_000;
+pub static MOCKED_DECIMAL_MAX_DIGITS: usize = 9;
+pub static MOCKED_FLOAT_MAX_DECIMALS: usize = 4;
+
+/// A mocker of entire rows of a [`FixedSchema`], where the field of each column that
+/// alternatives are chosen by is one of the values of their conditions, and the region of the
+/// alternatives is mocked from the alternative whose condition the field meets.
+///
+/// The fields of nullable columns are left blank, i.e. only padding, with the null ratio of
+/// the column. Blank fields are read as nulls, except for string columns which read them as
+/// empty strings, and columns with a default value which read them as the default.
+pub struct RowMocker<'a> {
+    schema: &'a FixedSchema,
+    /// The values of the conditions on each of the columns that alternatives are chosen by.
+    discriminators: HashMap<&'a str, Vec<&'a str>>,
+    /// The ratio of blank fields of the nullable columns without a ratio of their own.
+    null_ratio: f64,
+    /// The ratio of blank fields of each of the nullable columns with a ratio of their own.
+    column_null_ratios: HashMap<String, f64>,
+    /// The generators of the values of the columns that have them, by their names.
+    generators: HashMap<String, Generator>,
+    /// The characters of the strings of the string columns without a generator.
+    charset: Charset,
+}
+
+impl<'a> RowMocker<'a> {
+    /// Create a new [`RowMocker`] for the provided schema.
+    pub fn new(schema: &'a FixedSchema) -> Self {
+        let mut discriminators: HashMap<&str, Vec<&str>> = HashMap::new();
+        for condition in schema.iter().filter_map(|c| c.condition()) {
+            discriminators
+                .entry(condition.column())
+                .or_default()
+                .extend(condition.values().iter().map(|v| v.as_str()));
+        }
+
+        Self {
+            schema,
+            discriminators,
+            null_ratio: 0.0,
+            column_null_ratios: HashMap::new(),
+            generators: HashMap::new(),
+            charset: Charset::default(),
+        }
+    }
+
+    /// Set the ratio of blank fields of the nullable columns.
+    pub fn with_null_ratio(mut self, null_ratio: f64) -> Self {
+        self.null_ratio = null_ratio;
+        self
+    }
+
+    /// Set the ratio of blank fields of individual nullable columns, by their names, overriding
+    /// the ratio of all other nullable columns.
+    pub fn with_column_null_ratios(mut self, column_null_ratios: HashMap<String, f64>) -> Self {
+        self.column_null_ratios = column_null_ratios;
+        self
+    }
+
+    /// Set the generators of the values of individual columns, by their names. The values of
+    /// all other columns are mocked uniformly at random.
+    pub fn with_generators(mut self, generators: HashMap<String, Generator>) -> Self {
+        self.generators = generators;
+        self
+    }
+
+    /// Set the characters of the strings of the string columns without a generator.
+    pub fn with_charset(mut self, charset: Charset) -> Self {
+        self.charset = charset;
+        self
+    }
+
+    /// Mock a single row, with the index of the row in the file, without a trailing newline,
+    /// and push it to the buffer.
+    pub fn mock_row(&self, row_idx: usize, rng: &mut impl Rng, buffer: &mut Vec<u8>) {
+        self.mock_row_into(row_idx, rng, buffer, None);
+    }
+
+    /// Mock a single row like [`RowMocker::mock_row`], and also push the value of each of its
+    /// fields, before padding, to the values. The value of a field left blank is `None`.
+    pub fn mock_row_with_values(
+        &self,
+        row_idx: usize,
+        rng: &mut impl Rng,
+        buffer: &mut Vec<u8>,
+        values: &mut Vec<Option<String>>,
+    ) {
+        self.mock_row_into(row_idx, rng, buffer, Some(values));
+    }
+
+    /// Mock a single row, and push the values of its fields to the values, if any.
+    fn mock_row_into(
+        &self,
+        row_idx: usize,
+        rng: &mut impl Rng,
+        buffer: &mut Vec<u8>,
+        mut values: Option<&mut Vec<Option<String>>>,
+    ) {
+        let mut chosen: Vec<(&str, &str)> = Vec::with_capacity(self.discriminators.len());
+
+        for (idx, column) in self.schema.iter().enumerate() {
+            if self.schema.is_redefinition(idx) {
+                continue;
+            }
+
+            match (
+                column.condition(),
+                self.discriminators.get(column.name().as_str()),
+            ) {
+                (Some(condition), _) => {
+                    let value: Option<&str> = chosen
+                        .iter()
+                        .find(|(name, _)| *name == condition.column())
+                        .map(|(_, value)| *value);
+                    let alternatives: &[FixedColumn] = self.schema.alternatives(idx);
+                    let alternative: &FixedColumn = alternatives
+                        .iter()
+                        .find(|a| {
+                            a.condition().is_some_and(|c| {
+                                c.values().iter().any(|v| Some(v.as_str()) == value)
+                            })
+                        })
+                        .unwrap_or(&alternatives[0]);
+                    self.mock_field(alternative, row_idx, rng, buffer, values.as_deref_mut());
+                }
+                (None, Some(codes)) => {
+                    let value: &str = codes[rng.gen_range(0..codes.len())];
+                    chosen.push((column.name(), value));
+                    pad_and_push_runes_to_buffer(
+                        value,
+                        column.element_length(),
+                        column.alignment(),
+                        column.pad_symbol(),
+                        buffer,
+                    );
+                    if let Some(values) = values.as_deref_mut() {
+                        values.push(Some(value.to_string()));
+                    }
+                }
+                (None, None) => {
+                    self.mock_field(column, row_idx, rng, buffer, values.as_deref_mut())
+                }
+            }
+        }
+    }
+
+    /// Mock each of the elements of the field of a column, padded to their lengths, one after
+    /// the other, and push them to the buffer. The elements of struct columns are mocked field
+    /// by field, so that each of their nullable fields can be left blank. The value of each
+    /// element is pushed to the values, if any.
+    fn mock_field(
+        &self,
+        column: &FixedColumn,
+        row_idx: usize,
+        rng: &mut impl Rng,
+        buffer: &mut Vec<u8>,
+        mut values: Option<&mut Vec<Option<String>>>,
+    ) {
+        let null_ratio: f64 = match column.is_nullable() {
+            true => *self
+                .column_null_ratios
+                .get(column.name())
+                .unwrap_or(&self.null_ratio),
+            false => 0.0,
+        };
+
+        for _ in 0..column.occurs().map_or(1, |o| o.count()) {
+            if column.dtype() == DataType::Struct {
+                for field in column.fields() {
+                    self.mock_field(field, row_idx, rng, buffer, values.as_deref_mut());
+                }
+                continue;
+            }
+
+            // Only draw from the generator when the column can have nulls, so that files mocked
+            // from a seed without any null ratios stay the same.
+            let value: Option<String> = match null_ratio > 0.0 && rng.gen_bool(null_ratio) {
+                true => None,
+                false => Some(match self.generators.get(column.name()) {
+                    Some(generator) => generator.generate(row_idx, rng),
+                    None => self.mock_value(column, rng),
+                }),
+            };
+            let value: Option<String> = match column.dtype() {
+                DataType::Utf8 | DataType::LargeUtf8 => {
+                    value.map(|v| fit_string(v, column.element_length()))
+                }
+                _ => value,
+            };
+
+            pad_and_push_runes_to_buffer(
+                value.as_deref().unwrap_or_default(),
+                column.element_length(),
+                column.alignment(),
+                column.pad_symbol(),
+                buffer,
+            );
+            if let Some(values) = values.as_deref_mut() {
+                values.push(value);
+            }
+        }
+    }
+
+    /// Mock a value of a column without a generator, where the values of string columns are
+    /// strings of the charset unless it is [`Charset::Ascii`].
+    fn mock_value(&self, column: &FixedColumn, rng: &mut impl Rng) -> String {
+        match (column.dtype(), self.charset) {
+            (_, Charset::Ascii) => mock_column(column, rng),
+            (DataType::Utf8 | DataType::LargeUtf8, charset) => {
+                mock_text(charset, column.element_length(), rng)
+            }
+            _ => mock_column(column, rng),
+        }
+    }
+}
+
+/// Mock each of the elements of the field of a column, padded to their lengths, one after
+/// the other, and push them to the buffer.
+fn mock_field(column: &FixedColumn, rng: &mut impl Rng, buffer: &mut Vec<u8>) {
+    for _ in 0..column.occurs().map_or(1, |o| o.count()) {
+        pad_and_push_runes_to_buffer(
+            &mock_column(column, rng),
+            column.element_length(),
+            column.alignment(),
+            column.pad_symbol(),
+            buffer,
+        );
+    }
+}
+
+/// Pad the value with the symbol to the number of characters, i.e. runes, and push it to the
+/// buffer. Unlike [`padder::pad_and_push_to_buffer`], which pads to a number of bytes, this
+/// keeps the rows aligned when values have multibyte characters.
+///
+/// # Panics
+/// If the value is longer than the number of characters, since cutting it would silently
+/// change it, values have to be fit to their columns before they are padded.
+pub(crate) fn pad_and_push_runes_to_buffer(
+    value: &str,
+    n_chars: usize,
+    alignment: Alignment,
+    symbol: Symbol,
+    buffer: &mut Vec<u8>,
+) {
+    let n_value_chars: usize = value.chars().count();
+    assert!(
+        n_value_chars <= n_chars,
+        "The mocked value '{}' does not fit in {} characters!",
+        value,
+        n_chars,
+    );
+    let (left, right) = alignment.left_right_padding(n_chars - n_value_chars);
+
+    let mut pad: [u8; 4] = [0; 4];
+    let pad: &[u8] = char::from(symbol).encode_utf8(&mut pad).as_bytes();
+    for _ in 0..left {
+        buffer.extend_from_slice(pad);
+    }
+    buffer.extend_from_slice(value.as_bytes());
+    for _ in 0..right {
+        buffer.extend_from_slice(pad);
+    }
+}
 
-/// Create a string with mocked data based on the [`FixedColumn`] datatype.
-pub fn mock_column(column: &FixedColumn, rng: &mut ThreadRng) -> String {
+/// Create a string with mocked data based on the [`FixedColumn`] datatype, which fits in the
+/// length of (an element of) the column.
+pub fn mock_column(column: &FixedColumn, rng: &mut impl Rng) -> String {
     match column.dtype() {
-        DataType::Boolean => mock_bool(rng),
-        DataType::Float16 => mock_f16(rng),
-        DataType::Float32 => mock_f32(rng),
-        DataType::Float64 => mock_f64(rng),
-        DataType::Int16 => mock_i16(rng),
-        DataType::Int32 => mock_i32(rng),
-        DataType::Int64 => mock_i64(rng),
-        DataType::Utf8 => mock_utf8(rng),
-        DataType::LargeUtf8 => mock_utf8(rng),
+        DataType::Boolean => mock_bool(column, rng),
+        DataType::Float16 => mock_float(column, MOCKED_F16_MAX as f64, rng),
+        DataType::Float32 => mock_float(column, MOCKED_F32_MAX as f64, rng),
+        DataType::Float64 => mock_float(column, MOCKED_F64_MAX, rng),
+        DataType::Int16 => mock_integer(column, MOCKED_I16_MAX as i64, rng),
+        DataType::Int32 => mock_integer(column, MOCKED_I32_MAX as i64, rng),
+        DataType::Int64 => mock_integer(column, MOCKED_I64_MAX, rng),
+        DataType::Utf8 => mock_utf8(column, rng),
+        DataType::LargeUtf8 => mock_utf8(column, rng),
+        DataType::Date32 => mock_date(column, rng),
+        DataType::Decimal128 => mock_decimal(column, rng),
+        DataType::Struct => mock_struct(column, rng),
     }
 }
 
-/// Mock a boolean with 50/50 chance of being True/False.
-fn mock_bool(rng: &mut ThreadRng) -> String {
-    rng.gen_bool(0.5).to_string()
+/// Mock each of the fields of a struct column, padded to their lengths, one after the other.
+fn mock_struct(column: &FixedColumn, rng: &mut impl Rng) -> String {
+    let mut buffer: Vec<u8> = Vec::new();
+    for field in column.fields() {
+        mock_field(field, rng, &mut buffer);
+    }
+    // The fields are padded from valid UTF-8, so the buffer is valid UTF-8.
+    String::from_utf8(buffer).unwrap()
 }
 
-/// Mock a f16 number in the range [-[`MOCKED_F16_MAX`], [`MOCKED_F16_MAX`]].
-fn mock_f16(rng: &mut ThreadRng) -> String {
-    rng.gen_range(-MOCKED_F16_MAX..=MOCKED_F16_MAX).to_string()
+/// Mock a boolean with 50/50 chance of being True/False, or always True if False does not fit
+/// in the length of the column.
+fn mock_bool(column: &FixedColumn, rng: &mut impl Rng) -> String {
+    match column.element_length() >= "false".len() {
+        true => rng.gen_bool(0.5).to_string(),
+        false => true.to_string(),
+    }
+}
+
+/// Mock a float number in the range [-`max`, `max`], which fits in the length of the column.
+/// The number is written with the implied decimals of the column if it has any, and otherwise
+/// with as many decimals as fit, at most [`MOCKED_FLOAT_MAX_DECIMALS`].
+fn mock_float(column: &FixedColumn, max: f64, rng: &mut impl Rng) -> String {
+    let n_decimals: usize = column.implied_decimals().unwrap_or(
+        // One character each for the sign, an integer digit, and the decimal point.
+        column
+            .element_length()
+            .saturating_sub(3)
+            .min(MOCKED_FLOAT_MAX_DECIMALS),
+    );
+
+    mock_number(
+        column,
+        max as u64,
+        n_decimals,
+        column.implied_decimals().is_some(),
+        rng,
+    )
 }
 
-/// Mock a f32 number in the range [-[`MOCKED_F32_MAX`], [`MOCKED_F32_MAX`]].
-fn mock_f32(rng: &mut ThreadRng) -> String {
-    rng.gen_range(-MOCKED_F32_MAX..=MOCKED_F32_MAX).to_string()
+/// Mock an integer in the range [-`max`, `max`], which fits in the length of the column.
+fn mock_integer(column: &FixedColumn, max: i64, rng: &mut impl Rng) -> String {
+    mock_number(column, max as u64, 0, false, rng)
 }
 
-/// Mock a f64 number in the range [-[`MOCKED_F64_MAX`], [`MOCKED_F64_MAX`]].
-fn mock_f64(rng: &mut ThreadRng) -> String {
-    rng.gen_range(-MOCKED_F64_MAX..=MOCKED_F64_MAX).to_string()
+/// Mock a date within [`MOCKED_DATE_MAX_DAYS`] days from the unix epoch, written in the
+/// format of the column.
+fn mock_date(column: &FixedColumn, rng: &mut impl Rng) -> String {
+    let date: NaiveDate = NaiveDate::default() + Days::new(rng.gen_range(0..MOCKED_DATE_MAX_DAYS));
+    date.format(column.date_format()).to_string()
 }
 
-/// Mock a i16 number in the range [-[`MOCKED_I16_MAX`], [`MOCKED_I16_MAX`]].
-fn mock_i16(rng: &mut ThreadRng) -> String {
-    rng.gen_range(-MOCKED_I16_MAX..=MOCKED_I16_MAX).to_string()
+/// Mock a decimal number with at most [`MOCKED_DECIMAL_MAX_DIGITS`] integer digits within the
+/// precision of the column, which fits in the length of the column. The number is written with
+/// the implied decimals of the column if it has any, and otherwise with as many decimals of its
+/// scale as fit.
+fn mock_decimal(column: &FixedColumn, rng: &mut impl Rng) -> String {
+    let (precision, scale) = column.precision_and_scale();
+    let n_integer_digits: usize = (precision - scale).min(MOCKED_DECIMAL_MAX_DIGITS);
+    let n_decimals: usize = column
+        .implied_decimals()
+        .unwrap_or(scale.min(column.element_length().saturating_sub(3)));
+
+    mock_number(
+        column,
+        max_with_digits(n_integer_digits),
+        n_decimals,
+        column.implied_decimals().is_some(),
+        rng,
+    )
 }
 
-/// Mock a i32 number in the range [-[`MOCKED_I32_MAX`], [`MOCKED_I32_MAX`]].
-fn mock_i32(rng: &mut ThreadRng) -> String {
-    rng.gen_range(-MOCKED_I32_MAX..=MOCKED_I32_MAX).to_string()
+/// Mock a number with an integer part in the range [-`max`, `max`] and the number of decimals,
+/// written either with a decimal point or with implied decimals. The integer part is limited to
+/// the digits that fit in the length of the column next to the decimals, and it is only
+/// negative if a sign fits as well.
+fn mock_number(
+    column: &FixedColumn,
+    max: u64,
+    n_decimals: usize,
+    is_implied: bool,
+    rng: &mut impl Rng,
+) -> String {
+    let n_fraction_chars: usize = n_decimals + usize::from(!is_implied && n_decimals > 0);
+    let n_integer_chars: usize = column
+        .element_length()
+        .saturating_sub(n_fraction_chars)
+        .max(1);
+
+    let is_negative: bool = n_integer_chars > 1 && rng.gen_bool(0.5);
+    let n_integer_digits: usize = n_integer_chars - usize::from(is_negative);
+    let integer: u64 = rng.gen_range(0..=max.min(max_with_digits(n_integer_digits)));
+    let fraction: String = (0..n_decimals)
+        .map(|_| char::from(b'0' + rng.gen_range(0..10)))
+        .collect::<String>();
+
+    let sign: &str = if is_negative { "-" } else { "" };
+    match (is_implied, n_decimals) {
+        (true, _) => format!("{}{}{}", sign, integer, fraction),
+        (false, 0) => format!("{}{}", sign, integer),
+        (false, _) => format!("{}{}.{}", sign, integer, fraction),
+    }
 }
 
-/// Mock a i64 number in the range [-[`MOCKED_I64_MAX`], [`MOCKED_I64_MAX`]].
-fn mock_i64(rng: &mut ThreadRng) -> String {
-    rng.gen_range(-MOCKED_I64_MAX..=MOCKED_I64_MAX).to_string()
+/// Get the largest number with at most the number of digits.
+fn max_with_digits(n_digits: usize) -> u64 {
+    10_u64
+        .checked_pow(n_digits as u32)
+        .map_or(u64::MAX, |p| p - 1)
 }
 
-/// Sample a random name uniformly from the [`FirstName`] distribution.
-fn mock_utf8(rng: &mut ThreadRng) -> String {
-    rng.gen::<FirstName>().to_string()
+/// Sample a random name uniformly from the [`FirstName`] distribution, cut to the length of
+/// the column.
+fn mock_utf8(column: &FixedColumn, rng: &mut impl Rng) -> String {
+    fit_string(rng.gen::<FirstName>().to_string(), column.element_length())
 }
 
+/// Cut a string to at most the number of characters.
+pub(crate) fn fit_string(value: String, n_chars: usize) -> String {
+    match value.char_indices().nth(n_chars) {
+        Some((idx, _)) => value[..idx].to_string(),
+        None => value,
+    }
+}
+
+/// Write a float number with as many decimals as fit in the number of characters, at most
+/// [`MOCKED_FLOAT_MAX_DECIMALS`], or `None` if it does not fit even without any decimals.
+pub(crate) fn fit_float(value: f64, n_chars: usize) -> Option<String> {
+    (0..=MOCKED_FLOAT_MAX_DECIMALS)
+        .rev()
+        .map(|n_decimals| format!("{:.*}", n_decimals, value))
+        .find(|v| v.len() <= n_chars)
+}
+
+/// Find a column, or a field of a struct column, by its name.
+pub(crate) fn find_column<'a>(columns: &'a [FixedColumn], name: &str) -> Option<&'a FixedColumn> {
+    columns.iter().find_map(|c| match c.name() == name {
+        true => Some(c),
+        false => find_column(c.fields(), name),
+    })
+}
+
+pub mod dataset;
+pub mod fault;
+pub mod generator;
 pub mod mocker;
+pub mod text;
+pub mod truth;
+
+#[cfg(test)]
+mod tests_mock {
+    use super::*;
+    use crate::generator::GeneratorSpec;
+    use evolution_builder::builder::ColumnBuilderRef;
+    use padder::{Alignment, Symbol};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn column(dtype: DataType, length: usize) -> FixedColumn {
+        FixedColumn::new(
+            format!("{:?}", dtype),
+            0,
+            length,
+            dtype,
+            Alignment::Right,
+            Symbol::Whitespace,
+            false,
+        )
+    }
+
+    #[test]
+    fn test_mocked_values_fit_column_length() {
+        // Each column is mocked both by default and by a generator of numbers or values which
+        // do not all fit in it.
+        let columns: Vec<(FixedColumn, &str)> = vec![
+            (
+                column(DataType::Boolean, 4),
+                r#"{"kind": "values", "values": ["true"]}"#,
+            ),
+            (
+                column(DataType::Int16, 2),
+                r#"{"kind": "normal", "mean": 0.0, "std_dev": 1000.0}"#,
+            ),
+            (
+                column(DataType::Int32, 3),
+                r#"{"kind": "sequence", "start": 990, "step": 7}"#,
+            ),
+            (
+                column(DataType::Int64, 1),
+                r#"{"kind": "pattern", "pattern": "[0-9]"}"#,
+            ),
+            (
+                column(DataType::Float16, 4),
+                r#"{"kind": "normal", "mean": 0.0, "std_dev": 1e6}"#,
+            ),
+            (
+                column(DataType::Float32, 5),
+                r#"{"kind": "normal", "mean": 0.0, "std_dev": 1e6}"#,
+            ),
+            (
+                column(DataType::Float64, 6).with_implied_decimals(2),
+                r#"{"kind": "normal", "mean": 0.0, "std_dev": 1e6}"#,
+            ),
+            (
+                column(DataType::Decimal128, 5).with_precision_and_scale(10, 3),
+                r#"{"kind": "normal", "mean": 0.0, "std_dev": 1e6}"#,
+            ),
+            (
+                column(DataType::Decimal128, 6)
+                    .with_precision_and_scale(8, 2)
+                    .with_implied_decimals(2),
+                r#"{"kind": "sequence", "start": -10000, "step": 13}"#,
+            ),
+            (
+                column(DataType::Utf8, 3),
+                r#"{"kind": "values", "values": ["abc"]}"#,
+            ),
+            (
+                column(DataType::Date32, 10),
+                r#"{"kind": "dates", "min": "1970-01-01", "max": "9999-12-31"}"#,
+            ),
+        ];
+
+        let mut rng: StdRng = StdRng::seed_from_u64(0);
+        for (column, spec) in columns.iter() {
+            assert!(column.try_validate().is_ok());
+            let mut builder: ColumnBuilderRef = column.as_column_builder();
+            let spec: GeneratorSpec = serde_json::from_str(spec).unwrap();
+            let generator: Generator = Generator::try_new(&spec, column).unwrap();
+
+            for row_idx in 0..1000 {
+                let value: String = match row_idx % 2 {
+                    0 => mock_column(column, &mut rng),
+                    _ => generator.generate(row_idx, &mut rng),
+                };
+                assert!(
+                    value.chars().count() <= column.length(),
+                    "'{}' does not fit in column '{}'",
+                    value,
+                    column.name(),
+                );
+
+                let mut field: Vec<u8> = Vec::new();
+                pad_and_push_runes_to_buffer(
+                    &value,
+                    column.length(),
+                    column.alignment(),
+                    column.pad_symbol(),
+                    &mut field,
+                );
+                assert!(builder.try_build_column(&field).is_ok());
+            }
+
+            assert_eq!(0, builder.n_parse_failures());
+        }
+    }
+
+    #[test]
+    fn test_pad_runes() {
+        let pad = |value: &str, n_chars: usize, alignment: Alignment| {
+            let mut buffer: Vec<u8> = Vec::new();
+            pad_and_push_runes_to_buffer(value, n_chars, alignment, Symbol::Hyphen, &mut buffer);
+            String::from_utf8(buffer).unwrap()
+        };
+
+        assert_eq!("--Åsa", pad("Åsa", 5, Alignment::Right));
+        assert_eq!("東京---", pad("東京", 5, Alignment::Left));
+        assert_eq!("-😀😀--", pad("😀😀", 5, Alignment::Center));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_pad_runes_does_not_cut_values() {
+        let mut buffer: Vec<u8> = Vec::new();
+        pad_and_push_runes_to_buffer("😀ø東京", 3, Alignment::Right, Symbol::Hyphen, &mut buffer);
+    }
+
+    #[test]
+    fn test_mocked_text_fits_column_length() {
+        let column: FixedColumn = column(DataType::Utf8, 6);
+        let mut builder: ColumnBuilderRef = column.as_column_builder();
+        let mut rng: StdRng = StdRng::seed_from_u64(0);
+
+        for charset in [
+            Charset::Nordic,
+            Charset::Cjk,
+            Charset::Emoji,
+            Charset::Mixed,
+        ] {
+            for _ in 0..100 {
+                let value: String = mock_text(charset, column.length(), &mut rng);
+                assert!((1..=6).contains(&value.chars().count()));
+
+                let mut field: Vec<u8> = Vec::new();
+                pad_and_push_runes_to_buffer(
+                    &value,
+                    column.length(),
+                    column.alignment(),
+                    column.pad_symbol(),
+                    &mut field,
+                );
+                assert!(builder.try_build_column(&field).is_ok());
+            }
+        }
+
+        assert_eq!(0, builder.n_parse_failures());
+    }
+
+    #[test]
+    fn test_fit_values() {
+        assert_eq!("Åsa", fit_string("Åsalind".to_string(), 3));
+        assert_eq!("Bo", fit_string("Bo".to_string(), 3));
+        assert_eq!(Some("-123.46".to_string()), fit_float(-123.456789, 7));
+        assert_eq!(Some("123457".to_string()), fit_float(123456.7, 6));
+        assert_eq!(None, fit_float(123456.7, 5));
+    }
+}
@@ -22,24 +22,31 @@
 // SOFTWARE.
 //
 // File created: 2024-02-05
-// Last updated: 2024-10-13
+// Last updated: 2026-10-18
 //
 
 use crossbeam::channel;
 
 use evolution_common::error::{Result, SetupError};
-use evolution_common::{newline, NUM_BYTES_FOR_NEWLINE};
+use evolution_common::NUM_BYTES_FOR_NEWLINE;
+use evolution_schema::column::FixedColumn;
+use evolution_schema::profile::Profile;
 use evolution_schema::schema::FixedSchema;
 use evolution_writer::writer::{FixedLengthFileWriter, FixedLengthFileWriterProperties, Writer};
 use log::{info, warn};
-use padder::pad_and_push_to_buffer;
-use rand::rngs::ThreadRng;
+use rand::rngs::StdRng;
+use rand::{RngCore, SeedableRng};
 
+use std::collections::{BTreeMap, HashMap};
 use std::path::PathBuf;
 use std::sync::Arc;
 use std::thread::{spawn, JoinHandle};
 
-use crate::mock_column;
+use crate::fault::{Fault, FaultInjector, FaultKind, FaultManifest};
+use crate::generator::{try_new_generators, Generator, GeneratorSpec, MockConfig};
+use crate::text::Charset;
+use crate::truth::{GroundTruth, GroundTruthFormat, GroundTruthRecorder};
+use crate::{find_column, RowMocker};
 
 /// If the user only wants to generate a small amount of mocked .flf rows then multithreading
 /// is not a stuiable choice and probably only introduces extra overhead. This variable
@@ -49,6 +56,10 @@ use crate::mock_column;
 /// This value takes priority over any CLI options regarding number of threads to use for mocking.
 pub static MIN_NUM_ROWS_FOR_MULTITHREADING: usize = 100_000;
 
+/// The mocked bytes of a chunk of rows, and the faults injected into them and their ground
+/// truth if it is recorded, which the worker threads send to the master thread by chunk index.
+type MockedChunk = (Vec<u8>, Vec<Fault>, Option<GroundTruth>);
+
 /// Unified trait for all types of file mockers.
 pub trait Mocker {}
 pub type MockerRef = Box<dyn Mocker>;
@@ -67,6 +78,22 @@ pub struct FixedLengthFileMocker {
     write_buffer_size: usize,
     // The maximum number of active messages allowed in the thread channels.
     thread_channel_capacity: usize,
+    /// The seed to derive the random number generators of each row from, if any.
+    seed: Option<u64>,
+    /// The ratio of blank fields of the nullable columns without a ratio of their own.
+    null_ratio: f64,
+    /// The ratio of blank fields of each of the nullable columns with a ratio of their own.
+    column_null_ratios: HashMap<String, f64>,
+    /// The generators of the values of the columns that have them, by their names.
+    generators: HashMap<String, Generator>,
+    /// The characters of the strings of the string columns without a generator.
+    charset: Charset,
+    /// The injector of faults into the mocked rows, which also terminates each row.
+    fault_injector: FaultInjector,
+    /// The file to write the manifest of the injected faults to, if any.
+    fault_manifest_path: Option<PathBuf>,
+    /// The file to write the typed values of the mocked rows to, and its format, if any.
+    ground_truth_file: Option<(PathBuf, GroundTruthFormat)>,
 }
 
 impl FixedLengthFileMocker {
@@ -90,12 +117,64 @@ impl FixedLengthFileMocker {
     /// [`try_mock_multithreaded`]: FixedLengthFileMocker::try_mock_multithreaded
     /// [`try_mock_single_threaded`]: FixedLengthFileMocker::try_mock_single_threaded
     pub fn try_mock(&mut self) -> Result<()> {
-        if self.n_threads > 1 {
-            self.try_mock_multithreaded()?;
+        let recorder: Option<GroundTruthRecorder> = match self.ground_truth_file.is_some() {
+            true => Some(self.try_new_recorder(false)?),
+            false => None,
+        };
+        self.try_mock_and_record(recorder)?;
+        Ok(())
+    }
+
+    /// Try and generate mocked data like [`try_mock`], and also record the [`GroundTruth`] of
+    /// the mocked rows, i.e. the value of every field before it was padded.
+    ///
+    /// # Errors
+    /// Besides the errors of [`try_mock`], if the schema is not flat, see [`GroundTruth`], or
+    /// if any faults are injected, since the values of corrupted rows are not what the file
+    /// contains.
+    ///
+    /// [`try_mock`]: FixedLengthFileMocker::try_mock
+    pub fn try_mock_with_ground_truth(&mut self) -> Result<GroundTruth> {
+        if !self.fault_injector.is_empty() {
+            return Err(Box::new(SetupError::new(
+                "The ground truth of mocked rows can not be recorded when injecting faults, exiting...",
+            )));
+        }
+
+        let recorder: GroundTruthRecorder = self.try_new_recorder(true)?;
+        // The rows are always returned by a recorder which keeps them.
+        Ok(self.try_mock_and_record(Some(recorder))?.unwrap())
+    }
+
+    /// Try and create a new [`GroundTruthRecorder`] of the mocked rows, which writes them to the
+    /// ground truth file, if any, and keeps them in memory if requested.
+    fn try_new_recorder(&self, keep_rows: bool) -> Result<GroundTruthRecorder> {
+        GroundTruthRecorder::try_new(
+            &self.schema,
+            keep_rows,
+            self.ground_truth_file
+                .as_ref()
+                .map(|(path, format)| (path.to_path_buf(), *format)),
+        )
+    }
+
+    /// Try and generate mocked data, in single-threaded or multithreaded mode, and record the
+    /// values of the mocked rows with the recorder, if any. Returns the recorded rows if the
+    /// recorder keeps them.
+    fn try_mock_and_record(
+        &mut self,
+        recorder: Option<GroundTruthRecorder>,
+    ) -> Result<Option<GroundTruth>> {
+        let recorder: Option<GroundTruthRecorder> = if self.n_threads > 1 {
+            self.try_mock_multithreaded(recorder)?
         } else {
-            self.try_mock_single_threaded()?;
+            self.try_mock_single_threaded(recorder)?
+        };
+
+        match recorder {
+            Some(recorder) => recorder.try_finish(),
+            None => Ok(None),
         }
-        Ok(())
     }
 
     /// Try and generate mocked data in multithreaded mode.
@@ -105,63 +184,88 @@ impl FixedLengthFileMocker {
     /// * If the [`FixedLengthFileWriter`] failed to write the generated columns to file.
     /// * If the [`FixedLengthFileWriter`] failed to flush any remaining bytes and close the buffer.
     ///
-    fn try_mock_multithreaded(&mut self) -> Result<()> {
-        let thread_workloads: Vec<usize> = self.distribute_worker_thread_workloads();
-        let (sender, receiver) = channel::bounded(self.thread_channel_capacity);
+    fn try_mock_multithreaded(
+        &mut self,
+        mut recorder: Option<GroundTruthRecorder>,
+    ) -> Result<Option<GroundTruthRecorder>> {
+        let n_worker_threads: usize = self.n_threads - 1;
+        let n_chunks: usize = self.n_rows.div_ceil(self.write_buffer_size);
+        let seed: u64 = self.create_seed();
+        let (sender, receiver) =
+            channel::bounded::<(usize, MockedChunk)>(self.thread_channel_capacity);
 
         info!(
             "Starting {} worker threads to generate {} mocked rows.",
-            self.n_threads - 1,
-            self.n_rows
+            n_worker_threads, self.n_rows
         );
         let arc_schema = Arc::new(self.schema.clone());
+        let n_rows: usize = self.n_rows;
         let t_n_rows_buffer_size: usize = self.write_buffer_size;
         let t_buffer_size: usize = 2 * self.write_buffer_size * arc_schema.row_length()
             + NUM_BYTES_FOR_NEWLINE * self.write_buffer_size;
 
-        let threads = thread_workloads
-            .into_iter()
-            .enumerate()
-            .map(|(t_idx, t_workload)| {
+        let threads = (0..n_worker_threads)
+            .map(|t_idx| {
                 let t_schema = Arc::clone(&arc_schema);
                 let t_sender = sender.clone();
+                let t_null_ratio: f64 = self.null_ratio;
+                let t_column_null_ratios: HashMap<String, f64> = self.column_null_ratios.clone();
+                let t_generators: HashMap<String, Generator> = self.generators.clone();
+                let t_charset: Charset = self.charset;
+                let t_fault_injector: FaultInjector = self.fault_injector.clone();
+                let t_truth: Option<GroundTruth> = recorder.as_ref().map(|r| r.new_chunk());
                 spawn(move || {
-                    let mut rng: ThreadRng = rand::thread_rng();
-                    let mut buffer: Vec<u8> = Vec::with_capacity(t_buffer_size);
+                    let row_mocker: RowMocker = RowMocker::new(&t_schema)
+                        .with_null_ratio(t_null_ratio)
+                        .with_column_null_ratios(t_column_null_ratios)
+                        .with_generators(t_generators)
+                        .with_charset(t_charset);
 
-                    for row_idx in 0..t_workload {
-                        if (row_idx % t_n_rows_buffer_size == 0) && (row_idx != 0) {
-                            t_sender.send(buffer).unwrap_or_else(|_| {
+                    // The rows are mocked in chunks of the size of the write buffer, where each
+                    // thread mocks every n:th chunk, starting from the chunk of its own index.
+                    for chunk_idx in (t_idx..n_chunks).step_by(n_worker_threads) {
+                        let first_row_idx: usize = chunk_idx * t_n_rows_buffer_size;
+                        let last_row_idx: usize =
+                            (first_row_idx + t_n_rows_buffer_size).min(n_rows);
+
+                        let mut buffer: Vec<u8> = Vec::with_capacity(t_buffer_size);
+                        let mut faults: Vec<Fault> = Vec::new();
+                        let mut chunk_truth: Option<GroundTruth> = t_truth.clone();
+                        let mut row_values: Vec<Option<String>> = Vec::new();
+                        for row_idx in first_row_idx..last_row_idx {
+                            let mut rng: StdRng = row_rng(seed, row_idx);
+                            let row_start: usize = buffer.len();
+                            match chunk_truth.as_mut() {
+                                Some(chunk_truth) => {
+                                    row_mocker.mock_row_with_values(
+                                        row_idx,
+                                        &mut rng,
+                                        &mut buffer,
+                                        &mut row_values,
+                                    );
+                                    chunk_truth.push_row(&mut row_values);
+                                }
+                                None => row_mocker.mock_row(row_idx, &mut rng, &mut buffer),
+                            }
+                            faults.extend(t_fault_injector.finish_row(
+                                row_idx,
+                                row_start,
+                                row_idx + 1 == n_rows,
+                                &mut rng,
+                                &mut buffer,
+                            ));
+                        }
+
+                        t_sender
+                            .send((chunk_idx, (buffer, faults, chunk_truth)))
+                            .unwrap_or_else(|_| {
                                 panic!(
                                     "Thread {} could not send buffer to master thread!",
                                     t_idx + 1
                                 )
                             });
-
-                            // We need to reallocate the buffer since we sent it to the master thread.
-                            buffer = Vec::with_capacity(t_buffer_size);
-                        }
-
-                        for column in t_schema.iter() {
-                            pad_and_push_to_buffer(
-                                mock_column(column, &mut rng).as_bytes(),
-                                column.length(),
-                                column.alignment(),
-                                column.pad_symbol(),
-                                &mut buffer,
-                            );
-                        }
-
-                        buffer.extend_from_slice(newline().as_bytes());
                     }
 
-                    t_sender.send(buffer).unwrap_or_else(|_| {
-                        panic!(
-                            "Thread {} could not send buffer to master thread!",
-                            t_idx + 1
-                        )
-                    });
-
                     info!("Thread {} done!", t_idx + 1);
                     drop(t_sender);
                 })
@@ -169,9 +273,22 @@ impl FixedLengthFileMocker {
             .collect::<Vec<JoinHandle<()>>>();
 
         drop(sender);
-        for buffer in receiver {
-            self.writer.try_write(&buffer)?;
-            drop(buffer);
+
+        // The chunks are written in order, regardless of which thread finishes its chunk first,
+        // so that the same seed always produces the same file.
+        let mut pending: HashMap<usize, MockedChunk> = HashMap::new();
+        let mut next_chunk_idx: usize = 0;
+        let mut faults: Vec<Fault> = Vec::new();
+        for (chunk_idx, chunk) in receiver {
+            pending.insert(chunk_idx, chunk);
+            while let Some((buffer, chunk_faults, chunk_truth)) = pending.remove(&next_chunk_idx) {
+                self.writer.try_write(&buffer)?;
+                faults.extend(chunk_faults);
+                if let (Some(recorder), Some(chunk_truth)) = (recorder.as_mut(), chunk_truth) {
+                    recorder.try_record(chunk_truth)?;
+                }
+                next_chunk_idx += 1;
+            }
         }
 
         for (t_idx, handle) in threads.into_iter().enumerate() {
@@ -180,7 +297,9 @@ impl FixedLengthFileMocker {
                 .unwrap_or_else(|_| panic!("Thread {} could not join the master thread!", t_idx));
         }
 
-        Ok(())
+        self.try_write_fault_manifest(faults)?;
+
+        Ok(recorder)
     }
 
     /// Try and generate mocked data in single-threaded mode.
@@ -189,7 +308,10 @@ impl FixedLengthFileMocker {
     /// This function might return an error for the following reasons:
     /// * If the [`FixedLengthFileWriter`] failed to write the generated columns to file.
     /// * If the [`FixedLengthFileWriter`] failed to flush any remaining bytes and close the buffer.
-    fn try_mock_single_threaded(&mut self) -> Result<()> {
+    fn try_mock_single_threaded(
+        &mut self,
+        mut recorder: Option<GroundTruthRecorder>,
+    ) -> Result<Option<GroundTruthRecorder>> {
         let n_runes_in_row = self.schema.row_length();
         // Here we multiply by 4 because a valid UTF-8 encoded character can at most be
         // exactly 4 bytes. Thus, we will always allocate enough memory for the writer buffer.
@@ -198,53 +320,91 @@ impl FixedLengthFileMocker {
             + self.write_buffer_size * n_runes_in_row;
 
         let mut buffer: Vec<u8> = Vec::with_capacity(writer_buffer_size);
-        let mut rng: ThreadRng = rand::thread_rng();
+        let seed: u64 = self.create_seed();
+        let row_mocker: RowMocker = RowMocker::new(&self.schema)
+            .with_null_ratio(self.null_ratio)
+            .with_column_null_ratios(self.column_null_ratios.clone())
+            .with_generators(self.generators.clone())
+            .with_charset(self.charset);
 
         info!(
             "Generating {} mocked rows in single-threaded mode.",
             self.n_rows
         );
+        let mut faults: Vec<Fault> = Vec::new();
+        let mut row_values: Vec<Option<String>> = Vec::new();
+        let mut truth: Option<GroundTruth> = recorder.as_ref().map(|r| r.new_chunk());
         for ridx in 0..self.n_rows {
             if (ridx % self.write_buffer_size == 0) && (ridx != 0) {
                 self.writer.try_write(&buffer)?;
                 buffer.clear();
+                if let (Some(recorder), Some(truth)) = (recorder.as_mut(), truth.as_mut()) {
+                    recorder.try_record(std::mem::replace(truth, recorder.new_chunk()))?;
+                }
             }
 
-            for column in self.schema.iter() {
-                pad_and_push_to_buffer(
-                    mock_column(column, &mut rng).as_bytes(),
-                    column.length(),
-                    column.alignment(),
-                    column.pad_symbol(),
-                    &mut buffer,
-                );
+            let mut rng: StdRng = row_rng(seed, ridx);
+            let row_start: usize = buffer.len();
+            match truth.as_mut() {
+                Some(truth) => {
+                    row_mocker.mock_row_with_values(ridx, &mut rng, &mut buffer, &mut row_values);
+                    truth.push_row(&mut row_values);
+                }
+                None => row_mocker.mock_row(ridx, &mut rng, &mut buffer),
             }
-
-            buffer.extend_from_slice(newline().as_bytes());
+            faults.extend(self.fault_injector.finish_row(
+                ridx,
+                row_start,
+                ridx + 1 == self.n_rows,
+                &mut rng,
+                &mut buffer,
+            ));
         }
 
         info!("Done mocking, flushing any remaining buffers.");
         self.writer.try_write(&buffer)?;
         self.writer.try_finish()?;
+        if let (Some(recorder), Some(truth)) = (recorder.as_mut(), truth) {
+            recorder.try_record(truth)?;
+        }
+        self.try_write_fault_manifest(faults)?;
 
-        Ok(())
+        Ok(recorder)
     }
 
-    /// Distribute the workload of mocking rows to the available threads. Attempts to dsitribute
-    /// uniformly, but the last thread will always get any remaining rows which were not split evenly.
-    fn distribute_worker_thread_workloads(&self) -> Vec<usize> {
-        let n_worker_threads: usize = self.n_threads - 1;
-        let n_rows_per_thread: usize = self.n_rows / n_worker_threads;
-        let n_rows_remaining: usize = self.n_rows - (n_rows_per_thread * n_worker_threads);
-        let mut thread_workloads: Vec<usize> = (0..(n_worker_threads - 1))
-            .map(|_| n_rows_per_thread)
-            .collect();
+    /// Try and write the manifest of the injected faults, if a file to write it to was set.
+    ///
+    /// # Errors
+    /// If the manifest could not be written to the file.
+    fn try_write_fault_manifest(&self, faults: Vec<Fault>) -> Result<()> {
+        if !faults.is_empty() {
+            info!("Injected {} faults into the mocked rows.", faults.len());
+        }
+
+        match &self.fault_manifest_path {
+            Some(path) => FaultManifest::new(self.n_rows, faults).try_write(path.to_path_buf()),
+            None => Ok(()),
+        }
+    }
 
-        thread_workloads.push(n_rows_per_thread + n_rows_remaining);
-        thread_workloads
+    /// Get the seed to derive the random number generators of each row from, which is the set
+    /// seed if any, otherwise a seed from the operating system.
+    fn create_seed(&self) -> u64 {
+        self.seed
+            .unwrap_or_else(|| StdRng::from_entropy().next_u64())
     }
 }
 
+/// Create the random number generator of a row from the seed and the index of the row, so that
+/// the same seed always produces the same rows, regardless of the size of the write buffer and
+/// the number of threads that the rows are mocked with.
+fn row_rng(seed: u64, row_idx: usize) -> StdRng {
+    let mut key: [u8; 32] = [0; 32];
+    key[..8].copy_from_slice(&seed.to_le_bytes());
+    key[8..16].copy_from_slice(&(row_idx as u64).to_le_bytes());
+    StdRng::from_seed(key)
+}
+
 impl Mocker for FixedLengthFileMocker {}
 
 /// A helper struct for building an instance of a [`FixedLengthFileMocker`] struct.
@@ -256,6 +416,17 @@ pub struct FixedLengthFileMockerBuilder {
     n_threads: Option<usize>,
     write_buffer_size: Option<usize>,
     thread_channel_capacity: Option<usize>,
+    seed: Option<u64>,
+    null_ratio: Option<f64>,
+    column_null_ratios: HashMap<String, f64>,
+    config_path: Option<PathBuf>,
+    profile_path: Option<PathBuf>,
+    generator_specs: HashMap<String, GeneratorSpec>,
+    charset: Option<Charset>,
+    fault_rates: BTreeMap<FaultKind, f64>,
+    fault_manifest_path: Option<PathBuf>,
+    ground_truth_path: Option<PathBuf>,
+    ground_truth_format: Option<GroundTruthFormat>,
 
     // File descriptor properties.
     force_create_new: Option<bool>,
@@ -310,6 +481,13 @@ impl FixedLengthFileMockerBuilder {
         self
     }
 
+    /// Set the seed to mock the rows from, producing the same file for the same seed (with the
+    /// same version of evolution).
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
     /// Set default values for the optional configuration fields.
     ///
     /// # Note
@@ -323,10 +501,84 @@ impl FixedLengthFileMockerBuilder {
         self
     }
 
+    /// Set the ratio, between 0 and 1, of the fields of the nullable columns to leave blank.
+    pub fn with_null_ratio(mut self, null_ratio: f64) -> Self {
+        self.null_ratio = Some(null_ratio);
+        self
+    }
+
+    /// Set the ratio, between 0 and 1, of the fields of a nullable column to leave blank,
+    /// overriding the ratio of all nullable columns for it.
+    pub fn with_column_null_ratio(mut self, column: String, null_ratio: f64) -> Self {
+        self.column_null_ratios.insert(column, null_ratio);
+        self
+    }
+
+    /// Set the relative or absolute path to a json mock config file, with the specifications
+    /// of the generators of individual columns.
+    pub fn with_config(mut self, config_path: PathBuf) -> Self {
+        self.config_path = Some(config_path);
+        self
+    }
+
+    /// Set the relative or absolute path to a json profile of a fixed-length file with the same
+    /// schema, to mock values like the profiled values, see [`GeneratorSpec::from_profile`].
+    /// The null ratios and generators of the profile are overridden by any set otherwise.
+    pub fn with_profile(mut self, profile_path: PathBuf) -> Self {
+        self.profile_path = Some(profile_path);
+        self
+    }
+
+    /// Set the specification of the generator of a column, overriding any generator of it in
+    /// the mock config file.
+    pub fn with_generator(mut self, column: String, spec: GeneratorSpec) -> Self {
+        self.generator_specs.insert(column, spec);
+        self
+    }
+
+    /// Set the characters of the strings of the string columns without a generator, e.g.
+    /// [`Charset::Cjk`] to mock files of multibyte characters.
+    pub fn with_charset(mut self, charset: Charset) -> Self {
+        self.charset = Some(charset);
+        self
+    }
+
+    /// Set the rate, between 0 and 1, of the rows to inject a kind of fault into. Each row gets
+    /// at most one fault, besides the last row which can also be missing its trailing newline.
+    pub fn with_fault_rate(mut self, kind: FaultKind, rate: f64) -> Self {
+        self.fault_rates.insert(kind, rate);
+        self
+    }
+
+    /// Set the relative or absolute path to the json file to write the manifest of the injected
+    /// faults to.
+    pub fn with_fault_manifest(mut self, manifest_path: PathBuf) -> Self {
+        self.fault_manifest_path = Some(manifest_path);
+        self
+    }
+
+    /// Set the relative or absolute path to a file to write the exact same values as the mocked
+    /// rows to, typed per the schema, i.e. the values converting the mocked file should produce.
+    pub fn with_ground_truth_file(mut self, ground_truth_path: PathBuf) -> Self {
+        self.ground_truth_path = Some(ground_truth_path);
+        self
+    }
+
+    /// Set the file format of the ground truth file, defaults to [`GroundTruthFormat::Parquet`].
+    pub fn with_ground_truth_format(mut self, format: GroundTruthFormat) -> Self {
+        self.ground_truth_format = Some(format);
+        self
+    }
+
     /// Try creating a new [`FixedLengthFileMocker`] from the previously set values.
     ///
     /// # Errors
-    /// If any of the required fields are `None`, or if the schema deserialization failed.
+    /// If any of the required fields are `None`, if the schema deserialization failed, if any
+    /// of the null ratios is not between 0 and 1, if a null ratio, generator or profile is set
+    /// for a column which is not in the schema, if the profile, the mock config or any of the
+    /// generators is invalid, if the fault rates are invalid, or if a ground truth file is set
+    /// but the schema is not flat or faults are injected, see
+    /// [`FixedLengthFileMocker::try_mock_with_ground_truth`].
     pub fn try_build(self) -> Result<FixedLengthFileMocker> {
         let schema: FixedSchema = match self.schema_path {
             Some(p) => FixedSchema::from_path(p)?,
@@ -378,6 +630,86 @@ impl FixedLengthFileMockerBuilder {
 
         let thread_channel_capacity: usize = self.thread_channel_capacity.unwrap_or(n_threads);
 
+        let null_ratio: f64 = self.null_ratio.unwrap_or(0.0);
+        if !(0.0..=1.0).contains(&null_ratio) {
+            return Err(Box::new(SetupError::new(&format!(
+                "The null ratio {} is not between 0 and 1, exiting...",
+                null_ratio,
+            ))));
+        }
+
+        let mut column_null_ratios: HashMap<String, f64> = HashMap::new();
+        let mut generator_specs: HashMap<String, GeneratorSpec> = HashMap::new();
+        if let Some(p) = self.profile_path {
+            for profile in Profile::from_path(p)?.columns() {
+                let column: &FixedColumn = match find_column(schema.columns(), profile.name()) {
+                    Some(c) => c,
+                    None => {
+                        return Err(Box::new(SetupError::new(&format!(
+                        "The profile has the column '{}' which is not in the schema, exiting...",
+                        profile.name(),
+                    ))))
+                    }
+                };
+
+                if column.is_nullable() {
+                    column_null_ratios.insert(profile.name().clone(), profile.null_rate());
+                }
+                if let Some(spec) = GeneratorSpec::from_profile(profile, column) {
+                    generator_specs.insert(profile.name().clone(), spec);
+                }
+            }
+        }
+        column_null_ratios.extend(self.column_null_ratios);
+
+        for (name, ratio) in column_null_ratios.iter() {
+            let column: &FixedColumn = match find_column(schema.columns(), name) {
+                Some(c) => c,
+                None => {
+                    return Err(Box::new(SetupError::new(&format!(
+                        "A null ratio was set for the column '{}' which is not in the schema, exiting...",
+                        name,
+                    ))))
+                }
+            };
+
+            if !(0.0..=1.0).contains(ratio) {
+                return Err(Box::new(SetupError::new(&format!(
+                    "The null ratio {} of column '{}' is not between 0 and 1, exiting...",
+                    ratio, name,
+                ))));
+            }
+
+            if !column.is_nullable() {
+                warn!(
+                    "Column '{}' is not nullable, ignoring its null ratio of {}.",
+                    name, ratio
+                );
+            }
+        }
+
+        if let Some(p) = self.config_path {
+            generator_specs.extend(MockConfig::from_path(p)?.into_columns());
+        }
+        generator_specs.extend(self.generator_specs);
+        let generators: HashMap<String, Generator> =
+            try_new_generators(&generator_specs, schema.columns())?;
+
+        let fault_injector: FaultInjector = FaultInjector::try_new(&schema, self.fault_rates)?;
+
+        let ground_truth_file: Option<(PathBuf, GroundTruthFormat)> = match self.ground_truth_path {
+            Some(path) => {
+                GroundTruth::try_new(&schema)?;
+                if !fault_injector.is_empty() {
+                    return Err(Box::new(SetupError::new(
+                        "A ground truth file can not be written when injecting faults, exiting...",
+                    )));
+                }
+                Some((path, self.ground_truth_format.unwrap_or_default()))
+            }
+            None => None,
+        };
+
         let writer_properties: FixedLengthFileWriterProperties =
             FixedLengthFileWriterProperties::builder()
                 .with_force_create_new(force_create_new)
@@ -410,6 +742,14 @@ impl FixedLengthFileMockerBuilder {
             n_threads,
             write_buffer_size,
             thread_channel_capacity,
+            seed: self.seed,
+            null_ratio,
+            column_null_ratios,
+            generators,
+            charset: self.charset.unwrap_or_default(),
+            fault_injector,
+            fault_manifest_path: self.fault_manifest_path,
+            ground_truth_file,
         })
     }
 
@@ -427,3 +767,319 @@ impl FixedLengthFileMockerBuilder {
         self.try_build().unwrap()
     }
 }
+
+#[cfg(test)]
+mod tests_mocker {
+    use super::*;
+    use arrow::array::RecordBatch;
+    use arrow::compute::concat_batches;
+    use arrow::ipc::reader::FileReader;
+    use evolution_common::datatype::DataType;
+    use evolution_schema::profile::{ColumnProfile, Profiler};
+    use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+    use std::fs::{self, File};
+
+    fn mock(name: &str, n_rows: usize, n_threads: usize, seed: u64) -> Vec<u8> {
+        mock_with(name, n_rows, n_threads, |b| b.with_seed(seed))
+    }
+
+    fn mock_with(
+        name: &str,
+        n_rows: usize,
+        n_threads: usize,
+        configure: impl FnOnce(FixedLengthFileMockerBuilder) -> FixedLengthFileMockerBuilder,
+    ) -> Vec<u8> {
+        let mut schema_path: PathBuf = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        schema_path.push("../evolution-schema/res/test_valid_schema.json");
+        let out_path: PathBuf = std::env::temp_dir().join(name);
+
+        let builder: FixedLengthFileMockerBuilder = FixedLengthFileMocker::builder()
+            .with_schema(schema_path)
+            .with_out_file(out_path.clone())
+            .with_num_rows(n_rows)
+            .with_num_threads(n_threads)
+            .with_write_buffer_size(1000)
+            .with_force_create_new(false)
+            .with_truncate_existing(true);
+
+        configure(builder).try_build().unwrap().try_mock().unwrap();
+
+        let mocked: Vec<u8> = fs::read(&out_path).unwrap();
+        fs::remove_file(out_path).unwrap();
+        mocked
+    }
+
+    #[test]
+    fn test_mock_with_seed_single_threaded() {
+        let a: Vec<u8> = mock("evolution_test_seed_a.flf", 500, 1, 42);
+        let b: Vec<u8> = mock("evolution_test_seed_b.flf", 500, 1, 42);
+        let c: Vec<u8> = mock("evolution_test_seed_c.flf", 500, 1, 43);
+
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+    }
+
+    #[test]
+    fn test_mock_with_seed_multithreaded() {
+        let n_rows: usize = MIN_NUM_ROWS_FOR_MULTITHREADING;
+        let a: Vec<u8> = mock("evolution_test_seed_mt_a.flf", n_rows, 3, 42);
+        let b: Vec<u8> = mock("evolution_test_seed_mt_b.flf", n_rows, 3, 42);
+
+        assert_eq!(n_rows, a.iter().filter(|b| **b == b'\n').count());
+        assert_eq!(a, b);
+
+        // The rows only depend on the seed, not on the size of the write buffer or the number
+        // of threads that they are mocked with.
+        let c: Vec<u8> = mock_with("evolution_test_seed_mt_c.flf", n_rows, 2, |b| {
+            b.with_seed(42).with_write_buffer_size(777)
+        });
+        let d: Vec<u8> = mock_with("evolution_test_seed_mt_d.flf", n_rows, 1, |b| {
+            b.with_seed(42).with_write_buffer_size(333)
+        });
+        assert_eq!(a, c);
+        assert_eq!(a, d);
+    }
+
+    #[test]
+    fn test_mock_with_null_ratios() {
+        let mocked: Vec<u8> = mock_with("evolution_test_null_ratio.flf", 200, 1, |b| {
+            b.with_null_ratio(1.0)
+                .with_column_null_ratio("pet-name".to_string(), 0.0)
+        });
+        let mocked: String = String::from_utf8(mocked).unwrap();
+
+        for row in mocked.lines() {
+            assert!(row[41..73].bytes().all(|b| b == b' '));
+            assert!(row[73..78].bytes().all(|b| b == b'-'));
+            assert!(!row[88..138].bytes().all(|b| b == b'*'));
+            assert!(!row[0..9].bytes().all(|b| b == b' '));
+        }
+    }
+
+    #[test]
+    fn test_invalid_null_ratios() {
+        let mut schema_path: PathBuf = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        schema_path.push("../evolution-schema/res/test_valid_schema.json");
+        let builder = || {
+            FixedLengthFileMocker::builder()
+                .with_schema(schema_path.clone())
+                .with_out_file(std::env::temp_dir().join("evolution_test_invalid_null.flf"))
+                .with_num_rows(1)
+                .with_default_values()
+        };
+
+        assert!(builder().with_null_ratio(1.5).try_build().is_err());
+        assert!(builder()
+            .with_column_null_ratio("missing".to_string(), 0.5)
+            .try_build()
+            .is_err());
+    }
+
+    #[test]
+    fn test_mock_sequence_multithreaded() {
+        let mocked: Vec<u8> = mock_with(
+            "evolution_test_sequence_mt.flf",
+            MIN_NUM_ROWS_FOR_MULTITHREADING,
+            4,
+            |b| {
+                b.with_generator(
+                    "id".to_string(),
+                    GeneratorSpec::Sequence { start: 0, step: 1 },
+                )
+            },
+        );
+        let mocked: String = String::from_utf8(mocked).unwrap();
+
+        for (row_idx, row) in mocked.lines().enumerate() {
+            assert_eq!(row_idx, row[0..9].trim().parse::<usize>().unwrap());
+        }
+    }
+
+    #[test]
+    fn test_mock_with_charsets() {
+        for (idx, charset) in [
+            Charset::Nordic,
+            Charset::Cjk,
+            Charset::Emoji,
+            Charset::Mixed,
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            let mocked: Vec<u8> = mock_with(
+                &format!("evolution_test_charset_{}.flf", idx),
+                MIN_NUM_ROWS_FOR_MULTITHREADING,
+                2,
+                |b| b.with_seed(3).with_charset(charset),
+            );
+            let mocked: String = String::from_utf8(mocked).unwrap();
+
+            assert!(mocked.len() > 158 * MIN_NUM_ROWS_FOR_MULTITHREADING);
+            for row in mocked.lines() {
+                assert_eq!(158, row.chars().count());
+            }
+        }
+    }
+
+    #[test]
+    fn test_mock_with_ground_truth_files() {
+        let mut schema_path: PathBuf = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        schema_path.push("../evolution-schema/res/test_valid_schema.json");
+
+        for (format, n_threads, n_rows) in [
+            (GroundTruthFormat::Parquet, 1, 2500),
+            (GroundTruthFormat::Ipc, 3, MIN_NUM_ROWS_FOR_MULTITHREADING),
+        ] {
+            let out_path: PathBuf = std::env::temp_dir().join("evolution_test_ground_truth.flf");
+            let truth_path: PathBuf =
+                std::env::temp_dir().join(format!("evolution_test_ground_truth.{:?}", format));
+
+            let truth: GroundTruth = FixedLengthFileMocker::builder()
+                .with_schema(schema_path.clone())
+                .with_out_file(out_path.clone())
+                .with_num_rows(n_rows)
+                .with_default_values()
+                .with_num_threads(n_threads)
+                .with_seed(11)
+                .with_null_ratio(0.2)
+                .with_ground_truth_file(truth_path.clone())
+                .with_ground_truth_format(format)
+                .try_build()
+                .unwrap()
+                .try_mock_with_ground_truth()
+                .unwrap();
+            let expected: RecordBatch = truth.try_to_record_batch().unwrap();
+
+            let file: File = File::open(&truth_path).unwrap();
+            let batches: Vec<RecordBatch> = match format {
+                GroundTruthFormat::Parquet => ParquetRecordBatchReaderBuilder::try_new(file)
+                    .unwrap()
+                    .build()
+                    .unwrap()
+                    .map(|b| b.unwrap())
+                    .collect(),
+                GroundTruthFormat::Ipc => FileReader::try_new(file, None)
+                    .unwrap()
+                    .map(|b| b.unwrap())
+                    .collect(),
+            };
+            let actual: RecordBatch = concat_batches(&expected.schema(), &batches).unwrap();
+            fs::remove_file(out_path).unwrap();
+            fs::remove_file(truth_path).unwrap();
+
+            assert_eq!(n_rows, actual.num_rows());
+            assert_eq!(expected.columns(), actual.columns());
+        }
+    }
+
+    #[test]
+    fn test_mock_from_profile() {
+        let mut schema_path: PathBuf = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        schema_path.push("../evolution-schema/res/test_valid_schema.json");
+        let schema: FixedSchema = FixedSchema::from_path(schema_path).unwrap();
+        let profile = |mocked: Vec<u8>| {
+            let records: Vec<String> = String::from_utf8(mocked)
+                .unwrap()
+                .lines()
+                .map(|l| l.to_string())
+                .collect();
+            Profiler::new(&records).try_profile(&schema).unwrap()
+        };
+
+        let real: Profile = profile(mock_with("evolution_test_profile_a.flf", 5000, 1, |b| {
+            b.with_seed(1)
+                .with_null_ratio(0.1)
+                .with_column_null_ratio("city".to_string(), 0.4)
+                .with_generator(
+                    "city".to_string(),
+                    serde_json::from_str(
+                        r#"{"kind": "values", "values": ["Oslo", "Malmö"], "weights": [3, 1]}"#,
+                    )
+                    .unwrap(),
+                )
+                .with_generator(
+                    "salary".to_string(),
+                    serde_json::from_str(r#"{"kind": "range", "min": 1000, "max": 5000}"#).unwrap(),
+                )
+        }));
+        let profile_path: PathBuf = std::env::temp_dir().join("evolution_test_profile.json");
+        fs::write(&profile_path, real.try_to_json().unwrap()).unwrap();
+
+        let mocked: Profile = profile(mock_with("evolution_test_profile_b.flf", 5000, 1, |b| {
+            b.with_seed(2).with_profile(profile_path.clone())
+        }));
+        fs::remove_file(profile_path).unwrap();
+
+        for (real, mocked) in real.columns().iter().zip(mocked.columns().iter()) {
+            assert!((real.null_rate() - mocked.null_rate()).abs() < 0.05);
+
+            if real.is_categorical() {
+                let values = |p: &ColumnProfile| -> Vec<String> {
+                    let mut values: Vec<String> =
+                        p.top_values().iter().map(|v| v.value().clone()).collect();
+                    values.sort();
+                    values
+                };
+                assert_eq!(values(real), values(mocked));
+                continue;
+            }
+
+            match real.dtype() {
+                DataType::Utf8 | DataType::LargeUtf8 => {
+                    assert!(mocked
+                        .lengths()
+                        .keys()
+                        .all(|l| real.lengths().contains_key(l)));
+                    assert!(mocked
+                        .char_classes()
+                        .keys()
+                        .all(|c| real.char_classes().contains_key(c)));
+                }
+                _ => {
+                    let number = |v: Option<&str>| v.unwrap().parse::<f64>().unwrap();
+                    assert!(number(mocked.min()) >= number(real.min()));
+                    assert!(number(mocked.max()) <= number(real.max()));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_mock_with_faults() {
+        let manifest_path: PathBuf = std::env::temp_dir().join("evolution_test_faults.json");
+        let mocked: Vec<u8> = mock_with("evolution_test_faults.flf", 2000, 1, |b| {
+            b.with_seed(7)
+                .with_fault_rate(FaultKind::UnparseableValue, 0.05)
+                .with_fault_rate(FaultKind::TruncatedLine, 0.05)
+                .with_fault_rate(FaultKind::OverlongLine, 0.05)
+                .with_fault_rate(FaultKind::InvalidUtf8, 0.05)
+                .with_fault_rate(FaultKind::WrongLineTerminator, 0.05)
+                .with_fault_rate(FaultKind::MissingTrailingNewline, 1.0)
+                .with_fault_manifest(manifest_path.clone())
+        });
+        let manifest: FaultManifest = FaultManifest::from_path(manifest_path.clone()).unwrap();
+        fs::remove_file(manifest_path).unwrap();
+
+        assert_eq!(2000, manifest.n_rows());
+        assert!(!mocked.ends_with(b"\n"));
+
+        let kinds: HashMap<usize, FaultKind> = manifest
+            .faults()
+            .iter()
+            .filter(|f| f.kind() != FaultKind::MissingTrailingNewline)
+            .map(|f| (f.row(), f.kind()))
+            .collect();
+        assert!(kinds.len() > 100);
+
+        for (row_idx, line) in mocked.split(|b| *b == b'\n').enumerate() {
+            let n_chars: Option<usize> = std::str::from_utf8(line).ok().map(|l| l.chars().count());
+            match kinds.get(&row_idx) {
+                Some(FaultKind::TruncatedLine) => assert!(n_chars.unwrap() < 158),
+                Some(FaultKind::OverlongLine) => assert!(n_chars.unwrap() > 158),
+                Some(FaultKind::InvalidUtf8) => assert!(n_chars.is_none()),
+                Some(FaultKind::WrongLineTerminator) => assert_eq!(Some(159), n_chars),
+                _ => assert_eq!(Some(158), n_chars),
+            }
+        }
+    }
+}
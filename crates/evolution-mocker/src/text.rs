@@ -0,0 +1,123 @@
+//
+// MIT License
+//
+// Copyright (c) 2026 Firelink Data
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//
+// File created: 2026-10-18
+// Last updated: 2026-10-18
+//
+
+//! Generation of strings of multibyte UTF-8 characters, so that mocked files exercise the
+//! rune counting of the parsers the same way as files of non-ASCII text do.
+
+use evolution_schema::profile::CharClass;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use std::str::FromStr;
+
+/// The letters of the Nordic alphabets, including the ASCII letters.
+static NORDIC_LETTERS: &str =
+    "abcdefghijklmnopqrstuvwxyzåäöæøðþéüABCDEFGHIJKLMNOPQRSTUVWXYZÅÄÖÆØÐÞÉÜ";
+/// The range of the CJK unified ideographs, which are 3 bytes each.
+static CJK_RANGE: std::ops::RangeInclusive<u32> = 0x4E00..=0x9FFF;
+/// The range of the emoticons, which are 4 bytes each.
+static EMOJI_RANGE: std::ops::RangeInclusive<u32> = 0x1F600..=0x1F64F;
+/// The ASCII punctuation characters.
+static PUNCTUATION: &str = "!\"#$%&'()*+,-./:;<=>?@[\\]^_`{|}~";
+
+/// The characters of mocked strings.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum Charset {
+    /// Names of ASCII characters only.
+    #[default]
+    Ascii,
+    /// Nordic letters, e.g. `å`, `ø` and `þ`, which are 1 or 2 bytes each.
+    Nordic,
+    /// CJK ideographs, which are 3 bytes each.
+    Cjk,
+    /// Emoji, which are 4 bytes each.
+    Emoji,
+    /// A mix of all of the above, i.e. characters of 1 to 4 bytes each.
+    Mixed,
+}
+
+impl FromStr for Charset {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        serde_json::from_value(serde_json::Value::String(s.to_string()))
+            .map_err(|_| format!("unknown charset '{}'", s))
+    }
+}
+
+/// Mock a string of 1 to `n_runes` characters of the charset. The strings of [`Charset::Ascii`]
+/// are names, which are mocked elsewhere, so it falls back to the Nordic letters here.
+pub(crate) fn mock_text(charset: Charset, n_runes: usize, rng: &mut impl Rng) -> String {
+    let n_chars: usize = rng.gen_range(1..=n_runes.max(1));
+    (0..n_chars)
+        .map(|_| match charset {
+            Charset::Ascii | Charset::Nordic => mock_nordic_char(rng),
+            Charset::Cjk => mock_char_in(&CJK_RANGE, rng),
+            Charset::Emoji => mock_char_in(&EMOJI_RANGE, rng),
+            Charset::Mixed => match rng.gen_range(0..3) {
+                0 => mock_nordic_char(rng),
+                1 => mock_char_in(&CJK_RANGE, rng),
+                _ => mock_char_in(&EMOJI_RANGE, rng),
+            },
+        })
+        .collect::<String>()
+}
+
+/// Mock a character of the class, where the characters of [`CharClass::Other`] are CJK
+/// ideographs and emoji.
+pub(crate) fn mock_class_char(class: CharClass, rng: &mut impl Rng) -> char {
+    match class {
+        CharClass::Upper => rng.gen_range('A'..='Z'),
+        CharClass::Lower => rng.gen_range('a'..='z'),
+        CharClass::Digit => rng.gen_range('0'..='9'),
+        CharClass::Whitespace => ' ',
+        // The punctuation characters are all ASCII, so the index is always a character.
+        CharClass::Punctuation => {
+            PUNCTUATION.as_bytes()[rng.gen_range(0..PUNCTUATION.len())] as char
+        }
+        CharClass::Other => match rng.gen_bool(0.5) {
+            true => mock_char_in(&CJK_RANGE, rng),
+            false => mock_char_in(&EMOJI_RANGE, rng),
+        },
+    }
+}
+
+/// Mock one of the [`NORDIC_LETTERS`].
+fn mock_nordic_char(rng: &mut impl Rng) -> char {
+    let n_letters: usize = NORDIC_LETTERS.chars().count();
+    // The letters are all valid characters, so the index is always within them.
+    NORDIC_LETTERS
+        .chars()
+        .nth(rng.gen_range(0..n_letters))
+        .unwrap()
+}
+
+/// Mock a character in the range of code points, which are all valid characters.
+fn mock_char_in(range: &std::ops::RangeInclusive<u32>, rng: &mut impl Rng) -> char {
+    char::from_u32(rng.gen_range(range.clone())).unwrap_or(char::REPLACEMENT_CHARACTER)
+}
@@ -0,0 +1,441 @@
+//
+// MIT License
+//
+// Copyright (c) 2026 Firelink Data
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//
+// File created: 2026-10-18
+// Last updated: 2026-10-18
+//
+
+//! The ground truth of mocked files, i.e. the values of every field of the mocked rows before
+//! they were padded, and the typed values that converting the file is expected to produce.
+
+use arrow::array::{
+    ArrayRef, ArrowPrimitiveType, BooleanBuilder, LargeStringBuilder, PrimitiveBuilder,
+    StringBuilder,
+};
+use arrow::datatypes::{
+    Date32Type, Decimal128Type, Field as ArrowField, Float16Type, Float32Type, Float64Type,
+    Int16Type, Int32Type, Int64Type, Schema as ArrowSchema, SchemaRef as ArrowSchemaRef,
+};
+use arrow::record_batch::RecordBatch;
+use chrono::{Datelike, NaiveDate};
+use evolution_common::datatype::DataType;
+use evolution_common::error::{ExecutionError, Result, SetupError};
+use evolution_schema::column::FixedColumn;
+use evolution_schema::schema::FixedSchema;
+use evolution_writer::ipc::IpcWriter;
+use evolution_writer::parquet::ParquetWriter;
+use half::f16;
+use serde::{Deserialize, Serialize};
+
+use std::fs;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// The number of days from the common era to the unix epoch, 1970-01-01.
+static DAYS_FROM_CE_TO_UNIX_EPOCH: i32 = 719_163;
+
+/// The values of every field of a number of mocked rows, column by column, before they were
+/// padded. The value of a field that was left blank is `None`.
+///
+/// Only flat schemas are supported, i.e. schemas without struct, repeating, or conditional
+/// columns, so that each field of a row is the value of exactly one column.
+#[derive(Clone, Debug)]
+pub struct GroundTruth {
+    columns: Vec<FixedColumn>,
+    values: Vec<Vec<Option<String>>>,
+}
+
+impl GroundTruth {
+    /// Try and create a new, empty [`GroundTruth`] of the rows of the schema.
+    ///
+    /// # Errors
+    /// If the schema has any struct, repeating, or conditional columns.
+    pub fn try_new(schema: &FixedSchema) -> Result<Self> {
+        if let Some(column) = schema.iter().find(|c| {
+            c.dtype() == DataType::Struct || c.occurs().is_some() || c.condition().is_some()
+        }) {
+            return Err(Box::new(SetupError::new(&format!(
+                "The ground truth of mocked rows only supports flat schemas, but column '{}' is a struct, repeating, or conditional column, exiting...",
+                column.name(),
+            ))));
+        }
+
+        Ok(Self {
+            columns: schema.columns().clone(),
+            values: vec![Vec::new(); schema.num_columns()],
+        })
+    }
+
+    /// Get the columns of the rows.
+    pub fn columns(&self) -> &[FixedColumn] {
+        &self.columns
+    }
+
+    /// Get the number of rows.
+    pub fn n_rows(&self) -> usize {
+        self.values.first().map_or(0, |v| v.len())
+    }
+
+    /// Get the values of the column at the index, one for each row.
+    pub fn values(&self, column_idx: usize) -> &[Option<String>] {
+        &self.values[column_idx]
+    }
+
+    /// Push the values of the fields of a row, one for each column, and leave the row empty.
+    pub(crate) fn push_row(&mut self, row: &mut Vec<Option<String>>) {
+        for (values, value) in self.values.iter_mut().zip(row.drain(..)) {
+            values.push(value);
+        }
+    }
+
+    /// Append the rows of another [`GroundTruth`] of the same schema after the rows of this one.
+    pub(crate) fn append(&mut self, other: GroundTruth) {
+        for (values, other_values) in self.values.iter_mut().zip(other.values) {
+            values.extend(other_values);
+        }
+    }
+
+    /// Try and create a [`RecordBatch`] of the typed values of the rows, which are the values
+    /// that converting the mocked file is expected to produce. Blank fields are the default
+    /// value of the column if it has one, otherwise empty strings for string columns and nulls
+    /// for all other columns.
+    ///
+    /// # Errors
+    /// If any of the values can not be read as the datatype of its column.
+    pub fn try_to_record_batch(&self) -> Result<RecordBatch> {
+        let arrays: Vec<ArrayRef> = self
+            .columns
+            .iter()
+            .zip(self.values.iter())
+            .map(|(column, values)| try_build_array(column, values))
+            .collect::<Result<Vec<ArrayRef>>>()?;
+
+        Ok(RecordBatch::try_new(self.arrow_schema(), arrays)?)
+    }
+
+    /// Get the [`ArrowSchemaRef`] of the typed values of the rows, which is the schema of the
+    /// converted file.
+    pub fn arrow_schema(&self) -> ArrowSchemaRef {
+        Arc::new(ArrowSchema::new(
+            self.columns
+                .iter()
+                .map(|c| ArrowField::new(c.name(), c.as_arrow_dtype(), c.is_nullable()))
+                .collect::<Vec<ArrowField>>(),
+        ))
+    }
+}
+
+/// The file formats to write the typed values of the mocked rows to.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum GroundTruthFormat {
+    #[default]
+    Parquet,
+    Ipc,
+}
+
+impl FromStr for GroundTruthFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        serde_json::from_value(serde_json::Value::String(s.to_string()))
+            .map_err(|_| format!("unknown ground truth format '{}'", s))
+    }
+}
+
+/// A writer of the typed values of the mocked rows to a parquet or IPC file.
+enum GroundTruthWriter {
+    Parquet(ParquetWriter),
+    Ipc(IpcWriter),
+}
+
+impl GroundTruthWriter {
+    /// Try and create a new [`GroundTruthWriter`] of the file format, writing to the path, which
+    /// is replaced if it already exists.
+    ///
+    /// # Errors
+    /// If the file could not be replaced or created.
+    fn try_new(path: PathBuf, format: GroundTruthFormat, schema: ArrowSchemaRef) -> Result<Self> {
+        // The parquet writer appends to existing files, so any previous file is removed first.
+        if path.exists() {
+            fs::remove_file(&path)?;
+        }
+
+        Ok(match format {
+            GroundTruthFormat::Parquet => Self::Parquet(
+                ParquetWriter::builder()
+                    .with_out_path(path)
+                    .with_arrow_schema(schema)
+                    .try_build()?,
+            ),
+            GroundTruthFormat::Ipc => Self::Ipc(
+                IpcWriter::builder()
+                    .with_out_path(path)
+                    .with_arrow_schema(schema)
+                    .try_build()?,
+            ),
+        })
+    }
+
+    /// Try and write the typed values of the rows as a record batch.
+    fn try_write(&mut self, truth: &GroundTruth) -> Result<()> {
+        let record_batch: RecordBatch = truth.try_to_record_batch()?;
+        match self {
+            Self::Parquet(writer) => writer.try_write_batch(&record_batch),
+            Self::Ipc(writer) => writer.try_write_batch(&record_batch),
+        }
+    }
+
+    /// Try and finish the file.
+    fn try_finish(&mut self) -> Result<()> {
+        match self {
+            Self::Parquet(writer) => writer.try_finish().map(|_| ()),
+            Self::Ipc(writer) => writer.try_finish(),
+        }
+    }
+}
+
+/// A recorder of the ground truth of the mocked rows, chunk by chunk in the order of the rows,
+/// which keeps all of the rows in memory and/or writes the typed values of each chunk to a file.
+pub(crate) struct GroundTruthRecorder {
+    /// An empty ground truth, to record each chunk into.
+    empty: GroundTruth,
+    /// All of the recorded rows, if they are kept.
+    rows: Option<GroundTruth>,
+    /// The writer of the typed values of the rows, if any.
+    writer: Option<GroundTruthWriter>,
+}
+
+impl GroundTruthRecorder {
+    /// Try and create a new [`GroundTruthRecorder`] of the rows of the schema, which keeps the
+    /// rows if requested, and writes them to the file of the format at the path, if any.
+    ///
+    /// # Errors
+    /// If the schema is not flat, see [`GroundTruth::try_new`], or if the file could not be
+    /// created.
+    pub(crate) fn try_new(
+        schema: &FixedSchema,
+        keep_rows: bool,
+        file: Option<(PathBuf, GroundTruthFormat)>,
+    ) -> Result<Self> {
+        let empty: GroundTruth = GroundTruth::try_new(schema)?;
+        let writer: Option<GroundTruthWriter> = match file {
+            Some((path, format)) => Some(GroundTruthWriter::try_new(
+                path,
+                format,
+                empty.arrow_schema(),
+            )?),
+            None => None,
+        };
+
+        Ok(Self {
+            rows: keep_rows.then(|| empty.clone()),
+            empty,
+            writer,
+        })
+    }
+
+    /// Create an empty ground truth, to record the next chunk of rows into.
+    pub(crate) fn new_chunk(&self) -> GroundTruth {
+        self.empty.clone()
+    }
+
+    /// Try and record the next chunk of rows.
+    ///
+    /// # Errors
+    /// If the typed values of the rows could not be written to the file.
+    pub(crate) fn try_record(&mut self, chunk: GroundTruth) -> Result<()> {
+        if let Some(writer) = self.writer.as_mut() {
+            writer.try_write(&chunk)?;
+        }
+
+        if let Some(rows) = self.rows.as_mut() {
+            rows.append(chunk);
+        }
+
+        Ok(())
+    }
+
+    /// Try and finish the file of the typed values, if any, and get the rows, if they were kept.
+    ///
+    /// # Errors
+    /// If the file could not be finished.
+    pub(crate) fn try_finish(mut self) -> Result<Option<GroundTruth>> {
+        if let Some(writer) = self.writer.as_mut() {
+            writer.try_finish()?;
+        }
+
+        Ok(self.rows)
+    }
+}
+
+/// Try and build the array of the typed values of a column.
+fn try_build_array(column: &FixedColumn, values: &[Option<String>]) -> Result<ArrayRef> {
+    match column.dtype() {
+        DataType::Boolean => {
+            let mut builder = BooleanBuilder::with_capacity(values.len());
+            for value in values {
+                match typed_value(column, value) {
+                    Some(v) => builder.append_value(try_parse_value::<bool>(column, v)?),
+                    None => builder.append_null(),
+                }
+            }
+            Ok(Arc::new(builder.finish()))
+        }
+        DataType::Float16 => try_build_primitive_array::<Float16Type>(column, values, |v| {
+            f16::from_str(&with_implied_decimal_point(column, v)?).ok()
+        }),
+        DataType::Float32 => try_build_primitive_array::<Float32Type>(column, values, |v| {
+            f32::from_str(&with_implied_decimal_point(column, v)?).ok()
+        }),
+        DataType::Float64 => try_build_primitive_array::<Float64Type>(column, values, |v| {
+            f64::from_str(&with_implied_decimal_point(column, v)?).ok()
+        }),
+        DataType::Int16 => {
+            try_build_primitive_array::<Int16Type>(column, values, |v| i16::from_str(v).ok())
+        }
+        DataType::Int32 => {
+            try_build_primitive_array::<Int32Type>(column, values, |v| i32::from_str(v).ok())
+        }
+        DataType::Int64 => {
+            try_build_primitive_array::<Int64Type>(column, values, |v| i64::from_str(v).ok())
+        }
+        DataType::Date32 => try_build_primitive_array::<Date32Type>(column, values, |v| {
+            NaiveDate::parse_from_str(v, column.date_format())
+                .ok()
+                .map(|d| d.num_days_from_ce() - DAYS_FROM_CE_TO_UNIX_EPOCH)
+        }),
+        DataType::Decimal128 => try_build_primitive_array::<Decimal128Type>(column, values, |v| {
+            scaled_decimal(&with_implied_decimal_point(column, v)?, column)
+        }),
+        DataType::Utf8 => {
+            let mut builder = StringBuilder::with_capacity(values.len(), 0);
+            for value in values {
+                builder.append_value(typed_string(column, value));
+            }
+            Ok(Arc::new(builder.finish()))
+        }
+        DataType::LargeUtf8 => {
+            let mut builder = LargeStringBuilder::with_capacity(values.len(), 0);
+            for value in values {
+                builder.append_value(typed_string(column, value));
+            }
+            Ok(Arc::new(builder.finish()))
+        }
+        DataType::Struct => Err(Box::new(SetupError::new(
+            "The ground truth of mocked rows does not support struct columns, exiting...",
+        ))),
+    }
+}
+
+/// Try and build the array of the typed values of a column of a primitive datatype, where each
+/// value is read by the function.
+fn try_build_primitive_array<T: ArrowPrimitiveType>(
+    column: &FixedColumn,
+    values: &[Option<String>],
+    read: impl Fn(&str) -> Option<T::Native>,
+) -> Result<ArrayRef> {
+    let mut builder =
+        PrimitiveBuilder::<T>::with_capacity(values.len()).with_data_type(column.as_arrow_dtype());
+    for value in values {
+        match typed_value(column, value) {
+            Some(v) => builder.append_value(read(v).ok_or_else(|| invalid_value(column, v))?),
+            None => builder.append_null(),
+        }
+    }
+    Ok(Arc::new(builder.finish()))
+}
+
+/// Get the value of a field, or the default value of the column if the field was left blank.
+fn typed_value<'a>(column: &'a FixedColumn, value: &'a Option<String>) -> Option<&'a str> {
+    value.as_deref().or(column.default())
+}
+
+/// Get the value of a field of a string column, where an empty field is the default value of
+/// the column if it has one, and an empty string otherwise.
+fn typed_string<'a>(column: &'a FixedColumn, value: &'a Option<String>) -> &'a str {
+    match (value.as_deref(), column.default()) {
+        (Some(v), _) if !v.is_empty() => v,
+        (_, Some(default)) => default,
+        _ => "",
+    }
+}
+
+/// Try and parse the value of a field as the type.
+fn try_parse_value<T: FromStr>(column: &FixedColumn, value: &str) -> Result<T> {
+    value
+        .parse::<T>()
+        .map_err(|_| Box::new(invalid_value(column, value)).into())
+}
+
+/// Write the digits of a value with the implied decimals of the column, if it has any, with a
+/// decimal point before the decimals. Returns `None` if the value is not a number of digits.
+fn with_implied_decimal_point(column: &FixedColumn, value: &str) -> Option<String> {
+    let n_decimals: usize = match column.implied_decimals() {
+        Some(n) if n > 0 => n,
+        _ => return Some(value.to_string()),
+    };
+
+    let (sign, digits) = match value.strip_prefix('-') {
+        Some(digits) => ("-", digits),
+        None => ("", value),
+    };
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    let digits: String = format!("{:0>width$}", digits, width = n_decimals + 1);
+    let (integer, fraction) = digits.split_at(digits.len() - n_decimals);
+    Some(format!("{}{}.{}", sign, integer, fraction))
+}
+
+/// Read a decimal number as an integer scaled by ten to the power of the scale of the column.
+/// Returns `None` if the number has more decimals than the scale.
+fn scaled_decimal(value: &str, column: &FixedColumn) -> Option<i128> {
+    let (_, scale) = column.precision_and_scale();
+    let (integer, fraction) = value.split_once('.').unwrap_or((value, ""));
+    if fraction.len() > scale {
+        return None;
+    }
+
+    format!(
+        "{}{}{:0<scale$}",
+        integer,
+        fraction,
+        "",
+        scale = scale - fraction.len()
+    )
+    .parse::<i128>()
+    .ok()
+}
+
+/// Create the error of a value which can not be read as the datatype of its column.
+fn invalid_value(column: &FixedColumn, value: &str) -> ExecutionError {
+    ExecutionError::new(&format!(
+        "The mocked value '{}' of column '{}' is not a valid '{:?}', exiting...",
+        value,
+        column.name(),
+        column.dtype(),
+    ))
+}
@@ -22,16 +22,18 @@
 // SOFTWARE.
 //
 // File created: 2024-05-08
-// Last updated: 2024-06-01
+// Last updated: 2026-10-18
 //
 
+use chrono::{Datelike, NaiveDate};
+use evolution_common::datatype::SignFormat;
 use padder::{Alignment, Symbol};
 
 use std::str::{from_utf8_unchecked, FromStr};
 use std::usize;
 
 use crate::parser::Parser;
-use crate::trimmer::{FloatTrimmer, IntTrimmer, TextTrimmer};
+use crate::trimmer::{count_bytes_in_runes, FloatTrimmer, IntTrimmer, TextTrimmer};
 
 ///
 pub struct BooleanParser {
@@ -65,13 +67,186 @@ impl BooleanParser {
 
         (end_byte_idx, self.trimmer.trim(text).parse::<bool>().ok())
     }
+
+    /// Check whether the field, as counted by [`BooleanParser::try_parse`], holds nothing but padding
+    /// or whitespace, i.e., whether it is empty rather than not a valid boolean.
+    pub fn is_empty(&self, field: &[u8]) -> bool {
+        is_blank(self.trimmer.trim(unsafe { from_utf8_unchecked(field) }))
+    }
 }
 
 impl Parser for BooleanParser {}
 
+/// Check whether the trimmed text of a field is empty, ignoring any whitespace.
+fn is_blank(text: &str) -> bool {
+    text.trim().is_empty()
+}
+
+/// The number of days from the common era to the unix epoch, which dates are counted from.
+static DAYS_FROM_CE_TO_UNIX_EPOCH: i32 = 719_163;
+
+/// The format of dates when none is specified, e.g., `2024-12-31`.
+pub static DEFAULT_DATE_FORMAT: &str = "%Y-%m-%d";
+
+/// The maximum number of digits of a 128-bit decimal.
+pub static MAX_DECIMAL128_PRECISION: usize = 38;
+
+/// A parser of dates written in a [`chrono`] format, e.g., `%Y%m%d`.
+pub struct DateParser {
+    trimmer: TextTrimmer,
+    format: String,
+}
+
+impl DateParser {
+    /// Create a new [`DateParser`] for dates written in the provided format.
+    pub fn new(alignment: Alignment, trim_symbol: Symbol, format: String) -> Self {
+        Self {
+            trimmer: TextTrimmer::new(alignment, trim_symbol),
+            format,
+        }
+    }
+
+    /// Try and parse the byte slice as a date, as the number of days since the unix epoch,
+    /// and count the number of bytes that the date was represented as in the byte slice.
+    ///
+    /// # Safety
+    /// See [`BooleanParser::try_parse`], the byte slice is assumed to be valid UTF-8.
+    pub fn try_parse(&self, bytes: &[u8], n_runes: usize) -> (usize, Option<i32>) {
+        let end_byte_idx: usize = self.trimmer.find_byte_indices(bytes, n_runes);
+        let text: &str = unsafe { from_utf8_unchecked(&bytes[..end_byte_idx]) };
+
+        (
+            end_byte_idx,
+            parse_date(self.trimmer.trim(text), &self.format),
+        )
+    }
+
+    /// Check whether the field, as counted by [`DateParser::try_parse`], holds nothing but padding
+    /// or whitespace, i.e., whether it is empty rather than not a valid date.
+    pub fn is_empty(&self, field: &[u8]) -> bool {
+        is_blank(self.trimmer.trim(unsafe { from_utf8_unchecked(field) }))
+    }
+}
+
+impl Parser for DateParser {}
+
+/// Parse the text as a date written in the provided format, as the number of days since the
+/// unix epoch. Returns `None` if the text is not a valid date in the format.
+pub fn parse_date(text: &str, format: &str) -> Option<i32> {
+    NaiveDate::parse_from_str(text, format)
+        .ok()
+        .map(|d| d.num_days_from_ce() - DAYS_FROM_CE_TO_UNIX_EPOCH)
+}
+
+/// A parser of decimal numbers, which are stored as integers scaled by ten to the power of
+/// the scale, e.g., `123.45` is stored as `12345` with a scale of two.
+pub struct DecimalParser {
+    trimmer: FloatTrimmer,
+    precision: usize,
+    scale: usize,
+    implied_decimals: usize,
+    sign_format: Option<SignFormat>,
+}
+
+impl DecimalParser {
+    /// Create a new [`DecimalParser`] for numbers with at most `precision` digits, of which
+    /// `scale` are decimals.
+    pub fn new(alignment: Alignment, trim_symbol: Symbol, precision: usize, scale: usize) -> Self {
+        Self {
+            trimmer: FloatTrimmer::new(alignment, trim_symbol),
+            precision,
+            scale,
+            implied_decimals: 0,
+            sign_format: None,
+        }
+    }
+
+    /// Set the number of trailing digits which are decimals even though the field has no
+    /// decimal point, see [`FloatParser::with_implied_decimals`].
+    pub fn with_implied_decimals(mut self, implied_decimals: usize) -> Self {
+        self.implied_decimals = implied_decimals;
+        self
+    }
+
+    /// Set how the sign of the numbers is written, if not as a leading `-`.
+    pub fn with_sign_format(mut self, sign_format: Option<SignFormat>) -> Self {
+        self.sign_format = sign_format;
+        self
+    }
+
+    /// Try and parse the byte slice as a scaled decimal number and count the number of bytes
+    /// that the number was represented as in the byte slice.
+    ///
+    /// # Safety
+    /// See [`BooleanParser::try_parse`], the byte slice is assumed to be valid UTF-8.
+    pub fn try_parse(&self, bytes: &[u8], n_runes: usize) -> (usize, Option<i128>) {
+        let end_byte_idx: usize = self.trimmer.find_byte_indices(bytes, n_runes);
+        let text: &str = self
+            .trimmer
+            .trim(unsafe { from_utf8_unchecked(&bytes[..end_byte_idx]) });
+        let text: Option<String> = decode_sign(text, self.sign_format);
+
+        let value: Option<i128> = text.and_then(|text| match self.implied_decimals {
+            0 => parse_decimal(&text, self.precision, self.scale),
+            n => insert_implied_decimal_point(&text, n)
+                .and_then(|t| parse_decimal(&t, self.precision, self.scale)),
+        });
+
+        (end_byte_idx, value)
+    }
+
+    /// Check whether the field, as counted by [`DecimalParser::try_parse`], holds nothing but padding
+    /// or whitespace, i.e., whether it is empty rather than not a valid decimal.
+    pub fn is_empty(&self, field: &[u8]) -> bool {
+        is_blank(self.trimmer.trim(unsafe { from_utf8_unchecked(field) }))
+    }
+}
+
+impl Parser for DecimalParser {}
+
+/// Parse the text as a decimal number with at most `precision` digits, of which `scale` are
+/// decimals, as an integer scaled by ten to the power of the scale. Returns `None` if the text
+/// is not a number, has more decimals than the scale, or has more digits than the precision.
+pub fn parse_decimal(text: &str, precision: usize, scale: usize) -> Option<i128> {
+    let (is_negative, number) = match text.strip_prefix('-') {
+        Some(number) => (true, number),
+        None => (false, text.strip_prefix('+').unwrap_or(text)),
+    };
+    let (integer, fraction) = number.split_once('.').unwrap_or((number, ""));
+    if integer.is_empty() && fraction.is_empty() {
+        return None;
+    }
+
+    let integer: &str = integer.trim_start_matches('0');
+    if !integer
+        .bytes()
+        .chain(fraction.bytes())
+        .all(|b| b.is_ascii_digit())
+        || fraction.len() > scale
+        || integer.len() + scale > precision.min(MAX_DECIMAL128_PRECISION)
+    {
+        return None;
+    }
+
+    let digits: String = format!(
+        "{}{}{}",
+        integer,
+        fraction,
+        "0".repeat(scale - fraction.len())
+    );
+    let value: i128 = match digits.is_empty() {
+        true => 0,
+        false => digits.parse::<i128>().ok()?,
+    };
+
+    Some(if is_negative { -value } else { value })
+}
+
 ///
 pub struct FloatParser {
     trimmer: FloatTrimmer,
+    implied_decimals: usize,
+    sign_format: Option<SignFormat>,
 }
 
 impl FloatParser {
@@ -79,9 +254,24 @@ impl FloatParser {
     pub fn new(alignment: Alignment, trim_symbol: Symbol) -> Self {
         Self {
             trimmer: FloatTrimmer::new(alignment, trim_symbol),
+            implied_decimals: 0,
+            sign_format: None,
         }
     }
 
+    /// Set the number of trailing digits which are decimals even though the field has no
+    /// decimal point, e.g., `12345` is parsed as `123.45` with two implied decimals.
+    pub fn with_implied_decimals(mut self, implied_decimals: usize) -> Self {
+        self.implied_decimals = implied_decimals;
+        self
+    }
+
+    /// Set how the sign of the numbers is written, if not as a leading `-`.
+    pub fn with_sign_format(mut self, sign_format: Option<SignFormat>) -> Self {
+        self.sign_format = sign_format;
+        self
+    }
+
     /// Try and parse the byte slice as UTF-8 characters and count the number of
     /// bytes that the boolean was represented as in the byte slice.
     ///
@@ -105,15 +295,95 @@ impl FloatParser {
         // FLOAT WITH SIMD?
         let text: &str = unsafe { from_utf8_unchecked(&bytes[..end_byte_idx]) };
 
+        if self.implied_decimals > 0 || self.sign_format.is_some() {
+            let value: Option<T> = decode_sign(self.trimmer.trim(text), self.sign_format)
+                .and_then(|t| match self.implied_decimals {
+                    0 => Some(t),
+                    n => insert_implied_decimal_point(&t, n),
+                })
+                .and_then(|t| t.parse::<T>().ok());
+            return (end_byte_idx, value);
+        }
+
         (end_byte_idx, self.trimmer.trim(text).parse::<T>().ok())
     }
+
+    /// Check whether the field, as counted by [`FloatParser::try_parse`], holds nothing but padding
+    /// or whitespace, i.e., whether it is empty rather than not a valid float.
+    pub fn is_empty(&self, field: &[u8]) -> bool {
+        is_blank(self.trimmer.trim(unsafe { from_utf8_unchecked(field) }))
+    }
 }
 
 impl Parser for FloatParser {}
 
+/// Decode the sign of a number written in the provided sign format into a leading `-`, e.g.,
+/// `1234J` with a trailing overpunch is `-12341`. Numbers which are written with a leading sign
+/// or without a sign are kept as they are. Returns `None` if the text ends (or starts) with
+/// something which is neither a digit nor a sign of the format.
+fn decode_sign(text: &str, sign_format: Option<SignFormat>) -> Option<String> {
+    let overpunched = |c: char| -> Option<(bool, char)> {
+        match c {
+            '0'..='9' => Some((false, c)),
+            '{' => Some((false, '0')),
+            'A'..='I' => Some((false, (b'1' + (c as u8 - b'A')) as char)),
+            '}' => Some((true, '0')),
+            'J'..='R' => Some((true, (b'1' + (c as u8 - b'J')) as char)),
+            _ => None,
+        }
+    };
+    let signed = |is_negative: bool, number: String| -> String {
+        match is_negative {
+            true => format!("-{}", number),
+            false => number,
+        }
+    };
+
+    match (sign_format, text.chars().next(), text.chars().last()) {
+        (None, _, _) | (_, None, _) => Some(text.to_string()),
+        (Some(SignFormat::LeadingOverpunch), Some(c), _) => match c {
+            '+' | '-' => Some(text.to_string()),
+            c => overpunched(c)
+                .map(|(is_negative, d)| signed(is_negative, format!("{}{}", d, &text[1..]))),
+        },
+        (Some(SignFormat::TrailingOverpunch), Some(first), Some(c)) => match (first, c) {
+            ('+' | '-', '0'..='9') => Some(text.to_string()),
+            _ => overpunched(c).map(|(is_negative, d)| {
+                signed(is_negative, format!("{}{}", &text[..text.len() - 1], d))
+            }),
+        },
+        (Some(SignFormat::TrailingSeparate), Some(_), Some(c)) => match c {
+            '+' | '-' => Some(signed(c == '-', text[..text.len() - 1].to_string())),
+            _ => Some(text.to_string()),
+        },
+        (Some(_), Some(_), None) => None,
+    }
+}
+
+/// Insert a decimal point before the last `n_decimals` digits of the text, keeping any
+/// leading sign, and left pad the digits with zeros if there are fewer than `n_decimals`.
+/// Returns `None` if the text is empty or contains anything but an optional sign and digits.
+fn insert_implied_decimal_point(text: &str, n_decimals: usize) -> Option<String> {
+    let (sign, digits) = match text.strip_prefix(['-', '+']) {
+        Some(digits) => (&text[..1], digits),
+        None => ("", text),
+    };
+
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    let n_zeros: usize = (n_decimals + 1).saturating_sub(digits.len());
+    let padded: String = format!("{}{}", "0".repeat(n_zeros), digits);
+    let (integer, fraction) = padded.split_at(padded.len() - n_decimals);
+
+    Some(format!("{}{}.{}", sign, integer, fraction))
+}
+
 ///
 pub struct IntParser {
     trimmer: IntTrimmer,
+    sign_format: Option<SignFormat>,
 }
 
 impl IntParser {
@@ -121,15 +391,30 @@ impl IntParser {
     pub fn new() -> Self {
         Self {
             trimmer: IntTrimmer::new(),
+            sign_format: None,
         }
     }
 
+    /// Set how the sign of the numbers is written, if not as a leading `-`.
+    pub fn with_sign_format(mut self, sign_format: Option<SignFormat>) -> Self {
+        self.sign_format = sign_format;
+        self
+    }
+
     /// Try and parse the byte slice as UTF-8 characters and count the number of
     /// bytes that the boolean was represented as in the byte slice.
     pub fn try_parse<T>(&self, bytes: &[u8], n_runes: usize) -> (usize, Option<T>)
     where
         T: atoi_simd::Parse + atoi_simd::ParseNeg,
     {
+        if self.sign_format.is_some() {
+            let n_bytes_in_column: usize = count_bytes_in_runes(bytes, n_runes);
+            let text: &str = unsafe { from_utf8_unchecked(&bytes[..n_bytes_in_column]) };
+            let value: Option<T> = decode_sign(text.trim(), self.sign_format)
+                .and_then(|t| atoi_simd::parse::<T>(t.as_bytes()).ok());
+            return (n_bytes_in_column, value);
+        }
+
         let (start_byte_idx, end_byte_idx, n_bytes_in_column): (usize, usize, usize) =
             self.trimmer.find_byte_indices(bytes, n_runes);
 
@@ -137,6 +422,17 @@ impl IntParser {
 
         (n_bytes_in_column, value)
     }
+
+    /// Check whether the field, as counted by [`IntParser::try_parse`], holds nothing but
+    /// whitespace or a run of a single padding symbol, i.e., whether it is empty rather than
+    /// not a valid integer. A run of zeros is not empty, it is parsed as zero.
+    pub fn is_empty(&self, field: &[u8]) -> bool {
+        let text: &str = unsafe { from_utf8_unchecked(field) }.trim();
+        match text.chars().next() {
+            Some(c) => !c.is_ascii_digit() && text.chars().all(|r| r == c),
+            None => true,
+        }
+    }
 }
 
 impl Parser for IntParser {}
@@ -176,3 +472,91 @@ impl Utf8Parser {
 }
 
 impl Parser for Utf8Parser {}
+
+#[cfg(test)]
+mod tests_datatype {
+    use super::*;
+
+    #[test]
+    fn test_parse_date() {
+        assert_eq!(Some(0), parse_date("1970-01-01", DEFAULT_DATE_FORMAT));
+        assert_eq!(Some(19_723), parse_date("20240101", "%Y%m%d"));
+        assert_eq!(Some(-1), parse_date("31.12.1969", "%d.%m.%Y"));
+        assert_eq!(None, parse_date("2024-02-30", DEFAULT_DATE_FORMAT));
+        assert_eq!(None, parse_date("", DEFAULT_DATE_FORMAT));
+
+        let parser = DateParser::new(Alignment::Left, Symbol::Whitespace, "%Y%m%d".to_string());
+        assert_eq!((10, Some(19_723)), parser.try_parse(b"20240101  ", 10));
+    }
+
+    #[test]
+    fn test_parse_decimal() {
+        assert_eq!(Some(12_345), parse_decimal("123.45", 5, 2));
+        assert_eq!(Some(-12_300), parse_decimal("-123", 5, 2));
+        assert_eq!(Some(50), parse_decimal("0.5", 5, 2));
+        assert_eq!(Some(0), parse_decimal("000", 5, 2));
+        assert_eq!(None, parse_decimal("1.234", 5, 2));
+        assert_eq!(None, parse_decimal("1234", 5, 2));
+        assert_eq!(None, parse_decimal("12a", 5, 2));
+        assert_eq!(None, parse_decimal("", 5, 2));
+
+        let parser =
+            DecimalParser::new(Alignment::Right, Symbol::Whitespace, 7, 2).with_implied_decimals(2);
+        assert_eq!((7, Some(-1_250)), parser.try_parse(b" -01250", 7));
+    }
+
+    #[test]
+    fn test_decode_sign() {
+        let trailing: Option<SignFormat> = Some(SignFormat::TrailingOverpunch);
+        assert_eq!(Some("-12341".to_string()), decode_sign("1234J", trailing));
+        assert_eq!(Some("12340".to_string()), decode_sign("1234{", trailing));
+        assert_eq!(Some("-12340".to_string()), decode_sign("1234}", trailing));
+        assert_eq!(Some("12349".to_string()), decode_sign("1234I", trailing));
+        assert_eq!(Some("-12349".to_string()), decode_sign("-12349", trailing));
+        assert_eq!(None, decode_sign("1234Z", trailing));
+
+        let leading: Option<SignFormat> = Some(SignFormat::LeadingOverpunch);
+        assert_eq!(Some("-21234".to_string()), decode_sign("K1234", leading));
+        assert_eq!(Some("01234".to_string()), decode_sign("{1234", leading));
+
+        let separate: Option<SignFormat> = Some(SignFormat::TrailingSeparate);
+        assert_eq!(Some("-1234".to_string()), decode_sign("1234-", separate));
+        assert_eq!(Some("1234".to_string()), decode_sign("1234+", separate));
+        assert_eq!(Some("1234".to_string()), decode_sign("1234", separate));
+    }
+
+    #[test]
+    fn test_parse_signed_numbers() {
+        let parser = IntParser::new().with_sign_format(Some(SignFormat::TrailingOverpunch));
+        assert_eq!((6, Some(-1_231)), parser.try_parse::<i32>(b"00123J", 6));
+        assert_eq!((6, Some(1_230)), parser.try_parse::<i32>(b"  123{", 6));
+
+        let parser = FloatParser::new(Alignment::Right, Symbol::Whitespace)
+            .with_implied_decimals(2)
+            .with_sign_format(Some(SignFormat::TrailingOverpunch));
+        assert_eq!((7, Some(-123.45)), parser.try_parse::<f64>(b"001234N", 7));
+
+        let parser = DecimalParser::new(Alignment::Right, Symbol::Whitespace, 9, 2)
+            .with_implied_decimals(2)
+            .with_sign_format(Some(SignFormat::TrailingSeparate));
+        assert_eq!((8, Some(-12_345)), parser.try_parse(b"0012345-", 8));
+    }
+
+    #[test]
+    fn test_is_empty_field() {
+        let parser = BooleanParser::new(Alignment::Center, Symbol::Asterisk);
+        assert!(parser.is_empty(b"*****"));
+        assert!(parser.is_empty(b"     "));
+        assert!(!parser.is_empty(b"*yes*"));
+
+        let parser = IntParser::new();
+        assert!(parser.is_empty(b"      "));
+        assert!(parser.is_empty(b"------"));
+        assert!(!parser.is_empty(b"000000"));
+        assert!(!parser.is_empty(b"   abc"));
+
+        let parser = FloatParser::new(Alignment::Right, Symbol::Zero);
+        assert!(parser.is_empty(b"0000"));
+        assert!(!parser.is_empty(b"00.5"));
+    }
+}
@@ -22,7 +22,7 @@
 // SOFTWARE.
 //
 // File created: 2024-05-31
-// Last updated: 2024-06-01
+// Last updated: 2026-10-18
 //
 
 use log::warn;
@@ -33,6 +33,29 @@ use std::slice::Iter;
 ///
 pub trait Trimmer {}
 
+/// Count the number of bytes that the provided number of UTF-8 encoded runes occupy at the
+/// start of the byte slice. If the byte slice contains fewer runes, then its length is returned.
+///
+/// # Panics
+/// If the byte slice contains an invalid UTF-8 sequence.
+pub fn count_bytes_in_runes(bytes: &[u8], n_runes: usize) -> usize {
+    let mut n_bytes: usize = 0;
+    let mut n_found_runes: usize = 0;
+
+    while n_found_runes < n_runes && n_bytes < bytes.len() {
+        n_bytes += match bytes[n_bytes] {
+            byte if byte >> 7 == 0 => 1,
+            byte if byte >> 5 == 0b110 => 2,
+            byte if byte >> 4 == 0b1110 => 3,
+            byte if byte >> 3 == 0b11110 => 4,
+            _ => panic!("Couldn't parse byte slice, invalid UTF-8 sequence!"),
+        };
+        n_found_runes += 1;
+    }
+
+    n_bytes.min(bytes.len())
+}
+
 ///
 pub type TrimmerRef = Box<dyn Trimmer>;
 
@@ -22,22 +22,108 @@
 // SOFTWARE.
 //
 // File created: 2023-11-25
-// Last updated: 2024-10-11
+// Last updated: 2026-10-18
 //
 
-use arrow::datatypes::DataType as ArrowDataType;
-use deltalake::kernel::DataType as DeltaDataType;
+use arrow::datatypes::Fields as ArrowFields;
+use arrow::datatypes::{DataType as ArrowDataType, Field as ArrowField};
+use chrono::format::{Item, StrftimeItems};
+use deltalake::kernel::{
+    ArrayType as DeltaArrayType, DataType as DeltaDataType, PrimitiveType as DeltaPrimitiveType,
+    StructField as DeltaStructField, StructType as DeltaStructType,
+};
+use evolution_builder::alternative::SharedField;
 use evolution_builder::builder::ColumnBuilderRef;
 use evolution_builder::datatype::{
-    BooleanColumnBuilder, Float16ColumnBuilder, Float32ColumnBuilder, Float64ColumnBuilder,
-    Int16ColumnBuilder, Int32ColumnBuilder, Int64ColumnBuilder, Utf8ColumnBuilder,
+    BooleanColumnBuilder, Date32ColumnBuilder, Decimal128ColumnBuilder, Float16ColumnBuilder,
+    Float32ColumnBuilder, Float64ColumnBuilder, Int16ColumnBuilder, Int32ColumnBuilder,
+    Int64ColumnBuilder, Utf8ColumnBuilder,
+};
+use evolution_builder::list::{FixedSizeListColumnBuilder, ListColumnBuilder, LIST_ITEM_NAME};
+use evolution_builder::nested::StructColumnBuilder;
+use evolution_common::datatype::{DataType, SignFormat};
+use evolution_common::error::{Result, SetupError};
+use evolution_parser::datatype::{
+    parse_date, parse_decimal, BooleanParser, DateParser, DecimalParser, FloatParser, IntParser,
+    Utf8Parser, DEFAULT_DATE_FORMAT, MAX_DECIMAL128_PRECISION,
 };
-use evolution_common::datatype::DataType;
-use evolution_parser::datatype::{BooleanParser, FloatParser, IntParser, Utf8Parser};
+use evolution_parser::trimmer::count_bytes_in_runes;
+use half::f16;
 use log::warn;
 use padder::{Alignment, Symbol};
 use serde::{Deserialize, Serialize};
 
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+
+/// The repeats of a column whose field holds several elements of the same layout, e.g., twelve
+/// monthly amounts, which is built as a list column.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct Occurs {
+    /// The number of elements, which is also the maximum number of elements in use if the
+    /// number of elements depends on another column.
+    count: usize,
+    /// The name of an earlier integer column holding the number of elements in use in each
+    /// row, if not all elements are always in use. The field always reserves space for
+    /// `count` elements.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    depending_on: Option<String>,
+}
+
+impl Occurs {
+    /// Create a new [`Occurs`] where all `count` elements are always in use.
+    pub fn new(count: usize) -> Self {
+        Self {
+            count,
+            depending_on: None,
+        }
+    }
+
+    /// Set the name of the column holding the number of elements in use in each row.
+    pub fn with_depending_on(mut self, column: String) -> Self {
+        self.depending_on = Some(column);
+        self
+    }
+
+    /// Get the (maximum) number of elements.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Get the name of the column holding the number of elements in use, if any.
+    pub fn depending_on(&self) -> Option<&str> {
+        self.depending_on.as_deref()
+    }
+}
+
+/// The condition under which an alternative layout of a region of the record is in use, e.g.,
+/// a person or a company block depending on the type of customer.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct Condition {
+    /// The name of an earlier column whose field chooses the alternative.
+    column: String,
+    /// The values of the field, with surrounding whitespace removed, for which the alternative
+    /// is in use.
+    values: Vec<String>,
+}
+
+impl Condition {
+    /// Create a new [`Condition`] which is met when the field of the column is any of the values.
+    pub fn new(column: String, values: Vec<String>) -> Self {
+        Self { column, values }
+    }
+
+    /// Get the name of the column whose field chooses the alternative.
+    pub fn column(&self) -> &str {
+        &self.column
+    }
+
+    /// Get the values of the field for which the alternative is in use.
+    pub fn values(&self) -> &Vec<String> {
+        &self.values
+    }
+}
+
 /// Unified trait for all types of schema columns.
 pub trait Column {}
 pub type ColumnRef = Box<dyn Column>;
@@ -54,7 +140,7 @@ pub struct FixedColumn {
     name: String,
     /// The starting offset index for the column (in runes).
     offset: usize,
-    /// The total length of the column (in runes).
+    /// The length of the column, or of each of its elements if it repeats (in runes).
     length: usize,
     /// The datatype of the column.
     dtype: DataType,
@@ -66,6 +152,36 @@ pub struct FixedColumn {
     pad_symbol: Symbol,
     /// Whether or not the column can contain null values.
     is_nullable: bool,
+    /// The value to use when a field is empty or could not be parsed (default is `None`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    default: Option<String>,
+    /// The number of trailing digits of a float or decimal column which are decimals, for
+    /// fields that are written without a decimal point (default is `None`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    implied_decimals: Option<usize>,
+    /// How the sign of the numbers of a numeric column is written, if not as a leading `-`
+    /// (default is `None`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    sign_format: Option<SignFormat>,
+    /// The format of the dates of a date column, e.g., `%Y%m%d` (default is `%Y-%m-%d`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    format: Option<String>,
+    /// The maximum number of digits of a decimal column (default is 38).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    precision: Option<usize>,
+    /// The number of digits of a decimal column which are decimals (default is 0).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    scale: Option<usize>,
+    /// The repeats of the column, if its field holds several elements (default is `None`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    occurs: Option<Occurs>,
+    /// The consecutive fields of a struct column, with offsets in the record (default is empty).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    fields: Vec<FixedColumn>,
+    /// The condition under which a struct column, which is one of several alternative layouts
+    /// of the same region, is in use (default is `None`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    condition: Option<Condition>,
 }
 
 impl FixedColumn {
@@ -87,9 +203,84 @@ impl FixedColumn {
             alignment,
             pad_symbol,
             is_nullable,
+            default: None,
+            implied_decimals: None,
+            sign_format: None,
+            format: None,
+            precision: None,
+            scale: None,
+            occurs: None,
+            fields: Vec::new(),
+            condition: None,
         }
     }
 
+    /// Set the datatype of the column.
+    pub fn with_dtype(mut self, dtype: DataType) -> Self {
+        self.dtype = dtype;
+        self
+    }
+
+    /// Set whether or not the column can contain null values.
+    pub fn with_nullable(mut self, is_nullable: bool) -> Self {
+        self.is_nullable = is_nullable;
+        self
+    }
+
+    /// Set the value to use when a field is empty or could not be parsed.
+    pub fn with_default(mut self, default: String) -> Self {
+        self.default = Some(default);
+        self
+    }
+
+    /// Set the number of trailing digits which are decimals, for float columns whose fields
+    /// are written without a decimal point, e.g., `0012345` with two implied decimals is `123.45`.
+    pub fn with_implied_decimals(mut self, implied_decimals: usize) -> Self {
+        self.implied_decimals = Some(implied_decimals);
+        self
+    }
+
+    /// Set how the sign of the numbers of a numeric column is written, e.g., overpunched on the
+    /// last digit as in COBOL display fields without a separate sign, see [`SignFormat`].
+    pub fn with_sign_format(mut self, sign_format: SignFormat) -> Self {
+        self.sign_format = Some(sign_format);
+        self
+    }
+
+    /// Set the format of the dates of a date column, see [`chrono::format::strftime`].
+    pub fn with_format(mut self, format: String) -> Self {
+        self.format = Some(format);
+        self
+    }
+
+    /// Set the maximum number of digits of a decimal column, and how many of them are decimals.
+    pub fn with_precision_and_scale(mut self, precision: usize, scale: usize) -> Self {
+        self.precision = Some(precision);
+        self.scale = Some(scale);
+        self
+    }
+
+    /// Set the repeats of the column, making it a list column where each element has the
+    /// length and datatype of the column.
+    pub fn with_occurs(mut self, occurs: Occurs) -> Self {
+        self.occurs = Some(occurs);
+        self
+    }
+
+    /// Set the consecutive fields of a struct column, which have to cover the entire column
+    /// (or a single element of it if it repeats) starting at the offset of the column.
+    pub fn with_fields(mut self, fields: Vec<FixedColumn>) -> Self {
+        self.fields = fields;
+        self
+    }
+
+    /// Set the condition under which the column is in use, making it one of several
+    /// alternative layouts of the same region, which is null in all rows not meeting it.
+    pub fn with_condition(mut self, condition: Condition) -> Self {
+        self.condition = Some(condition);
+        self
+    }
+
     /// Get the name of the column.
     pub fn name(&self) -> &String {
         &self.name
@@ -100,11 +291,32 @@ impl FixedColumn {
         self.offset
     }
 
-    /// Get the total length of the column (in runes).
+    /// Get the total length of the column, including all of its elements (in runes).
     pub fn length(&self) -> usize {
+        self.length * self.occurs.as_ref().map_or(1, |o| o.count)
+    }
+
+    /// Get the length of a single element of the column, which is the same as the total
+    /// length of the column unless it repeats (in runes).
+    pub fn element_length(&self) -> usize {
         self.length
     }
 
+    /// Get the repeats of the column, if any.
+    pub fn occurs(&self) -> Option<&Occurs> {
+        self.occurs.as_ref()
+    }
+
+    /// Get the fields of the column, which is empty unless it is a struct column.
+    pub fn fields(&self) -> &Vec<FixedColumn> {
+        &self.fields
+    }
+
+    /// Get the condition under which the column is in use, if it is an alternative layout.
+    pub fn condition(&self) -> Option<&Condition> {
+        self.condition.as_ref()
+    }
+
     /// Get the datatype of the column (as a [`DataType`] variant).
     pub fn dtype(&self) -> DataType {
         self.dtype
@@ -130,8 +342,401 @@ impl FixedColumn {
         self.is_nullable
     }
 
-    /// Get the datatype of the column as a [`ArrowDataType`] variant.
+    /// Get the value to use when a field is empty or could not be parsed, if any.
+    ///
+    /// # Note
+    /// A default value takes priority over [`FixedColumn::is_nullable`], meaning that a NULL
+    /// value will never be appended to the column builder if the column has a default value.
+    pub fn default(&self) -> Option<&str> {
+        self.default.as_deref()
+    }
+
+    /// Get the number of implied decimals of the column, if any.
+    pub fn implied_decimals(&self) -> Option<usize> {
+        self.implied_decimals
+    }
+
+    /// Get how the sign of the numbers of the column is written, if not as a leading `-`.
+    pub fn sign_format(&self) -> Option<SignFormat> {
+        self.sign_format
+    }
+
+    /// Get the format of the dates of the column, if any.
+    pub fn format(&self) -> Option<&str> {
+        self.format.as_deref()
+    }
+
+    /// Get the maximum number of digits of the column, if any.
+    pub fn precision(&self) -> Option<usize> {
+        self.precision
+    }
+
+    /// Get the number of digits of the column which are decimals, if any.
+    pub fn scale(&self) -> Option<usize> {
+        self.scale
+    }
+
+    /// Get the format of the dates of the column, or the default format if it has none.
+    pub fn date_format(&self) -> &str {
+        self.format.as_deref().unwrap_or(DEFAULT_DATE_FORMAT)
+    }
+
+    /// Get the precision and scale of the column, or their defaults if it has none.
+    pub fn precision_and_scale(&self) -> (usize, usize) {
+        (
+            self.precision.unwrap_or(MAX_DECIMAL128_PRECISION),
+            self.scale.unwrap_or(0),
+        )
+    }
+
+    /// Create a new [`DecimalParser`] for the column, with its implied decimals if any.
+    fn decimal_parser(&self) -> DecimalParser {
+        let (precision, scale) = self.precision_and_scale();
+        DecimalParser::new(self.alignment, self.pad_symbol, precision, scale)
+            .with_implied_decimals(self.implied_decimals.unwrap_or(0))
+            .with_sign_format(self.sign_format)
+    }
+
+    /// Create a new [`FloatParser`] for the column, with its implied decimals if any.
+    fn float_parser(&self) -> FloatParser {
+        FloatParser::new(self.alignment, self.pad_symbol)
+            .with_implied_decimals(self.implied_decimals.unwrap_or(0))
+            .with_sign_format(self.sign_format)
+    }
+
+    /// Create a new [`IntParser`] for the column, with its sign format if any.
+    pub(crate) fn int_parser(&self) -> IntParser {
+        IntParser::new().with_sign_format(self.sign_format)
+    }
+
+    /// Try and parse the default value of the column as the provided type `T`.
+    fn try_parse_default<T>(&self) -> Result<Option<T>>
+    where
+        T: FromStr,
+    {
+        self.try_parse_default_with(|d| d.parse::<T>().ok())
+    }
+
+    /// Try and parse the default value of the column with the provided function.
+    fn try_parse_default_with<T>(&self, parse: impl Fn(&str) -> Option<T>) -> Result<Option<T>> {
+        match &self.default {
+            Some(d) => match parse(d.trim()) {
+                Some(v) => Ok(Some(v)),
+                None => Err(Box::new(SetupError::new(
+                    format!(
+                        "Default value '{}' of column '{}' is not a valid '{:?}', exiting...",
+                        d, self.name, self.dtype,
+                    )
+                    .as_str(),
+                ))),
+            },
+            None => Ok(None),
+        }
+    }
+
+    /// Try and extract the field of the column from a single record, with the padding of the
+    /// field removed according to the alignment and pad symbol of the column. A record which
+    /// is shorter than expected produces a truncated, possibly empty, field.
+    ///
+    /// # Errors
+    /// If the field is not valid UTF-8.
+    pub fn try_extract_field<'a>(&self, record: &'a [u8]) -> Result<&'a str> {
+        let start: usize = count_bytes_in_runes(record, self.offset);
+        let end: usize = start + count_bytes_in_runes(&record[start..], self.length());
+
+        let field: &str = std::str::from_utf8(&record[start..end]).map_err(|_| {
+            Box::new(SetupError::new(&format!(
+                "Field '{}' is not valid UTF-8, exiting...",
+                self.name,
+            ))) as Box<dyn std::error::Error>
+        })?;
+
+        let pad: char = char::from(self.pad_symbol);
+        Ok(match self.alignment {
+            Alignment::Left => field.trim_end_matches(pad),
+            Alignment::Right => field.trim_start_matches(pad),
+            Alignment::Center => field.trim_matches(pad),
+        })
+    }
+
+    /// Check whether the value, without any padding, is a valid value of (an element of) the
+    /// column, i.e., whether it fits in the length of the column and is parsed as the datatype
+    /// of the column when it is converted.
+    pub fn is_valid_value(&self, value: &str) -> bool {
+        let bytes: &[u8] = value.as_bytes();
+        let n_runes: usize = value.chars().count();
+        if n_runes > self.element_length() {
+            return false;
+        }
+
+        match self.dtype {
+            DataType::Boolean => BooleanParser::new(self.alignment, self.pad_symbol)
+                .try_parse(bytes, n_runes)
+                .1
+                .is_some(),
+            DataType::Float16 => self
+                .float_parser()
+                .try_parse::<f16>(bytes, n_runes)
+                .1
+                .is_some(),
+            DataType::Float32 => self
+                .float_parser()
+                .try_parse::<f32>(bytes, n_runes)
+                .1
+                .is_some(),
+            DataType::Float64 => self
+                .float_parser()
+                .try_parse::<f64>(bytes, n_runes)
+                .1
+                .is_some(),
+            DataType::Int16 => self
+                .int_parser()
+                .try_parse::<i16>(bytes, n_runes)
+                .1
+                .is_some(),
+            DataType::Int32 => self
+                .int_parser()
+                .try_parse::<i32>(bytes, n_runes)
+                .1
+                .is_some(),
+            DataType::Int64 => self
+                .int_parser()
+                .try_parse::<i64>(bytes, n_runes)
+                .1
+                .is_some(),
+            DataType::Utf8 | DataType::LargeUtf8 => true,
+            DataType::Date32 => DateParser::new(
+                self.alignment,
+                self.pad_symbol,
+                self.date_format().to_string(),
+            )
+            .try_parse(bytes, n_runes)
+            .1
+            .is_some(),
+            DataType::Decimal128 => self.decimal_parser().try_parse(bytes, n_runes).1.is_some(),
+            DataType::Struct => false,
+        }
+    }
+
+    /// Try and validate that the values of the column are consistent with each other.
+    ///
+    /// # Errors
+    /// If the default value of the column can not be parsed as the datatype of the column, if
+    /// the column has implied decimals but is not a float column, if it repeats zero times, if
+    /// the fields of a struct column could not be validated, see [`FixedColumn::try_validate_fields`],
+    /// or if the column has a condition but is not a nullable struct column which does not repeat.
+    pub fn try_validate(&self) -> Result<()> {
+        self.try_validate_fields()?;
+
+        if let Some(condition) = &self.condition {
+            if self.dtype != DataType::Struct || !self.is_nullable || self.occurs.is_some() {
+                return Err(Box::new(SetupError::new(&format!(
+                    "Column '{}' has a condition but is not a nullable struct column which does not repeat, exiting...",
+                    self.name,
+                ))));
+            }
+            if condition.values.is_empty() {
+                return Err(Box::new(SetupError::new(&format!(
+                    "Column '{}' has a condition without any values, exiting...",
+                    self.name,
+                ))));
+            }
+        }
+
+        if self.occurs.as_ref().is_some_and(|o| o.count == 0) {
+            return Err(Box::new(SetupError::new(&format!(
+                "Column '{}' has to repeat at least once, exiting...",
+                self.name,
+            ))));
+        }
+
+        if self.implied_decimals.is_some()
+            && !matches!(
+                self.dtype,
+                DataType::Float16 | DataType::Float32 | DataType::Float64 | DataType::Decimal128
+            )
+        {
+            return Err(Box::new(SetupError::new(&format!(
+                "Column '{}' has implied decimals but is not a float or decimal column, exiting...",
+                self.name,
+            ))));
+        }
+
+        if self.sign_format.is_some()
+            && !matches!(
+                self.dtype,
+                DataType::Float16
+                    | DataType::Float32
+                    | DataType::Float64
+                    | DataType::Int16
+                    | DataType::Int32
+                    | DataType::Int64
+                    | DataType::Decimal128
+            )
+        {
+            return Err(Box::new(SetupError::new(&format!(
+                "Column '{}' has a sign format but is not a numeric column, exiting...",
+                self.name,
+            ))));
+        }
+
+        self.try_validate_date_and_decimal()?;
+
+        match self.dtype {
+            DataType::Boolean => self.try_parse_default::<bool>().map(|_| ()),
+            DataType::Float16 => self.try_parse_default::<f16>().map(|_| ()),
+            DataType::Float32 => self.try_parse_default::<f32>().map(|_| ()),
+            DataType::Float64 => self.try_parse_default::<f64>().map(|_| ()),
+            DataType::Int16 => self.try_parse_default::<i16>().map(|_| ()),
+            DataType::Int32 => self.try_parse_default::<i32>().map(|_| ()),
+            DataType::Int64 => self.try_parse_default::<i64>().map(|_| ()),
+            DataType::Utf8 | DataType::LargeUtf8 => Ok(()),
+            DataType::Date32 => self
+                .try_parse_default_with(|d| parse_date(d, self.date_format()))
+                .map(|_| ()),
+            DataType::Decimal128 => {
+                let (precision, scale) = self.precision_and_scale();
+                self.try_parse_default_with(|d| parse_decimal(d, precision, scale))
+                    .map(|_| ())
+            }
+            DataType::Struct if self.default.is_some() => Err(Box::new(SetupError::new(&format!(
+                "Struct column '{}' can not have a default value, exiting...",
+                self.name,
+            )))),
+            DataType::Struct => Ok(()),
+        }
+    }
+
+    /// Try and validate the format of a date column, and the precision and scale of a decimal
+    /// column.
+    ///
+    /// # Errors
+    /// If a column which is not a date column has a format, if the format is not a valid
+    /// [`chrono::format::strftime`] format, if a column which is not a decimal column has a
+    /// precision or scale, if the precision is not between 1 and 38, if the scale is larger
+    /// than the precision, or if there are more implied decimals than the scale.
+    fn try_validate_date_and_decimal(&self) -> Result<()> {
+        let invalid = |reason: &str| -> Result<()> {
+            Err(Box::new(SetupError::new(&format!(
+                "Column '{}' {}, exiting...",
+                self.name, reason,
+            ))))
+        };
+
+        if let Some(format) = &self.format {
+            if self.dtype != DataType::Date32 {
+                return invalid("has a format but is not a date column");
+            }
+            if StrftimeItems::new(format).any(|i| i == Item::Error) {
+                return invalid(&format!("has an invalid date format '{}'", format));
+            }
+        }
+
+        if self.dtype != DataType::Decimal128 {
+            return match self.precision.is_some() || self.scale.is_some() {
+                true => invalid("has a precision or scale but is not a decimal column"),
+                false => Ok(()),
+            };
+        }
+
+        let (precision, scale) = self.precision_and_scale();
+        if !(1..=MAX_DECIMAL128_PRECISION).contains(&precision) {
+            return invalid(&format!(
+                "has a precision of {} but it has to be between 1 and {}",
+                precision, MAX_DECIMAL128_PRECISION,
+            ));
+        }
+        if scale > precision {
+            return invalid(&format!(
+                "has a scale of {} which is larger than its precision of {}",
+                scale, precision,
+            ));
+        }
+        if self.implied_decimals.is_some_and(|n| n > scale) {
+            return invalid("has more implied decimals than its scale");
+        }
+
+        Ok(())
+    }
+
+    /// Try and validate that the fields of a struct column are valid, have unique names, and
+    /// cover the column (or a single element of it if it repeats) one after the other.
+    ///
+    /// # Errors
+    /// If a struct column does not have any fields, if a column which is not a struct has
+    /// fields, if any of the fields could not be validated or has the same name as another
+    /// field, if the fields do not cover the column, or if the number of elements of a field
+    /// depends on another column or a field has a condition, which are only supported for
+    /// columns which are not fields.
+    fn try_validate_fields(&self) -> Result<()> {
+        let invalid = |reason: &str| -> Result<()> {
+            Err(Box::new(SetupError::new(&format!(
+                "Column '{}' {}, exiting...",
+                self.name, reason,
+            ))))
+        };
+
+        match (self.dtype, self.fields.is_empty()) {
+            (DataType::Struct, true) => return invalid("is a struct but does not have any fields"),
+            (DataType::Struct, false) => (),
+            (_, true) => return Ok(()),
+            (_, false) => return invalid("has fields but is not a struct"),
+        }
+
+        let mut offset: usize = self.offset;
+        for (idx, field) in self.fields.iter().enumerate() {
+            field.try_validate()?;
+
+            if self.fields[..idx].iter().any(|f| f.name == field.name) {
+                return invalid(&format!("has several fields named '{}'", field.name));
+            }
+            if field.offset != offset {
+                return invalid(&format!(
+                    "has field '{}' at offset {} but it should be at offset {}",
+                    field.name, field.offset, offset,
+                ));
+            }
+            if field.occurs().is_some_and(|o| o.depending_on.is_some()) {
+                return invalid(&format!(
+                    "has field '{}' whose number of elements depends on another column",
+                    field.name,
+                ));
+            }
+            if field.condition.is_some() {
+                return invalid(&format!("has field '{}' with a condition", field.name));
+            }
+            offset += field.length();
+        }
+
+        if offset != self.offset + self.length {
+            return invalid(&format!(
+                "has fields of {} runes but a length of {} runes",
+                offset - self.offset,
+                self.length,
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Get the datatype of the column as a [`ArrowDataType`] variant. A repeating column is a
+    /// fixed-size list, or a list if the number of elements depends on another column.
     pub fn as_arrow_dtype(&self) -> ArrowDataType {
+        let element: ArrowDataType = self.as_arrow_element_dtype();
+        match &self.occurs {
+            Some(occurs) => {
+                let field = Arc::new(ArrowField::new(LIST_ITEM_NAME, element, self.is_nullable));
+                match occurs.depending_on {
+                    Some(_) => ArrowDataType::List(field),
+                    None => ArrowDataType::FixedSizeList(field, occurs.count as i32),
+                }
+            }
+            None => element,
+        }
+    }
+
+    /// Get the datatype of the column, or of its elements if it repeats, as a [`ArrowDataType`].
+    fn as_arrow_element_dtype(&self) -> ArrowDataType {
         match self.dtype {
             DataType::Boolean => ArrowDataType::Boolean,
             DataType::Float16 => ArrowDataType::Float16,
@@ -142,6 +747,17 @@ impl FixedColumn {
             DataType::Int64 => ArrowDataType::Int64,
             DataType::Utf8 => ArrowDataType::Utf8,
             DataType::LargeUtf8 => ArrowDataType::LargeUtf8,
+            DataType::Date32 => ArrowDataType::Date32,
+            DataType::Decimal128 => {
+                let (precision, scale) = self.precision_and_scale();
+                ArrowDataType::Decimal128(precision as u8, scale as i8)
+            }
+            DataType::Struct => ArrowDataType::Struct(
+                self.fields
+                    .iter()
+                    .map(|f| ArrowField::new(f.name(), f.as_arrow_dtype(), f.is_nullable()))
+                    .collect::<ArrowFields>(),
+            ),
         }
     }
 
@@ -150,7 +766,19 @@ impl FixedColumn {
     /// # Note
     /// Currently this method will map [`DataType::Float16`] to the [`DataType::Float32`] variant. This
     /// is because the [`deltalake`] crate does not yet define a Float16 variant in its [`DeltaDataType`].
+    /// A repeating column is an array of its elements.
     pub fn as_delta_dtype(&self) -> DeltaDataType {
+        let element: DeltaDataType = self.as_delta_element_dtype();
+        match &self.occurs {
+            Some(_) => {
+                DeltaDataType::Array(Box::new(DeltaArrayType::new(element, self.is_nullable)))
+            }
+            None => element,
+        }
+    }
+
+    /// Get the datatype of the column, or of its elements if it repeats, as a [`DeltaDataType`].
+    fn as_delta_element_dtype(&self) -> DeltaDataType {
         match self.dtype {
             DataType::Boolean => DeltaDataType::BOOLEAN,
             DataType::Float16 => {
@@ -164,71 +792,176 @@ impl FixedColumn {
             DataType::Int64 => DeltaDataType::LONG,
             DataType::Utf8 => DeltaDataType::STRING,
             DataType::LargeUtf8 => DeltaDataType::STRING,
+            DataType::Date32 => DeltaDataType::DATE,
+            DataType::Decimal128 => {
+                let (precision, scale) = self.precision_and_scale();
+                DeltaDataType::Primitive(DeltaPrimitiveType::Decimal(precision as u8, scale as i8))
+            }
+            DataType::Struct => DeltaDataType::Struct(Box::new(DeltaStructType::new(
+                self.fields
+                    .iter()
+                    .map(|f| DeltaStructField::new(f.name(), f.as_delta_dtype(), f.is_nullable()))
+                    .collect::<Vec<DeltaStructField>>(),
+            ))),
         }
     }
 
     /// Create a new [`ColumnBuilderRef`] based on the datatype of the column.
     ///
+    /// # Note
+    /// A default value which can not be parsed as the datatype of the column is ignored here,
+    /// use [`FixedColumn::try_validate`] to detect such a default value during setup.
+    ///
+    /// A list column whose number of elements depends on another column can not read them by
+    /// itself, i.e., every row is empty or null, the builders of such columns are created by
+    /// [`FixedSchema::into_builder`](crate::schema::FixedSchema::into_builder) instead.
+    ///
     /// # Performance
     /// This method will clone the String which contains the name of the column.
     /// You should only use this during setup of the program, and not during any
     /// performance critical parts of the program.
     pub fn as_column_builder(&self) -> ColumnBuilderRef {
+        self.as_list_column_builder(Arc::new(Mutex::new(Vec::new())), IntParser::new())
+    }
+
+    /// Create a new [`ColumnBuilderRef`] based on the datatype of the column, where the number
+    /// of elements in use of a list column are parsed by the provided parser from the shared
+    /// field of the column they depend on, see [`ListColumnBuilder`].
+    pub fn as_list_column_builder(
+        &self,
+        count: SharedField,
+        count_parser: IntParser,
+    ) -> ColumnBuilderRef {
+        match &self.occurs {
+            Some(occurs) => {
+                let elements: ColumnBuilderRef =
+                    self.as_element_builder(LIST_ITEM_NAME.to_string());
+                match occurs.depending_on {
+                    Some(_) => Box::new(ListColumnBuilder::new(
+                        self.name.clone(),
+                        occurs.count,
+                        self.element_length(),
+                        self.is_nullable,
+                        elements,
+                        count,
+                        count_parser,
+                    )),
+                    None => Box::new(FixedSizeListColumnBuilder::new(
+                        self.name.clone(),
+                        occurs.count,
+                        self.is_nullable,
+                        elements,
+                    )),
+                }
+            }
+            None => self.as_element_builder(self.name.clone()),
+        }
+    }
+
+    /// Create a new [`ColumnBuilderRef`] which builds a single element of the column.
+    fn as_element_builder(&self, name: String) -> ColumnBuilderRef {
         match self.dtype {
             DataType::Boolean => Box::new(BooleanColumnBuilder::new(
-                self.name.clone(),
+                name,
                 self.length,
                 self.is_nullable,
+                self.try_parse_default::<bool>().ok().flatten(),
                 BooleanParser::new(self.alignment, self.pad_symbol),
             )),
             DataType::Float16 => Box::new(Float16ColumnBuilder::new(
-                self.name.clone(),
+                name,
                 self.length,
                 self.is_nullable,
-                FloatParser::new(self.alignment, self.pad_symbol),
+                self.try_parse_default::<f16>().ok().flatten(),
+                self.float_parser(),
             )),
             DataType::Float32 => Box::new(Float32ColumnBuilder::new(
-                self.name.clone(),
+                name,
                 self.length,
                 self.is_nullable,
-                FloatParser::new(self.alignment, self.pad_symbol),
+                self.try_parse_default::<f32>().ok().flatten(),
+                self.float_parser(),
             )),
             DataType::Float64 => Box::new(Float64ColumnBuilder::new(
-                self.name.clone(),
+                name,
                 self.length,
                 self.is_nullable,
-                FloatParser::new(self.alignment, self.pad_symbol),
+                self.try_parse_default::<f64>().ok().flatten(),
+                self.float_parser(),
             )),
             DataType::Int16 => Box::new(Int16ColumnBuilder::new(
-                self.name.clone(),
+                name,
                 self.length,
                 self.is_nullable,
-                IntParser::new(),
+                self.try_parse_default::<i16>().ok().flatten(),
+                self.int_parser(),
             )),
             DataType::Int32 => Box::new(Int32ColumnBuilder::new(
-                self.name.clone(),
+                name,
                 self.length,
                 self.is_nullable,
-                IntParser::new(),
+                self.try_parse_default::<i32>().ok().flatten(),
+                self.int_parser(),
             )),
             DataType::Int64 => Box::new(Int64ColumnBuilder::new(
-                self.name.clone(),
+                name,
                 self.length,
                 self.is_nullable,
-                IntParser::new(),
+                self.try_parse_default::<i64>().ok().flatten(),
+                self.int_parser(),
             )),
             DataType::Utf8 => Box::new(Utf8ColumnBuilder::new(
-                self.name.clone(),
+                name,
                 self.length,
                 self.is_nullable,
+                self.default.clone(),
                 Utf8Parser::new(self.alignment, self.pad_symbol),
             )),
             DataType::LargeUtf8 => Box::new(Utf8ColumnBuilder::new(
-                self.name.clone(),
+                name,
                 self.length,
                 self.is_nullable,
+                self.default.clone(),
                 Utf8Parser::new(self.alignment, self.pad_symbol),
             )),
+            DataType::Date32 => Box::new(Date32ColumnBuilder::new(
+                name,
+                self.length,
+                self.is_nullable,
+                self.try_parse_default_with(|d| parse_date(d, self.date_format()))
+                    .ok()
+                    .flatten(),
+                DateParser::new(
+                    self.alignment,
+                    self.pad_symbol,
+                    self.date_format().to_string(),
+                ),
+            )),
+            DataType::Decimal128 => {
+                let (precision, scale) = self.precision_and_scale();
+                Box::new(Decimal128ColumnBuilder::new(
+                    name,
+                    self.length,
+                    self.is_nullable,
+                    self.try_parse_default_with(|d| parse_decimal(d, precision, scale))
+                        .ok()
+                        .flatten(),
+                    precision as u8,
+                    scale as i8,
+                    self.decimal_parser(),
+                ))
+            }
+            DataType::Struct => Box::new(StructColumnBuilder::new(
+                name,
+                self.fields
+                    .iter()
+                    .map(|f| f.as_column_builder())
+                    .collect::<Vec<ColumnBuilderRef>>(),
+                self.fields
+                    .iter()
+                    .map(|f| f.is_nullable())
+                    .collect::<Vec<bool>>(),
+            )),
         }
     }
 }
@@ -239,6 +972,7 @@ impl Column for FixedColumn {}
 mod tests_column {
     use super::*;
 
+    use arrow::array::{Array, Date32Array, Decimal128Array, Float64Array, Int32Array};
     use std::fs;
     use std::path::PathBuf;
 
@@ -271,6 +1005,216 @@ mod tests_column {
         assert_eq!(a.as_delta_dtype(), b.as_delta_dtype());
     }
 
+    #[test]
+    fn test_column_builder_appends_default() {
+        let column: FixedColumn = FixedColumn::new(
+            String::from("quantity"),
+            0,
+            4,
+            DataType::Int32,
+            Alignment::Right,
+            Symbol::Whitespace,
+            false,
+        )
+        .with_default(String::from("0"));
+
+        assert!(column.try_validate().is_ok());
+
+        let mut builder: ColumnBuilderRef = column.as_column_builder();
+        builder.try_build_column("  12".as_bytes()).unwrap();
+        builder.try_build_column("    ".as_bytes()).unwrap();
+        builder.try_build_column("  xd".as_bytes()).unwrap();
+
+        let (_, array) = builder.finish();
+        let array: &Int32Array = array.as_any().downcast_ref::<Int32Array>().unwrap();
+
+        assert_eq!(0, array.null_count());
+        assert_eq!(&[12, 0, 0], array.values().as_ref());
+    }
+
+    #[test]
+    fn test_column_builder_implied_decimals() {
+        let column: FixedColumn = FixedColumn::new(
+            String::from("amount"),
+            0,
+            7,
+            DataType::Float64,
+            Alignment::Right,
+            Symbol::Whitespace,
+            true,
+        )
+        .with_implied_decimals(2);
+
+        assert!(column.try_validate().is_ok());
+
+        let mut builder: ColumnBuilderRef = column.as_column_builder();
+        builder.try_build_column("0012345".as_bytes()).unwrap();
+        builder.try_build_column("     -5".as_bytes()).unwrap();
+        builder.try_build_column("   12.3".as_bytes()).unwrap();
+
+        let (_, array) = builder.finish();
+        let array: &Float64Array = array.as_any().downcast_ref::<Float64Array>().unwrap();
+
+        assert_eq!(123.45, array.value(0));
+        assert_eq!(-0.05, array.value(1));
+        assert!(array.is_null(2));
+
+        let column: FixedColumn = FixedColumn::new(
+            String::from("quantity"),
+            0,
+            4,
+            DataType::Int32,
+            Alignment::Right,
+            Symbol::Whitespace,
+            false,
+        )
+        .with_implied_decimals(2);
+
+        assert!(column.try_validate().is_err());
+    }
+
+    #[test]
+    fn test_column_builder_sign_format() {
+        let column: FixedColumn = FixedColumn::new(
+            String::from("balance"),
+            0,
+            9,
+            DataType::Float64,
+            Alignment::Right,
+            Symbol::Whitespace,
+            true,
+        )
+        .with_implied_decimals(2)
+        .with_sign_format(SignFormat::TrailingOverpunch);
+
+        assert!(column.try_validate().is_ok());
+
+        let mut builder: ColumnBuilderRef = column.as_column_builder();
+        builder.try_build_column("00012345N".as_bytes()).unwrap();
+        builder.try_build_column("00012345E".as_bytes()).unwrap();
+        builder.try_build_column("      -12".as_bytes()).unwrap();
+
+        let (_, array) = builder.finish();
+        let array: &Float64Array = array.as_any().downcast_ref::<Float64Array>().unwrap();
+
+        assert_eq!(-1_234.55, array.value(0));
+        assert_eq!(1_234.55, array.value(1));
+        assert_eq!(-0.12, array.value(2));
+
+        let column: FixedColumn = FixedColumn::new(
+            String::from("name"),
+            0,
+            4,
+            DataType::Utf8,
+            Alignment::Left,
+            Symbol::Whitespace,
+            false,
+        )
+        .with_sign_format(SignFormat::TrailingSeparate);
+
+        assert!(column.try_validate().is_err());
+    }
+
+    #[test]
+    fn test_column_builder_dates_and_decimals() {
+        let column: FixedColumn = FixedColumn::new(
+            String::from("booked"),
+            0,
+            8,
+            DataType::Date32,
+            Alignment::Left,
+            Symbol::Whitespace,
+            true,
+        )
+        .with_format(String::from("%Y%m%d"));
+
+        assert!(column.try_validate().is_ok());
+
+        let mut builder: ColumnBuilderRef = column.as_column_builder();
+        builder.try_build_column("19700102".as_bytes()).unwrap();
+        builder.try_build_column("20240230".as_bytes()).unwrap();
+
+        let (_, array) = builder.finish();
+        let array: &Date32Array = array.as_any().downcast_ref::<Date32Array>().unwrap();
+
+        assert_eq!(1, array.value(0));
+        assert!(array.is_null(1));
+
+        let column: FixedColumn = FixedColumn::new(
+            String::from("amount"),
+            0,
+            7,
+            DataType::Decimal128,
+            Alignment::Right,
+            Symbol::Whitespace,
+            true,
+        )
+        .with_precision_and_scale(9, 2)
+        .with_implied_decimals(2);
+
+        assert!(column.try_validate().is_ok());
+
+        let mut builder: ColumnBuilderRef = column.as_column_builder();
+        builder.try_build_column("0012345".as_bytes()).unwrap();
+        builder.try_build_column("   -5.1".as_bytes()).unwrap();
+
+        let (_, array) = builder.finish();
+        let array: &Decimal128Array = array.as_any().downcast_ref::<Decimal128Array>().unwrap();
+
+        assert_eq!(12345, array.value(0));
+        assert!(array.is_null(1));
+        assert_eq!((9, 2), (array.precision(), array.scale()));
+
+        assert!(column
+            .with_precision_and_scale(2, 3)
+            .try_validate()
+            .is_err());
+    }
+
+    #[test]
+    fn test_invalid_default_is_not_valid() {
+        let column: FixedColumn = FixedColumn::new(
+            String::from("code"),
+            0,
+            3,
+            DataType::Int16,
+            Alignment::Right,
+            Symbol::Whitespace,
+            true,
+        )
+        .with_default(String::from("UNKNOWN"));
+
+        assert!(column.try_validate().is_err());
+    }
+
+    #[test]
+    fn test_is_valid_value() {
+        let column = |dtype: DataType| {
+            FixedColumn::new(
+                String::from("value"),
+                0,
+                5,
+                dtype,
+                Alignment::Right,
+                Symbol::Whitespace,
+                true,
+            )
+        };
+
+        assert!(column(DataType::Int16).is_valid_value("-2818"));
+        assert!(!column(DataType::Int16).is_valid_value("77093"));
+        assert!(!column(DataType::Int16).is_valid_value("-28186"));
+        assert!(!column(DataType::Int32).is_valid_value("abc"));
+        assert!(column(DataType::Boolean).is_valid_value("false"));
+        assert!(!column(DataType::Boolean).is_valid_value("yes"));
+        assert!(column(DataType::Float32)
+            .with_implied_decimals(2)
+            .is_valid_value("125"));
+        assert!(!column(DataType::Date32).is_valid_value("2024-01-01"));
+        assert!(column(DataType::Utf8).is_valid_value("abc"));
+        assert!(!column(DataType::Utf8).is_valid_value("abcdef"));
+    }
+
     #[test]
     #[should_panic]
     fn test_deserialize_invalid_column_from_file() {
@@ -0,0 +1,1223 @@
+//
+// MIT License
+//
+// Copyright (c) 2026 Firelink Data
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//
+// File created: 2026-10-18
+// Last updated: 2026-10-18
+//
+
+//! Import of COBOL copybooks as [`FixedSchema`]s.
+//!
+//! Only fields with display usage can be converted, since the fixed-length files are read as
+//! newline delimited UTF-8 text. Binary usages (`COMP`, `COMP-3`, `BINARY`, ...) are parsed, so
+//! that offsets are correct, but produce an error when the copybook is turned into a schema.
+//! Packed decimals and binary integers can contain any byte, including line breaks, so reading
+//! them requires support for binary record files, which is left as a follow-up. Signed fields
+//! with display usage are supported with a leading or trailing separate sign, and with an
+//! overpunched sign on their first or last digit, see [`SignFormat`].
+//!
+//! `FILLER` fields become string columns named `filler_<n>`, since the columns of a schema
+//! have to cover the entire record. An item with an `OCCURS` becomes a list column. Since the
+//! records are of fixed length, an `OCCURS ... DEPENDING ON` always reserves space for its
+//! maximum number of elements. Groups become struct columns, keeping the hierarchy of the
+//! record.
+//!
+//! A copybook does not say which definition of a region redefined with `REDEFINES` a record
+//! uses, so the condition of each definition has to be provided with
+//! [`Copybook::with_condition`]. A region directly in the record whose definitions all have
+//! conditions becomes alternative struct columns, see [`Condition`], where an elementary or
+//! repeating definition is wrapped in a struct of the same name and a shorter definition is
+//! padded with a filler. The first definition of any other region is kept, since the
+//! redefinitions are then only other views of the same bytes.
+
+use evolution_common::datatype::{DataType, SignFormat};
+use evolution_common::error::{Result, SetupError};
+use log::warn;
+use padder::{Alignment, Symbol};
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+use crate::column::{Condition, FixedColumn, Occurs};
+use crate::schema::FixedSchema;
+
+/// How the value of a copybook item is stored.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Usage {
+    Display,
+    Binary,
+    PackedDecimal,
+    Float32,
+    Float64,
+}
+
+/// The category of a picture string.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum PictureClass {
+    Alphanumeric,
+    Numeric,
+    Edited,
+}
+
+/// A parsed `PIC` clause.
+#[derive(Clone, Debug, PartialEq)]
+struct Picture {
+    class: PictureClass,
+    /// The number of characters the picture occupies with display usage, excluding the sign.
+    length: usize,
+    /// The number of digit positions.
+    digits: usize,
+    /// The number of digit positions after the (implied or explicit) decimal point.
+    decimals: usize,
+    is_signed: bool,
+    has_decimal_point: bool,
+}
+
+impl Picture {
+    /// Try and parse a picture string, e.g., `X(30)` or `S9(7)V99`.
+    fn try_parse(text: &str) -> Result<Self> {
+        let mut class: PictureClass = PictureClass::Numeric;
+        let mut length: usize = 0;
+        let mut digits: usize = 0;
+        let mut decimals: usize = 0;
+        let mut is_signed: bool = false;
+        let mut has_decimal_point: bool = false;
+        let mut after_point: bool = false;
+
+        let invalid = || {
+            Box::new(SetupError::new(&format!(
+                "Invalid picture string '{}', exiting...",
+                text,
+            ))) as Box<dyn std::error::Error>
+        };
+
+        let upper: String = text.to_ascii_uppercase();
+        let chars: Vec<char> = upper.chars().collect();
+        let mut idx: usize = 0;
+
+        while idx < chars.len() {
+            let symbol: char = chars[idx];
+            idx += 1;
+
+            // A symbol may be followed by a repetition count, e.g., `X(30)`.
+            let mut n_repeats: usize = 1;
+            if chars.get(idx) == Some(&'(') {
+                let close: usize = chars[idx..]
+                    .iter()
+                    .position(|c| *c == ')')
+                    .ok_or_else(invalid)?;
+                n_repeats = chars[idx + 1..idx + close]
+                    .iter()
+                    .collect::<String>()
+                    .parse::<usize>()
+                    .map_err(|_| invalid())?;
+                idx += close + 1;
+            }
+
+            match symbol {
+                'X' | 'A' => {
+                    class = PictureClass::Alphanumeric;
+                    length += n_repeats;
+                }
+                '9' | 'Z' => {
+                    digits += n_repeats;
+                    length += n_repeats;
+                    if after_point {
+                        decimals += n_repeats;
+                    }
+                }
+                'S' => is_signed = true,
+                'V' => after_point = true,
+                'P' => (),
+                '.' => {
+                    has_decimal_point = true;
+                    after_point = true;
+                    length += n_repeats;
+                }
+                ',' | '$' | '*' | 'B' | '/' | '0' | '+' | '-' => {
+                    if class != PictureClass::Alphanumeric {
+                        class = PictureClass::Edited;
+                    }
+                    length += n_repeats;
+                }
+                'C' | 'D' if matches!(chars.get(idx), Some('R') | Some('B')) => {
+                    if class != PictureClass::Alphanumeric {
+                        class = PictureClass::Edited;
+                    }
+                    length += 2;
+                    idx += 1;
+                }
+                _ => return Err(invalid()),
+            }
+        }
+
+        if length == 0 {
+            return Err(invalid());
+        }
+
+        Ok(Self {
+            class,
+            length,
+            digits,
+            decimals,
+            is_signed,
+            has_decimal_point,
+        })
+    }
+
+    /// Get the datatype which values of the picture are parsed as.
+    fn dtype(&self) -> DataType {
+        match self.class {
+            PictureClass::Alphanumeric | PictureClass::Edited => DataType::Utf8,
+            PictureClass::Numeric if self.decimals > 0 || self.has_decimal_point => {
+                DataType::Float64
+            }
+            PictureClass::Numeric => match self.digits {
+                0..=4 => DataType::Int16,
+                5..=9 => DataType::Int32,
+                10..=18 => DataType::Int64,
+                _ => DataType::Float64,
+            },
+        }
+    }
+}
+
+/// A single data description entry of a copybook, with its subordinate entries.
+#[derive(Clone, Debug)]
+struct CopybookItem {
+    level: u8,
+    /// The name of the item, `None` for `FILLER` items.
+    name: Option<String>,
+    picture: Option<Picture>,
+    usage: Usage,
+    sign_separate: bool,
+    /// Whether the sign of a signed item is leading rather than trailing, which is the default.
+    sign_leading: bool,
+    occurs: Option<usize>,
+    /// The name of the item holding the number of occurrences in use, if any.
+    depending_on: Option<String>,
+    redefines: Option<String>,
+    children: Vec<CopybookItem>,
+}
+
+impl CopybookItem {
+    /// Try and parse a single data description entry from its tokens, returning `None` for
+    /// entries which do not describe storage, i.e., condition names and renames.
+    fn try_parse(tokens: &[String]) -> Result<Option<Self>> {
+        let level: u8 = tokens[0].parse::<u8>().map_err(|_| {
+            Box::new(SetupError::new(&format!(
+                "Expected a level number but found '{}', exiting...",
+                tokens[0],
+            ))) as Box<dyn std::error::Error>
+        })?;
+
+        match level {
+            88 => return Ok(None),
+            66 => {
+                warn!("Ignoring level 66 entry '{}'.", tokens.join(" "));
+                return Ok(None);
+            }
+            1..=49 | 77 => (),
+            _ => {
+                return Err(Box::new(SetupError::new(&format!(
+                    "Invalid level number {}, exiting...",
+                    level,
+                ))))
+            }
+        }
+
+        let mut idx: usize = 1;
+        let name: Option<String> = match tokens.get(idx) {
+            Some(t) if !is_clause_keyword(t) => {
+                idx += 1;
+                if t.eq_ignore_ascii_case("FILLER") {
+                    None
+                } else {
+                    Some(t.to_string())
+                }
+            }
+            _ => None,
+        };
+
+        let mut item: CopybookItem = CopybookItem {
+            level,
+            name,
+            picture: None,
+            usage: Usage::Display,
+            sign_separate: false,
+            sign_leading: false,
+            occurs: None,
+            depending_on: None,
+            redefines: None,
+            children: Vec::new(),
+        };
+
+        let next_value = |idx: &mut usize, clause: &str| -> Result<String> {
+            if tokens
+                .get(*idx)
+                .is_some_and(|t| t.eq_ignore_ascii_case("IS"))
+            {
+                *idx += 1;
+            }
+            let value: Option<&String> = tokens.get(*idx);
+            *idx += 1;
+            value.cloned().ok_or_else(|| {
+                Box::new(SetupError::new(&format!(
+                    "Missing value of clause '{}' in entry '{}', exiting...",
+                    clause,
+                    tokens.join(" "),
+                ))) as Box<dyn std::error::Error>
+            })
+        };
+
+        while idx < tokens.len() {
+            let token: String = tokens[idx].to_ascii_uppercase();
+            idx += 1;
+
+            match token.as_str() {
+                "PIC" | "PICTURE" => {
+                    item.picture = Some(Picture::try_parse(&next_value(&mut idx, &token)?)?);
+                }
+                "USAGE" => {
+                    let usage: String = next_value(&mut idx, &token)?;
+                    item.usage = try_parse_usage(&usage)?;
+                }
+                "OCCURS" => {
+                    let mut n_times: String = next_value(&mut idx, &token)?;
+                    if tokens
+                        .get(idx)
+                        .is_some_and(|t| t.eq_ignore_ascii_case("TO"))
+                    {
+                        idx += 1;
+                        n_times = next_value(&mut idx, "TO")?;
+                    }
+                    item.occurs = Some(n_times.parse::<usize>().map_err(|_| {
+                        Box::new(SetupError::new(&format!(
+                            "Invalid number of occurrences '{}', exiting...",
+                            n_times,
+                        ))) as Box<dyn std::error::Error>
+                    })?);
+                }
+                "DEPENDING" => {
+                    if tokens
+                        .get(idx)
+                        .is_some_and(|t| t.eq_ignore_ascii_case("ON"))
+                    {
+                        idx += 1;
+                    }
+                    item.depending_on = Some(next_value(&mut idx, &token)?);
+                }
+                "REDEFINES" => item.redefines = Some(next_value(&mut idx, &token)?),
+                "SEPARATE" => item.sign_separate = true,
+                "LEADING" => item.sign_leading = true,
+                "TRAILING" => item.sign_leading = false,
+                _ => {
+                    if let Ok(usage) = try_parse_usage(&token) {
+                        item.usage = usage;
+                    }
+                }
+            }
+        }
+
+        Ok(Some(item))
+    }
+
+    /// Get the name of the item in snake case, e.g., `CUST-NAME` becomes `cust_name`.
+    fn column_name(&self) -> Option<String> {
+        self.name
+            .as_ref()
+            .map(|n| n.to_ascii_lowercase().replace('-', "_"))
+    }
+
+    /// Get the number of bytes of a single occurrence of the item.
+    fn element_size(&self) -> usize {
+        if !self.children.is_empty() {
+            return group_size(&self.children);
+        }
+
+        match (self.usage, &self.picture) {
+            (Usage::Float32, _) => 4,
+            (Usage::Float64, _) => 8,
+            (Usage::Display, Some(p)) => p.length + (p.is_signed && self.sign_separate) as usize,
+            (Usage::Binary, Some(p)) => match p.digits {
+                0..=4 => 2,
+                5..=9 => 4,
+                _ => 8,
+            },
+            (Usage::PackedDecimal, Some(p)) => p.digits / 2 + 1,
+            (_, None) => 0,
+        }
+    }
+
+    /// Get how the sign of a signed item is written, `None` for unsigned items and items with a
+    /// separate leading sign, which are parsed as they are.
+    fn sign_format(&self) -> Option<SignFormat> {
+        let is_signed: bool = self.picture.as_ref().is_some_and(|p| p.is_signed);
+        match (is_signed, self.sign_separate, self.sign_leading) {
+            (false, _, _) | (true, true, true) => None,
+            (true, true, false) => Some(SignFormat::TrailingSeparate),
+            (true, false, true) => Some(SignFormat::LeadingOverpunch),
+            (true, false, false) => Some(SignFormat::TrailingOverpunch),
+        }
+    }
+
+    /// Get the total number of bytes of the item, including all of its occurrences.
+    fn size(&self) -> usize {
+        self.element_size() * self.occurs.unwrap_or(1)
+    }
+}
+
+/// Get the number of bytes of a group of sibling items, where redefinitions share the
+/// storage of the items they redefine.
+fn group_size(items: &[CopybookItem]) -> usize {
+    let mut starts: HashMap<&str, usize> = HashMap::new();
+    let mut offset: usize = 0;
+
+    for item in items {
+        match item.redefines.as_deref().and_then(|r| starts.get(r)) {
+            Some(start) => offset = offset.max(start + item.size()),
+            None => {
+                if let Some(name) = &item.name {
+                    starts.insert(name, offset);
+                }
+                offset += item.size();
+            }
+        }
+    }
+
+    offset
+}
+
+fn try_parse_usage(text: &str) -> Result<Usage> {
+    match text.to_ascii_uppercase().as_str() {
+        "DISPLAY" => Ok(Usage::Display),
+        "COMP" | "COMPUTATIONAL" | "COMP-4" | "COMPUTATIONAL-4" | "COMP-5" | "COMPUTATIONAL-5"
+        | "BINARY" => Ok(Usage::Binary),
+        "COMP-3" | "COMPUTATIONAL-3" | "PACKED-DECIMAL" => Ok(Usage::PackedDecimal),
+        "COMP-1" | "COMPUTATIONAL-1" => Ok(Usage::Float32),
+        "COMP-2" | "COMPUTATIONAL-2" => Ok(Usage::Float64),
+        _ => Err(Box::new(SetupError::new(&format!(
+            "Unknown usage '{}', exiting...",
+            text,
+        )))),
+    }
+}
+
+fn is_clause_keyword(token: &str) -> bool {
+    matches!(
+        token.to_ascii_uppercase().as_str(),
+        "PIC" | "PICTURE" | "USAGE" | "OCCURS" | "REDEFINES" | "VALUE" | "VALUES" | "SIGN"
+    ) || try_parse_usage(token).is_ok()
+}
+
+/// A parsed COBOL copybook, which can be turned into a [`FixedSchema`].
+#[derive(Clone, Debug)]
+pub struct Copybook {
+    name: String,
+    items: Vec<CopybookItem>,
+    /// The conditions of the definitions of redefined regions, by upper case item name.
+    conditions: HashMap<String, Condition>,
+}
+
+impl Copybook {
+    /// Try and parse a copybook from its source text, naming the schema it produces `name`
+    /// unless the copybook describes a single record, in which case the record name is used.
+    ///
+    /// Both fixed format source, with sequence numbers in columns 1-6 and the indicator in
+    /// column 7, and free format source are accepted.
+    ///
+    /// # Errors
+    /// If any of the data description entries could not be parsed, or if an elementary item
+    /// has subordinate items.
+    pub fn try_parse(name: &str, text: &str) -> Result<Self> {
+        let mut roots: Vec<CopybookItem> = Vec::new();
+        let mut stack: Vec<CopybookItem> = Vec::new();
+
+        for sentence in split_sentences(&strip_source_format(text)) {
+            let tokens: Vec<String> = tokenize(&sentence);
+            if tokens.is_empty() {
+                continue;
+            }
+
+            let item: CopybookItem = match CopybookItem::try_parse(&tokens)? {
+                Some(item) => item,
+                None => continue,
+            };
+
+            close_items(&mut stack, &mut roots, item.level)?;
+            stack.push(item);
+        }
+        close_items(&mut stack, &mut roots, 0)?;
+
+        if roots.is_empty() {
+            return Err(Box::new(SetupError::new(
+                "The copybook does not contain any data description entries, exiting...",
+            )));
+        }
+
+        Ok(Self {
+            name: name.to_string(),
+            items: roots,
+            conditions: HashMap::new(),
+        })
+    }
+
+    /// Try and read and parse a copybook from a file, see [`Copybook::try_parse`]. The stem of
+    /// the file name is used as the default name of the schema.
+    ///
+    /// # Errors
+    /// If the file could not be read or the copybook could not be parsed.
+    pub fn from_path(path: PathBuf) -> Result<Self> {
+        let name: String = path
+            .file_stem()
+            .map(|s| s.to_string_lossy().to_string())
+            .unwrap_or_default();
+        Self::try_parse(&name, &fs::read_to_string(path)?)
+    }
+
+    /// Set the condition under which the definition named `item` of a redefined region is in
+    /// use, e.g., `PERSON` when `CUST-TYPE` is `P`. The column of the condition may be given
+    /// either as an item name or as a column name.
+    pub fn with_condition(mut self, item: &str, condition: Condition) -> Self {
+        let column: String = condition.column().to_ascii_lowercase().replace('-', "_");
+        self.conditions.insert(
+            item.to_ascii_uppercase(),
+            Condition::new(column, condition.values().clone()),
+        );
+        self
+    }
+
+    /// Get the names of the top level records in the copybook.
+    pub fn record_names(&self) -> Vec<String> {
+        self.items
+            .iter()
+            .filter(|i| !i.children.is_empty())
+            .filter_map(|i| i.name.clone())
+            .collect::<Vec<String>>()
+    }
+
+    /// Try and produce a [`FixedSchema`] from the copybook. If the copybook contains several
+    /// records, then the name of the record to use has to be provided.
+    ///
+    /// # Errors
+    /// If the record could not be selected, if any of the fields uses a binary usage, if two
+    /// fields would produce columns with the same name, if a condition is set for an item which
+    /// is not a definition of a redefined region directly in the record, if only some of the
+    /// definitions of a region have conditions, or if the conditions are not valid, see
+    /// [`FixedSchema::try_validate`].
+    pub fn try_into_schema(&self, record: Option<&str>) -> Result<FixedSchema> {
+        let records: Vec<&CopybookItem> = self
+            .items
+            .iter()
+            .filter(|i| i.level == 1 && !i.children.is_empty())
+            .collect::<Vec<&CopybookItem>>();
+
+        let (name, items): (String, &[CopybookItem]) = match record {
+            Some(record) => {
+                let item: &CopybookItem = records
+                    .iter()
+                    .find(|i| {
+                        i.name
+                            .as_deref()
+                            .is_some_and(|n| n.eq_ignore_ascii_case(record))
+                    })
+                    .ok_or_else(|| {
+                        Box::new(SetupError::new(&format!(
+                            "Record '{}' is not in the copybook, the records are {:?}, exiting...",
+                            record,
+                            self.record_names(),
+                        ))) as Box<dyn std::error::Error>
+                    })?;
+                (item.column_name().unwrap_or_default(), &item.children)
+            }
+            None if self.items.len() == 1 && !self.items[0].children.is_empty() => (
+                self.items[0].column_name().unwrap_or(self.name.clone()),
+                &self.items[0].children,
+            ),
+            None if records.is_empty() => (self.name.clone(), &self.items),
+            None => {
+                return Err(Box::new(SetupError::new(&format!(
+                    "The copybook contains the records {:?}, select one of them, exiting...",
+                    self.record_names(),
+                ))))
+            }
+        };
+
+        if let Some(item) = self
+            .conditions
+            .keys()
+            .find(|n| !is_redefined_region_definition(items, n))
+        {
+            return Err(Box::new(SetupError::new(&format!(
+                "Item '{}' has a condition but is not a definition of a redefined region directly in the record, exiting...",
+                item,
+            ))));
+        }
+
+        let mut n_fillers: usize = 0;
+        let (columns, _) = try_build_columns(items, 0, Some(&self.conditions), &mut n_fillers)?;
+
+        let schema: FixedSchema = FixedSchema::new(name, 1, columns);
+        schema.try_validate()?;
+
+        Ok(schema)
+    }
+}
+
+/// Pop all items of at least the provided level from the stack, attaching each of them to
+/// its parent, or to the roots if it has no parent.
+fn close_items(
+    stack: &mut Vec<CopybookItem>,
+    roots: &mut Vec<CopybookItem>,
+    level: u8,
+) -> Result<()> {
+    while stack.last().is_some_and(|i| i.level >= level) {
+        let item: CopybookItem = stack.pop().unwrap();
+
+        if item.picture.is_some() && !item.children.is_empty() {
+            return Err(Box::new(SetupError::new(&format!(
+                "Item '{}' has a picture but also subordinate items, exiting...",
+                item.name.as_deref().unwrap_or("FILLER"),
+            ))));
+        }
+
+        match stack.last_mut() {
+            Some(parent) => parent.children.push(item),
+            None => roots.push(item),
+        }
+    }
+
+    Ok(())
+}
+
+/// Get whether or not one of the items is named `name` and either redefines or is redefined
+/// by another of the items.
+fn is_redefined_region_definition(items: &[CopybookItem], name: &str) -> bool {
+    let is_named = |n: Option<&str>| n.is_some_and(|n| n.eq_ignore_ascii_case(name));
+    items.iter().any(|i| {
+        is_named(i.name.as_deref())
+            && (i.redefines.is_some() || items.iter().any(|j| is_named(j.redefines.as_deref())))
+    })
+}
+
+/// Try and build the columns of sibling items starting at `offset`, where groups become struct
+/// columns and redefined regions whose definitions all have conditions become alternatives.
+/// Returns the columns and the offset after the last item.
+///
+/// # Errors
+/// If any of the elementary items uses a binary usage, if two siblings have the same name, if
+/// an item redefines an item which it does not directly follow, only separated by other
+/// redefinitions of it, or if only some of the definitions of a region have conditions.
+fn try_build_columns(
+    items: &[CopybookItem],
+    offset: usize,
+    conditions: Option<&HashMap<String, Condition>>,
+    n_fillers: &mut usize,
+) -> Result<(Vec<FixedColumn>, usize)> {
+    let mut columns: Vec<FixedColumn> = Vec::new();
+    let mut offset: usize = offset;
+    let mut idx: usize = 0;
+
+    while idx < items.len() {
+        let item: &CopybookItem = &items[idx];
+        if let Some(redefined) = &item.redefines {
+            return Err(Box::new(SetupError::new(&format!(
+                "Item '{}' redefines '{}', which it has to directly follow, only separated by other redefinitions of it, exiting...",
+                item.name.as_deref().unwrap_or("FILLER"),
+                redefined,
+            ))));
+        }
+
+        let n_redefinitions: usize = items[idx + 1..]
+            .iter()
+            .take_while(|i| {
+                i.redefines.as_deref().is_some_and(|r| {
+                    item.name
+                        .as_deref()
+                        .is_some_and(|n| n.eq_ignore_ascii_case(r))
+                })
+            })
+            .count();
+        let definitions: &[CopybookItem] = &items[idx..=idx + n_redefinitions];
+        let length: usize = definitions.iter().map(|i| i.size()).max().unwrap_or(0);
+
+        let definition_conditions: Vec<Option<&Condition>> = definitions
+            .iter()
+            .map(|i| {
+                conditions.and_then(|c| {
+                    i.name
+                        .as_deref()
+                        .and_then(|n| c.get(&n.to_ascii_uppercase()))
+                })
+            })
+            .collect::<Vec<Option<&Condition>>>();
+
+        let mut region: Vec<FixedColumn> = Vec::new();
+        if n_redefinitions > 0 && definition_conditions.iter().all(|c| c.is_some()) {
+            for (definition, condition) in definitions.iter().zip(definition_conditions) {
+                region.push(try_build_alternative(
+                    definition,
+                    offset,
+                    length,
+                    condition.unwrap(),
+                    n_fillers,
+                )?);
+            }
+        } else {
+            if definition_conditions.iter().any(|c| c.is_some()) {
+                return Err(Box::new(SetupError::new(&format!(
+                    "Only some of the definitions of the region of '{}' have conditions, either all or none of them have to, exiting...",
+                    item.name.as_deref().unwrap_or("FILLER"),
+                ))));
+            }
+            if n_redefinitions > 0 {
+                warn!(
+                    "Keeping the first definition of the region of '{}', since its definitions do not have conditions.",
+                    item.name.as_deref().unwrap_or("FILLER"),
+                );
+            }
+
+            region.push(try_build_column(item, offset, n_fillers)?);
+            // A longer redefinition extends the record, which is covered by a filler.
+            if length > item.size() {
+                region.push(filler_column(
+                    offset + item.size(),
+                    length - item.size(),
+                    n_fillers,
+                ));
+            }
+        }
+
+        for column in region {
+            if columns.iter().any(|c| c.name() == column.name()) {
+                return Err(Box::new(SetupError::new(&format!(
+                    "Several fields in the same group are named '{}', exiting...",
+                    column.name(),
+                ))));
+            }
+            columns.push(column);
+        }
+
+        offset += length;
+        idx += n_redefinitions + 1;
+    }
+
+    Ok((columns, offset))
+}
+
+/// Try and build the column of a single item starting at `offset`, where a group becomes a
+/// struct column.
+///
+/// # Errors
+/// If any of the elementary items uses a binary usage, or if two siblings have the same name.
+fn try_build_column(
+    item: &CopybookItem,
+    offset: usize,
+    n_fillers: &mut usize,
+) -> Result<FixedColumn> {
+    let column: FixedColumn = match (item.column_name(), item.children.is_empty()) {
+        (None, true) => {
+            try_display_picture(item)?;
+            filler_column(offset, item.size(), n_fillers)
+        }
+        (None, false) => filler_column(offset, item.size(), n_fillers),
+        (Some(name), true) => {
+            let picture: &Picture = try_display_picture(item)?;
+            with_occurs(
+                picture_column(
+                    name,
+                    offset,
+                    item.element_size(),
+                    picture,
+                    item.sign_format(),
+                ),
+                item,
+            )
+        }
+        (Some(name), false) => {
+            let (fields, _) = try_build_columns(&item.children, offset, None, n_fillers)?;
+            let column: FixedColumn = FixedColumn::new(
+                name,
+                offset,
+                item.element_size(),
+                DataType::Struct,
+                Alignment::Left,
+                Symbol::Whitespace,
+                false,
+            )
+            .with_fields(fields);
+            with_occurs(column, item)
+        }
+    };
+
+    Ok(column)
+}
+
+/// Try and build the alternative struct column of a definition of a region of `length` bytes
+/// starting at `offset`. An elementary or repeating definition is wrapped in a struct of the
+/// same name, and a definition shorter than the region is padded with a filler.
+///
+/// # Errors
+/// If the column of the definition could not be built, see [`try_build_column`].
+fn try_build_alternative(
+    item: &CopybookItem,
+    offset: usize,
+    length: usize,
+    condition: &Condition,
+    n_fillers: &mut usize,
+) -> Result<FixedColumn> {
+    let column: FixedColumn = try_build_column(item, offset, n_fillers)?;
+    let name: String = column.name().clone();
+
+    let mut fields: Vec<FixedColumn> =
+        match column.dtype() == DataType::Struct && column.occurs().is_none() {
+            true => column.fields().clone(),
+            false => vec![column],
+        };
+    if length > item.size() {
+        fields.push(filler_column(
+            offset + item.size(),
+            length - item.size(),
+            n_fillers,
+        ));
+    }
+
+    Ok(FixedColumn::new(
+        name,
+        offset,
+        length,
+        DataType::Struct,
+        Alignment::Left,
+        Symbol::Whitespace,
+        true,
+    )
+    .with_fields(fields)
+    .with_condition(condition.clone()))
+}
+
+/// Try and get the picture of an elementary item with display usage.
+///
+/// # Errors
+/// If the item does not have display usage.
+fn try_display_picture(item: &CopybookItem) -> Result<&Picture> {
+    match (&item.picture, item.usage) {
+        (Some(p), Usage::Display) => Ok(p),
+        _ => Err(Box::new(SetupError::new(&format!(
+            "Field '{}' has usage {:?}, but only display usage can be read from newline delimited text, binary usages require support for binary record files, exiting...",
+            item.name.as_deref().unwrap_or("FILLER"),
+            item.usage,
+        )))),
+    }
+}
+
+/// Set the repeats of the column from the `OCCURS` clause of the item, if any.
+fn with_occurs(column: FixedColumn, item: &CopybookItem) -> FixedColumn {
+    match item.occurs {
+        Some(count) => {
+            let mut occurs: Occurs = Occurs::new(count);
+            if let Some(depending_on) = &item.depending_on {
+                occurs =
+                    occurs.with_depending_on(depending_on.to_ascii_lowercase().replace('-', "_"));
+            }
+            column.with_occurs(occurs)
+        }
+        None => column,
+    }
+}
+
+/// Create the column of an elementary item with display usage, where the sign of a numeric
+/// item is written in the provided sign format, if any.
+fn picture_column(
+    name: String,
+    offset: usize,
+    length: usize,
+    picture: &Picture,
+    sign_format: Option<SignFormat>,
+) -> FixedColumn {
+    let dtype: DataType = picture.dtype();
+    let column: FixedColumn = match dtype {
+        DataType::Utf8 => FixedColumn::new(
+            name,
+            offset,
+            length,
+            dtype,
+            Alignment::Left,
+            Symbol::Whitespace,
+            false,
+        ),
+        _ => FixedColumn::new(
+            name,
+            offset,
+            length,
+            dtype,
+            Alignment::Right,
+            Symbol::Whitespace,
+            true,
+        ),
+    };
+
+    let column: FixedColumn = match sign_format {
+        Some(sign_format) if dtype != DataType::Utf8 => column.with_sign_format(sign_format),
+        _ => column,
+    };
+
+    if dtype == DataType::Float64 && !picture.has_decimal_point && picture.decimals > 0 {
+        column.with_implied_decimals(picture.decimals)
+    } else {
+        column
+    }
+}
+
+/// Create the next string column covering a `FILLER` item.
+fn filler_column(offset: usize, length: usize, n_fillers: &mut usize) -> FixedColumn {
+    *n_fillers += 1;
+    FixedColumn::new(
+        format!("filler_{}", n_fillers),
+        offset,
+        length,
+        DataType::Utf8,
+        Alignment::Left,
+        Symbol::Whitespace,
+        false,
+    )
+}
+
+/// Check whether a line is in fixed format, i.e., it has a sequence number area of only digits
+/// or only spaces in columns 1-6 followed by an indicator in column 7.
+fn is_fixed_format_line(line: &str) -> bool {
+    let chars: Vec<char> = line.chars().take(7).collect();
+    chars.len() == 7
+        && (chars[..6].iter().all(|c| c.is_ascii_digit()) || chars[..6].iter().all(|c| *c == ' '))
+        && matches!(chars[6], ' ' | '*' | '/' | '-' | 'D' | 'd')
+}
+
+/// Remove sequence numbers, indicators, comments and the identification area from the
+/// source text of a copybook. The source is in fixed format if all non-blank lines are.
+fn strip_source_format(text: &str) -> String {
+    let is_fixed_format: bool = text
+        .lines()
+        .filter(|l| !l.trim().is_empty())
+        .all(is_fixed_format_line);
+
+    let mut source: String = String::with_capacity(text.len());
+
+    for line in text.lines() {
+        let code: String = if is_fixed_format && !line.trim().is_empty() {
+            let chars: Vec<char> = line.chars().collect();
+            if matches!(chars[6], '*' | '/') {
+                continue;
+            }
+            chars[7..chars.len().min(72)].iter().collect::<String>()
+        } else {
+            line.to_string()
+        };
+
+        let code: &str = match code.find("*>") {
+            Some(idx) => &code[..idx],
+            None => &code,
+        };
+        if code.trim_start().starts_with('*') {
+            continue;
+        }
+
+        source.push_str(code);
+        source.push(' ');
+    }
+
+    source
+}
+
+/// Split the source text into sentences ending with a period followed by whitespace,
+/// ignoring periods in quoted literals and picture strings.
+fn split_sentences(source: &str) -> Vec<String> {
+    let mut sentences: Vec<String> = Vec::new();
+    let mut sentence: String = String::new();
+    let mut quote: Option<char> = None;
+    let mut chars = source.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match (quote, c) {
+            (None, '\'' | '"') => quote = Some(c),
+            (Some(q), _) if q == c => quote = None,
+            (None, '.') if chars.peek().is_none_or(|n| n.is_whitespace()) => {
+                sentences.push(std::mem::take(&mut sentence));
+                continue;
+            }
+            _ => (),
+        }
+        sentence.push(c);
+    }
+
+    if !sentence.trim().is_empty() {
+        sentences.push(sentence);
+    }
+
+    sentences
+}
+
+/// Split a sentence into whitespace separated tokens, keeping quoted literals together.
+fn tokenize(sentence: &str) -> Vec<String> {
+    let mut tokens: Vec<String> = Vec::new();
+    let mut token: String = String::new();
+    let mut quote: Option<char> = None;
+
+    for c in sentence.chars() {
+        match (quote, c) {
+            (None, '\'' | '"') => quote = Some(c),
+            (Some(q), _) if q == c => quote = None,
+            (None, _) if c.is_whitespace() || c == ',' || c == ';' => {
+                if !token.is_empty() {
+                    tokens.push(std::mem::take(&mut token));
+                }
+                continue;
+            }
+            _ => (),
+        }
+        token.push(c);
+    }
+
+    if !token.is_empty() {
+        tokens.push(token);
+    }
+
+    tokens
+}
+
+#[cfg(test)]
+mod tests_copybook {
+    use super::*;
+    use arrow::array::{AsArray, RecordBatch};
+    use arrow::datatypes::{Float64Type, Int16Type};
+    use evolution_builder::builder::ParquetBuilder;
+
+    #[test]
+    fn test_copybook_into_schema() {
+        let mut path: PathBuf = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        path.push("res/test_copybook.cpy");
+
+        let schema: FixedSchema = Copybook::from_path(path)
+            .unwrap()
+            .try_into_schema(None)
+            .unwrap();
+
+        assert_eq!("customer_record", schema.name());
+
+        let columns: Vec<(&str, usize, usize, DataType)> = schema
+            .iter()
+            .map(|c| (c.name().as_str(), c.offset(), c.length(), c.dtype()))
+            .collect();
+
+        assert_eq!(
+            vec![
+                ("cust_id", 0, 8, DataType::Int32),
+                ("cust_name", 8, 30, DataType::Utf8),
+                ("cust_address", 38, 25, DataType::Struct),
+                ("filler_1", 63, 2, DataType::Utf8),
+                ("balance", 65, 10, DataType::Float64),
+                ("rate", 75, 5, DataType::Float64),
+                ("month_amount", 80, 12, DataType::Int16),
+                ("phones", 92, 22, DataType::Struct),
+                ("status", 114, 1, DataType::Utf8),
+            ],
+            columns,
+        );
+
+        assert_eq!(115, schema.row_length());
+        assert_eq!(Some(2), schema.columns()[4].implied_decimals());
+        assert_eq!(None, schema.columns()[5].implied_decimals());
+        assert_eq!(Some(&Occurs::new(3)), schema.columns()[6].occurs());
+        assert_eq!(4, schema.columns()[6].element_length());
+
+        let address: Vec<(&str, usize, usize)> = schema.columns()[2]
+            .fields()
+            .iter()
+            .map(|c| (c.name().as_str(), c.offset(), c.length()))
+            .collect();
+        assert_eq!(vec![("street", 38, 20), ("zip", 58, 5)], address);
+
+        let phones: &FixedColumn = &schema.columns()[7];
+        assert_eq!(Some(&Occurs::new(2)), phones.occurs());
+        assert_eq!(11, phones.element_length());
+        assert_eq!(
+            vec!["phone_type", "phone_number"],
+            phones
+                .fields()
+                .iter()
+                .map(|c| c.name().as_str())
+                .collect::<Vec<&str>>(),
+        );
+    }
+
+    #[test]
+    fn test_copybook_occurs_depending_on() {
+        let text: &str = "01 ORDER.\n   05 N-ITEMS PIC 9.\n   05 ITEM-CODE PIC X(2) OCCURS 1 TO 3 TIMES DEPENDING ON N-ITEMS.\n";
+        let schema: FixedSchema = Copybook::try_parse("order", text)
+            .unwrap()
+            .try_into_schema(None)
+            .unwrap();
+
+        assert_eq!(7, schema.row_length());
+        assert_eq!(
+            Some(&Occurs::new(3).with_depending_on(String::from("n_items"))),
+            schema.columns()[1].occurs(),
+        );
+    }
+
+    #[test]
+    fn test_copybook_signed_fields() {
+        let text: &str = "01 REC.\n   05 A PIC S9(5)V99.\n   05 B PIC S9(4) SIGN LEADING.\n   05 C PIC S9(3) SIGN TRAILING SEPARATE.\n   05 D PIC S9(3) SIGN IS LEADING SEPARATE CHARACTER.\n";
+        let schema: FixedSchema = Copybook::try_parse("signed", text)
+            .unwrap()
+            .try_into_schema(None)
+            .unwrap();
+
+        assert_eq!(19, schema.row_length());
+        assert_eq!(
+            vec![
+                Some(SignFormat::TrailingOverpunch),
+                Some(SignFormat::LeadingOverpunch),
+                Some(SignFormat::TrailingSeparate),
+                None,
+            ],
+            schema
+                .iter()
+                .map(|c| c.sign_format())
+                .collect::<Vec<Option<SignFormat>>>(),
+        );
+
+        let mut builder: ParquetBuilder = schema.into_builder::<ParquetBuilder>();
+        builder
+            .try_build_from_slice("001234J}123123--042\n".as_bytes())
+            .unwrap();
+        let batch: RecordBatch = builder.try_finish().unwrap();
+
+        assert_eq!(
+            -123.41,
+            batch.column(0).as_primitive::<Float64Type>().value(0)
+        );
+        assert_eq!(-123, batch.column(1).as_primitive::<Int16Type>().value(0));
+        assert_eq!(-123, batch.column(2).as_primitive::<Int16Type>().value(0));
+        assert_eq!(-42, batch.column(3).as_primitive::<Int16Type>().value(0));
+    }
+
+    #[test]
+    fn test_copybook_redefines_alternatives() {
+        let text: &str = "01 CUSTOMER.\n   05 CUST-TYPE PIC X.\n   05 PERSON.\n      10 FIRST-NAME PIC X(6).\n      10 LAST-NAME PIC X(6).\n   05 COMPANY REDEFINES PERSON.\n      10 ORG-NAME PIC X(8).\n      10 ORG-NO PIC 9(4).\n   05 CODE REDEFINES PERSON PIC X(10).\n   05 STATUS PIC X.\n";
+        let copybook: Copybook = Copybook::try_parse("customer", text).unwrap();
+
+        let schema: FixedSchema = copybook
+            .clone()
+            .with_condition(
+                "person",
+                Condition::new("CUST-TYPE".to_string(), vec!["P".to_string()]),
+            )
+            .with_condition(
+                "COMPANY",
+                Condition::new(
+                    "cust_type".to_string(),
+                    vec!["C".to_string(), "G".to_string()],
+                ),
+            )
+            .with_condition(
+                "CODE",
+                Condition::new("cust_type".to_string(), vec!["X".to_string()]),
+            )
+            .try_into_schema(None)
+            .unwrap();
+
+        assert_eq!(14, schema.row_length());
+        assert_eq!(3, schema.alternatives(1).len());
+        assert_eq!(
+            Some(&Condition::new(
+                "cust_type".to_string(),
+                vec!["C".to_string(), "G".to_string()]
+            )),
+            schema.columns()[2].condition(),
+        );
+
+        let code: Vec<(&str, usize, usize)> = schema.columns()[3]
+            .fields()
+            .iter()
+            .map(|c| (c.name().as_str(), c.offset(), c.length()))
+            .collect();
+        assert_eq!(vec![("code", 1, 10), ("filler_1", 11, 2)], code);
+
+        let mut builder: ParquetBuilder = schema.into_builder::<ParquetBuilder>();
+        builder
+            .try_build_from_slice("PAnna  Smith A\nCAcme    1234B\nXABCDEFGHIJ  C\n".as_bytes())
+            .unwrap();
+        let batch: RecordBatch = builder.try_finish().unwrap();
+
+        let is_null = |idx: usize| -> Vec<bool> {
+            (0..3).map(|row| batch.column(idx).is_null(row)).collect()
+        };
+        assert_eq!(vec![false, true, true], is_null(1));
+        assert_eq!(vec![true, false, true], is_null(2));
+        assert_eq!(vec![true, true, false], is_null(3));
+
+        let without_conditions: FixedSchema = copybook.clone().try_into_schema(None).unwrap();
+        assert_eq!(
+            vec!["cust_type", "person", "status"],
+            without_conditions
+                .iter()
+                .map(|c| c.name().as_str())
+                .collect::<Vec<&str>>(),
+        );
+
+        let condition: Condition = Condition::new("cust_type".to_string(), vec!["P".to_string()]);
+        assert!(copybook
+            .clone()
+            .with_condition("PERSON", condition.clone())
+            .try_into_schema(None)
+            .is_err());
+        assert!(copybook
+            .clone()
+            .with_condition("FIRST-NAME", condition.clone())
+            .try_into_schema(None)
+            .is_err());
+        assert!(copybook
+            .with_condition("STATUS", condition)
+            .try_into_schema(None)
+            .is_err());
+    }
+
+    #[test]
+    fn test_copybook_errors() {
+        let packed: &str = "01 REC.\n   05 AMOUNT PIC S9(7)V99 COMP-3.\n";
+        assert!(Copybook::try_parse("packed", packed)
+            .unwrap()
+            .try_into_schema(None)
+            .is_err());
+
+        let two_records: &str = "01 A.\n   05 X PIC X.\n01 B.\n   05 Y PIC 9.\n";
+        let copybook: Copybook = Copybook::try_parse("two", two_records).unwrap();
+        assert!(copybook.try_into_schema(None).is_err());
+        assert_eq!(
+            1,
+            copybook.try_into_schema(Some("b")).unwrap().num_columns()
+        );
+
+        let invalid: &str = "01 REC.\n   05 A PIC X(3 .\n";
+        assert!(Copybook::try_parse("invalid", invalid).is_err());
+    }
+}
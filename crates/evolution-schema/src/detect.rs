@@ -0,0 +1,418 @@
+//
+// MIT License
+//
+// Copyright (c) 2026 Firelink Data
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//
+// File created: 2026-10-18
+// Last updated: 2026-10-18
+//
+
+//! Detection of narrower datatypes for the string columns of an existing [`FixedSchema`], from
+//! a sample of the records of a fixed-length file.
+//!
+//! Every `Utf8` and `LargeUtf8` column, including the fields of struct columns, gets the first
+//! of the following datatypes that all of its sampled (non-empty) values can be parsed as:
+//!
+//! * `Boolean`, for the values `true` and `false`.
+//! * `Date32`, with the first of the [`DATE_FORMATS`] that all values are written in.
+//! * `Int16`, `Int32` or `Int64`, the narrowest that can hold any value that fits in the field.
+//!   Values with leading zeros, such as postal codes, are kept as strings.
+//! * `Decimal128`, with the largest number of decimals of the values as scale, and a precision
+//!   large enough for any value that fits in the field.
+//! * `Float64`, for any other numbers.
+//!
+//! A column with empty values becomes nullable, since an empty field can not be parsed as any
+//! of the datatypes above. Columns whose default value can not be parsed as the detected
+//! datatype are kept as they are.
+
+use evolution_common::datatype::DataType;
+use evolution_common::error::{Result, SetupError};
+use evolution_parser::datatype::{parse_date, MAX_DECIMAL128_PRECISION};
+use log::info;
+use padder::Alignment;
+
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::PathBuf;
+
+use crate::column::FixedColumn;
+use crate::schema::FixedSchema;
+
+/// The date formats which are detected, in order of preference.
+pub static DATE_FORMATS: [&str; 8] = [
+    "%Y-%m-%d", "%Y%m%d", "%Y/%m/%d", "%Y.%m.%d", "%d.%m.%Y", "%d/%m/%Y", "%d-%m-%Y", "%m/%d/%Y",
+];
+
+/// The range of years that dates without separators have to be in, so that numbers which
+/// happen to be valid dates are not detected as dates.
+static COMPACT_DATE_YEARS: std::ops::RangeInclusive<i32> = 1800..=2200;
+
+/// A detector of narrower datatypes for the string columns of a schema, from a sample of the
+/// body records of a fixed-length file.
+#[derive(Clone, Debug)]
+pub struct DtypeDetector {
+    /// The characters of each of the sampled records.
+    records: Vec<Vec<char>>,
+}
+
+impl DtypeDetector {
+    /// Create a new [`DtypeDetector`] from the provided body records.
+    pub fn new(records: &[String]) -> Self {
+        Self {
+            records: records
+                .iter()
+                .map(|r| r.chars().collect::<Vec<char>>())
+                .collect(),
+        }
+    }
+
+    /// Try and read at most `n_records` body records from a file with the provided schema,
+    /// skipping the lines and the header record before them, and leaving out the trailer
+    /// record if the file ends within the sample.
+    ///
+    /// # Errors
+    /// If the file could not be opened, or any of the sampled records is not valid UTF-8.
+    pub fn from_path(path: PathBuf, schema: &FixedSchema, n_records: usize) -> Result<Self> {
+        Ok(Self::new(&read_body_records(path, schema, n_records)?))
+    }
+
+    /// Get the number of sampled records.
+    pub fn n_records(&self) -> usize {
+        self.records.len()
+    }
+
+    /// Try and detect narrower datatypes for the string columns of the schema, producing an
+    /// upgraded copy of it.
+    ///
+    /// # Errors
+    /// If there are no sampled records.
+    pub fn try_detect(&self, schema: &FixedSchema) -> Result<FixedSchema> {
+        if self.records.is_empty() {
+            return Err(Box::new(SetupError::new(
+                "Can not detect any datatypes without any sampled records, exiting...",
+            )));
+        }
+
+        let columns: Vec<FixedColumn> = schema
+            .iter()
+            .map(|c| self.detect_column(c))
+            .collect::<Vec<FixedColumn>>();
+
+        let mut detected: FixedSchema =
+            FixedSchema::new(schema.name().to_string(), schema.version(), columns)
+                .with_skip_lines(schema.skip_lines());
+        if let Some(header) = schema.header() {
+            detected = detected.with_header(header.clone());
+        }
+        if let Some(trailer) = schema.trailer() {
+            detected = detected.with_trailer(trailer.clone());
+        }
+
+        Ok(detected)
+    }
+
+    /// Detect a narrower datatype for the column if it is a string column, or for its fields if
+    /// it is a struct column.
+    fn detect_column(&self, column: &FixedColumn) -> FixedColumn {
+        match column.dtype() {
+            DataType::Struct => column.clone().with_fields(
+                column
+                    .fields()
+                    .iter()
+                    .map(|f| self.detect_column(f))
+                    .collect::<Vec<FixedColumn>>(),
+            ),
+            DataType::Utf8 | DataType::LargeUtf8 => {
+                let values: Vec<String> = field_values(&self.records, column);
+                let has_empty: bool = values.iter().any(|v| v.is_empty());
+                let values: Vec<&str> = values
+                    .iter()
+                    .filter(|v| !v.is_empty())
+                    .map(|v| v.as_str())
+                    .collect::<Vec<&str>>();
+
+                let detected: FixedColumn = match detect_dtype(&values, column.element_length()) {
+                    Some(d) => d(column.clone()).with_nullable(column.is_nullable() || has_empty),
+                    None => return column.clone(),
+                };
+
+                match detected.try_validate() {
+                    Ok(()) => {
+                        info!(
+                            "Column '{}' can be narrowed from '{:?}' to '{:?}'.",
+                            column.name(),
+                            column.dtype(),
+                            detected.dtype(),
+                        );
+                        detected
+                    }
+                    Err(_) => column.clone(),
+                }
+            }
+            _ => column.clone(),
+        }
+    }
+}
+
+/// Try and read at most `n_records` body records from a file with the provided schema,
+/// skipping the lines and the header record before them, and leaving out the trailer record if
+/// the file ends within the sample.
+///
+/// # Errors
+/// If the file could not be opened, or any of the sampled records is not valid UTF-8.
+pub(crate) fn read_body_records(
+    path: PathBuf,
+    schema: &FixedSchema,
+    n_records: usize,
+) -> Result<Vec<String>> {
+    let n_skipped: usize = schema.skip_lines() + usize::from(schema.header().is_some());
+    let n_trailer: usize = usize::from(schema.trailer().is_some());
+
+    let mut records: Vec<String> = BufReader::new(File::open(path)?)
+        .lines()
+        .skip(n_skipped)
+        .take(n_records + n_trailer)
+        .map(|l| l.map(|l| l.trim_end_matches('\r').to_string()))
+        .collect::<std::io::Result<Vec<String>>>()?;
+
+    if records.len() > n_records {
+        records.truncate(n_records);
+    } else if n_trailer == 1 {
+        records.pop();
+    }
+
+    Ok(records)
+}
+
+/// Get the values of each element of the column in all of the records, with the padding
+/// removed according to the alignment and pad symbol of the column.
+pub(crate) fn field_values(records: &[Vec<char>], column: &FixedColumn) -> Vec<String> {
+    let n_elements: usize = column.occurs().map_or(1, |o| o.count());
+    let pad: char = char::from(column.pad_symbol());
+
+    let mut values: Vec<String> = Vec::with_capacity(records.len() * n_elements);
+    for record in records.iter() {
+        for element in 0..n_elements {
+            let start: usize = column.offset() + element * column.element_length();
+            let end: usize = (start + column.element_length()).min(record.len());
+            let field: String = record.get(start..end).unwrap_or(&[]).iter().collect();
+
+            values.push(match column.alignment() {
+                Alignment::Left => field.trim_end_matches(pad).to_string(),
+                Alignment::Right => field.trim_start_matches(pad).to_string(),
+                Alignment::Center => field.trim_matches(pad).to_string(),
+            });
+        }
+    }
+
+    values
+}
+
+/// A change of the datatype of a column.
+type Upgrade = Box<dyn Fn(FixedColumn) -> FixedColumn>;
+
+/// Detect the narrowest datatype that all of the (non-empty) values, of fields with the
+/// provided length, can be parsed as, if any other than a string.
+fn detect_dtype(values: &[&str], length: usize) -> Option<Upgrade> {
+    if values.is_empty() {
+        return None;
+    }
+
+    if values.iter().all(|v| v.parse::<bool>().is_ok()) {
+        return Some(Box::new(|c| c.with_dtype(DataType::Boolean)));
+    }
+
+    if let Some(format) = detect_date_format(values) {
+        return Some(Box::new(move |c| {
+            c.with_dtype(DataType::Date32)
+                .with_format(format.to_string())
+        }));
+    }
+
+    let numbers: Option<Vec<(&str, &str)>> = values.iter().map(|v| split_number(v)).collect();
+    let numbers: Vec<(&str, &str)> = match numbers {
+        Some(numbers) => numbers,
+        None => {
+            return match values
+                .iter()
+                .all(|v| v.parse::<f64>().is_ok_and(|f| f.is_finite()))
+            {
+                true => Some(Box::new(|c| c.with_dtype(DataType::Float64))),
+                false => None,
+            };
+        }
+    };
+
+    if numbers
+        .iter()
+        .any(|(integer, _)| integer.len() > 1 && integer.starts_with('0'))
+    {
+        return None;
+    }
+
+    let scale: usize = numbers.iter().map(|(_, f)| f.len()).max().unwrap_or(0);
+    if scale == 0 {
+        let dtype: DataType = match length {
+            0..=4 => DataType::Int16,
+            5..=9 => DataType::Int32,
+            10..=18 => DataType::Int64,
+            _ if values.iter().all(|v| v.parse::<i64>().is_ok()) => DataType::Int64,
+            _ => return None,
+        };
+        return Some(Box::new(move |c| c.with_dtype(dtype)));
+    }
+
+    let max_integer_digits: usize = numbers.iter().map(|(i, _)| i.len()).max().unwrap_or(0);
+    let precision: usize = match (length + scale).min(MAX_DECIMAL128_PRECISION) {
+        p if p >= max_integer_digits + scale => p,
+        _ => return None,
+    };
+
+    Some(Box::new(move |c| {
+        c.with_dtype(DataType::Decimal128)
+            .with_precision_and_scale(precision, scale)
+    }))
+}
+
+/// Detect the first of the [`DATE_FORMATS`] that all of the values are written in, if any.
+fn detect_date_format(values: &[&str]) -> Option<&'static str> {
+    DATE_FORMATS.iter().copied().find(|format| {
+        values
+            .iter()
+            .all(|v| is_date_candidate(v, format) && parse_date(v, format).is_some())
+    })
+}
+
+/// Whether the value could be a date in the format, before trying to parse it. Dates without
+/// separators also have to be within [`COMPACT_DATE_YEARS`], so that numbers which happen to be
+/// valid dates are kept as numbers.
+fn is_date_candidate(value: &str, format: &str) -> bool {
+    match format {
+        "%Y%m%d" => {
+            value.len() == 8
+                && value[..4]
+                    .parse::<i32>()
+                    .is_ok_and(|y| COMPACT_DATE_YEARS.contains(&y))
+        }
+        _ => value.len() >= 8,
+    }
+}
+
+/// Split a number, with an optional sign and decimal point, into its integer and fraction
+/// digits, or `None` if it is not such a number.
+fn split_number(value: &str) -> Option<(&str, &str)> {
+    let number: &str = value.strip_prefix(['-', '+']).unwrap_or(value);
+    let (integer, fraction) = number.split_once('.').unwrap_or((number, ""));
+
+    let is_number: bool = !(integer.is_empty() && fraction.is_empty())
+        && integer
+            .bytes()
+            .chain(fraction.bytes())
+            .all(|b| b.is_ascii_digit());
+    is_number.then_some((integer, fraction))
+}
+
+#[cfg(test)]
+mod tests_detect {
+    use super::*;
+    use padder::Symbol;
+
+    fn schema() -> FixedSchema {
+        let column = |name: &str, offset: usize, length: usize, alignment: Alignment| {
+            FixedColumn::new(
+                name.to_string(),
+                offset,
+                length,
+                DataType::Utf8,
+                alignment,
+                Symbol::Whitespace,
+                false,
+            )
+        };
+
+        FixedSchema::new(
+            "detect".to_string(),
+            1,
+            vec![
+                column("id", 0, 4, Alignment::Right),
+                column("date", 4, 10, Alignment::Left),
+                column("amount", 14, 8, Alignment::Right),
+                column("zip", 22, 5, Alignment::Left),
+                column("flag", 27, 5, Alignment::Left),
+                column("note", 32, 4, Alignment::Left),
+            ],
+        )
+    }
+
+    fn records() -> Vec<String> {
+        [
+            "   12024-01-31  123.4501234true abc ",
+            "  422023-12-01    -7.598765false    ",
+            " 3172020-02-29        00010true x   ",
+        ]
+        .iter()
+        .map(|r| r.to_string())
+        .collect()
+    }
+
+    #[test]
+    fn test_detect_dtypes() {
+        let detected: FixedSchema = DtypeDetector::new(&records())
+            .try_detect(&schema())
+            .unwrap();
+
+        assert_eq!(
+            vec![
+                DataType::Int16,
+                DataType::Date32,
+                DataType::Decimal128,
+                DataType::Utf8,
+                DataType::Boolean,
+                DataType::Utf8,
+            ],
+            detected.dtypes(),
+        );
+
+        let columns: &Vec<FixedColumn> = detected.columns();
+        assert_eq!(Some("%Y-%m-%d"), columns[1].format());
+        assert_eq!((10, 2), columns[2].precision_and_scale());
+        assert!(columns[2].is_nullable());
+        assert!(!columns[0].is_nullable());
+        assert!(!columns[5].is_nullable());
+        assert!(detected.try_validate().is_ok());
+    }
+
+    #[test]
+    fn test_detect_date_formats() {
+        assert_eq!(
+            Some("%Y%m%d"),
+            detect_date_format(&["20240131", "19991231"])
+        );
+        assert_eq!(Some("%d.%m.%Y"), detect_date_format(&["31.01.2024"]));
+        assert_eq!(None, detect_date_format(&["12345678"]));
+        assert_eq!(None, detect_date_format(&["2024-02-30"]));
+    }
+
+    #[test]
+    fn test_detect_dtypes_without_records() {
+        assert!(DtypeDetector::new(&[]).try_detect(&schema()).is_err());
+    }
+}
@@ -0,0 +1,587 @@
+//
+// MIT License
+//
+// Copyright (c) 2026 Firelink Data
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//
+// File created: 2026-10-18
+// Last updated: 2026-10-18
+//
+
+//! Inference of draft [`FixedSchema`]s from a sample of the records of a fixed-length file.
+//!
+//! The column boundaries are proposed by analysing, position by position, where the tokens of
+//! the records start and end. A token is a run of characters without whitespace, which is also
+//! split where letters meet digits or symbols, and where a run of a pad symbol other than
+//! whitespace starts or ends, e.g., `Anna****____12.5___` has the tokens `Anna`, `****`,
+//! `____`, `12.5` and `___`. A position where the tokens of nearly every record start is the
+//! start of a left aligned column, and a position where they end is the end of a right aligned
+//! column. A left aligned column directly followed by a right aligned column is split at the end
+//! of its longest value, when no record has a token crossing that position, and a boolean column
+//! at the end of a column is split off, since its values are recognizable even without padding.
+//!
+//! Each column gets the narrowest of the datatypes `Boolean`, `Int32`, `Int64` and `Float64`
+//! that all of its values can be parsed as, or `Utf8`, together with the alignment and pad
+//! symbol that most of its values agree on. Since a sample never shows every value a column
+//! can hold, the schema is a draft which should be reviewed before being used.
+
+use evolution_common::datatype::DataType;
+use evolution_common::error::{Result, SetupError};
+use log::warn;
+use padder::{Alignment, Symbol};
+
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::PathBuf;
+
+use crate::column::FixedColumn;
+use crate::schema::FixedSchema;
+
+/// The fraction of the records with a character at a position which have to start (or end) a
+/// token there, for the position to be a column boundary.
+pub static MIN_BOUNDARY_SUPPORT: f64 = 0.9;
+
+/// The fraction of all records which have to have a character at a position (or just before
+/// it) for the position to be a column boundary, so that the words of sparse text columns do
+/// not produce boundaries.
+pub static MIN_BOUNDARY_FILL: f64 = 0.5;
+
+/// The symbols which are considered as pad symbols of the columns, in order of preference.
+static PAD_SYMBOLS: [Symbol; 6] = [
+    Symbol::Whitespace,
+    Symbol::Hyphen,
+    Symbol::Underscore,
+    Symbol::Dot,
+    Symbol::Asterisk,
+    Symbol::Hashtag,
+];
+
+/// The class of a character, where a token is split when its class changes between letters and
+/// anything else, or between a run of a pad symbol and anything else.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum CharClass {
+    Space,
+    Digit,
+    Letter,
+    /// A run of two or more of the same pad symbol, other than whitespace. A single pad symbol,
+    /// e.g., a minus sign or a decimal point, is not padding.
+    Pad(char),
+    Other,
+}
+
+impl CharClass {
+    /// Get the class of the character at the index of the characters.
+    fn of(chars: &[char], idx: usize) -> Self {
+        let c: char = chars[idx];
+        let is_pad_run: bool = PAD_SYMBOLS[1..].iter().any(|s| char::from(*s) == c)
+            && ((idx > 0 && chars[idx - 1] == c) || chars.get(idx + 1) == Some(&c));
+
+        match is_pad_run {
+            true => CharClass::Pad(c),
+            false => CharClass::from(c),
+        }
+    }
+}
+
+impl From<char> for CharClass {
+    fn from(c: char) -> Self {
+        if c.is_whitespace() {
+            CharClass::Space
+        } else if c.is_ascii_digit() {
+            CharClass::Digit
+        } else if c.is_alphabetic() {
+            CharClass::Letter
+        } else {
+            CharClass::Other
+        }
+    }
+}
+
+/// Check whether a token is split between two consecutive characters.
+fn is_split(a: CharClass, b: CharClass) -> bool {
+    match (a, b) {
+        (CharClass::Pad(x), CharClass::Pad(y)) => x != y,
+        (CharClass::Pad(_), _) | (_, CharClass::Pad(_)) => true,
+        _ => matches!(
+            (a, b),
+            (CharClass::Space, _)
+                | (_, CharClass::Space)
+                | (CharClass::Digit, CharClass::Letter)
+                | (CharClass::Letter, CharClass::Digit)
+                | (CharClass::Letter, CharClass::Other)
+                | (CharClass::Other, CharClass::Letter)
+        ),
+    }
+}
+
+/// Check whether a character is whitespace or any of the pad symbols.
+fn is_pad_char(c: char) -> bool {
+    PAD_SYMBOLS.iter().any(|s| char::from(*s) == c) || c.is_whitespace()
+}
+
+/// A sample of the records of a fixed-length file, which a draft schema can be inferred from.
+#[derive(Clone, Debug)]
+pub struct Sample {
+    name: String,
+    /// The characters of each record, padded with whitespace to the length of the longest record.
+    records: Vec<Vec<char>>,
+    row_length: usize,
+}
+
+impl Sample {
+    /// Create a new [`Sample`] from the provided records, naming the schema it produces `name`.
+    pub fn new(name: &str, records: &[String]) -> Self {
+        let row_length: usize = records.iter().map(|r| r.chars().count()).max().unwrap_or(0);
+
+        if records.iter().any(|r| r.chars().count() != row_length) {
+            warn!(
+                "The sampled records are not all {} characters long, padding the shorter ones with whitespace.",
+                row_length,
+            );
+        }
+
+        let records: Vec<Vec<char>> = records
+            .iter()
+            .map(|r| {
+                let mut chars: Vec<char> = r.chars().collect();
+                chars.resize(row_length, ' ');
+                chars
+            })
+            .collect();
+
+        Self {
+            name: name.to_string(),
+            records,
+            row_length,
+        }
+    }
+
+    /// Try and read at most `n_records` records from the start of a file. The stem of the file
+    /// name is used as the name of the schema.
+    ///
+    /// # Errors
+    /// If the file could not be opened, or any of the sampled records is not valid UTF-8.
+    pub fn from_path(path: PathBuf, n_records: usize) -> Result<Self> {
+        let name: String = path
+            .file_stem()
+            .map(|s| s.to_string_lossy().to_string())
+            .unwrap_or_default();
+
+        let records: Vec<String> = BufReader::new(File::open(path)?)
+            .lines()
+            .take(n_records)
+            .map(|l| l.map(|l| l.trim_end_matches('\r').to_string()))
+            .collect::<std::io::Result<Vec<String>>>()?;
+
+        Ok(Self::new(&name, &records))
+    }
+
+    /// Get the number of sampled records.
+    pub fn n_records(&self) -> usize {
+        self.records.len()
+    }
+
+    /// Try and infer a draft [`FixedSchema`] from the sampled records, with the columns named
+    /// `column_<n>` in order.
+    ///
+    /// # Errors
+    /// If there are no sampled records, or all of them are empty.
+    pub fn try_into_schema(&self) -> Result<FixedSchema> {
+        if self.row_length == 0 {
+            return Err(Box::new(SetupError::new(
+                "Can not infer a schema without any non-empty records, exiting...",
+            )));
+        }
+
+        let mut boundaries: Vec<usize> = vec![0];
+        boundaries.extend(self.boundaries());
+        boundaries.push(self.row_length);
+
+        let columns: Vec<FixedColumn> = boundaries
+            .windows(2)
+            .enumerate()
+            .map(|(idx, w)| self.infer_column(format!("column_{}", idx + 1), w[0], w[1]))
+            .collect::<Vec<FixedColumn>>();
+
+        Ok(FixedSchema::new(self.name.clone(), 1, columns))
+    }
+
+    /// Get the class of the character at a position of a record, where the positions outside
+    /// of the record are whitespace.
+    fn class(&self, record: usize, position: usize) -> CharClass {
+        match position < self.records[record].len() {
+            true => CharClass::of(&self.records[record], position),
+            false => CharClass::Space,
+        }
+    }
+
+    /// Check whether a token of the record starts at the position.
+    fn is_start(&self, record: usize, position: usize) -> bool {
+        let class: CharClass = self.class(record, position);
+        class != CharClass::Space
+            && (position == 0 || is_split(self.class(record, position - 1), class))
+    }
+
+    /// Check whether a token of the record ends just before the position.
+    fn is_end(&self, record: usize, position: usize) -> bool {
+        let class: CharClass = self.class(record, position - 1);
+        class != CharClass::Space && is_split(class, self.class(record, position))
+    }
+
+    /// Check whether enough records have a character at a position (`filled`), and nearly all
+    /// of them meet the predicate, which checks whether a record starts (or ends) a token there.
+    fn is_supported(&self, filled: usize, predicate: impl Fn(usize) -> bool) -> bool {
+        let n_records: f64 = self.records.len() as f64;
+        let n_supporting: usize = (0..self.records.len()).filter(|r| predicate(*r)).count();
+
+        filled > 0
+            && filled as f64 >= MIN_BOUNDARY_FILL * n_records
+            && n_supporting as f64 >= MIN_BOUNDARY_SUPPORT * filled as f64
+    }
+
+    /// Get the number of records with a character at the position.
+    fn n_filled(&self, position: usize) -> usize {
+        (0..self.records.len())
+            .filter(|r| self.class(*r, position) != CharClass::Space)
+            .count()
+    }
+
+    /// Check whether the position is the start of a left aligned column.
+    fn is_left_boundary(&self, position: usize) -> bool {
+        self.is_supported(self.n_filled(position), |r| self.is_start(r, position))
+    }
+
+    /// Check whether the position is the end of a right aligned column.
+    fn is_right_boundary(&self, position: usize) -> bool {
+        self.is_supported(self.n_filled(position - 1), |r| self.is_end(r, position))
+    }
+
+    /// Get the column boundaries of the records, excluding the start and end of the records.
+    fn boundaries(&self) -> Vec<usize> {
+        let mut boundaries: Vec<usize> = Vec::new();
+        let mut start: usize = 0;
+        let mut is_left_aligned: bool = true;
+
+        for position in 1..=self.row_length {
+            let is_left: bool = position < self.row_length && self.is_left_boundary(position);
+            let is_right: bool = self.is_right_boundary(position);
+            if !is_left && !is_right {
+                continue;
+            }
+
+            if is_right {
+                let split: Option<usize> = match self.try_split_boolean(start, position) {
+                    None if is_left_aligned => self.try_split(start, position),
+                    split => split,
+                };
+                if let Some(split) = split {
+                    boundaries.push(split);
+                }
+            }
+            if position < self.row_length {
+                boundaries.push(position);
+            }
+
+            start = position;
+            is_left_aligned = is_left;
+        }
+
+        boundaries
+    }
+
+    /// Try and find where a left aligned column starting at `start` ends, when it is directly
+    /// followed by a right aligned column ending at `end`. This is the end of the longest first
+    /// token of the records with several tokens, as long as no record has a token crossing it
+    /// and no last token of a record starts before it.
+    fn try_split(&self, start: usize, end: usize) -> Option<usize> {
+        let mut split: Option<usize> = None;
+        let mut last_start: usize = end;
+
+        for record in self.records.iter() {
+            let tokens: Vec<(usize, usize)> = tokens(&record[start..end]);
+            if tokens.len() < 2 {
+                continue;
+            }
+            split = split.max(Some(start + tokens[0].1));
+            last_start = last_start.min(start + tokens[tokens.len() - 1].0);
+        }
+
+        let split: usize = split?;
+        let is_clear: bool = (0..self.records.len())
+            .all(|r| is_split(self.class(r, split - 1), self.class(r, split)));
+
+        (split <= last_start && is_clear).then_some(split)
+    }
+
+    /// Try and find where a column starting at `start` ends, when it is directly followed by a
+    /// boolean column ending at `end`, e.g., `Anna-true` and `Johnfalse`. This is the start of
+    /// the widest field before `end` which is blank or a boolean in every record, as long as
+    /// some record has a value before it.
+    fn try_split_boolean(&self, start: usize, end: usize) -> Option<usize> {
+        let trimmed = |record: &[char], from: usize, to: usize| -> String {
+            record[from..to]
+                .iter()
+                .collect::<String>()
+                .trim_matches(is_pad_char)
+                .to_string()
+        };
+
+        (start + 1..=end.saturating_sub("true".len())).find(|split| {
+            let values: Vec<String> = self
+                .records
+                .iter()
+                .map(|r| trimmed(r, *split, end))
+                .collect::<Vec<String>>();
+
+            values.iter().any(|v| !v.is_empty())
+                && values
+                    .iter()
+                    .all(|v| v.is_empty() || v.parse::<bool>().is_ok())
+                && self
+                    .records
+                    .iter()
+                    .any(|r| !trimmed(r, start, *split).is_empty())
+        })
+    }
+
+    /// Infer a column covering the positions from `start` to `end` of the records.
+    fn infer_column(&self, name: String, start: usize, end: usize) -> FixedColumn {
+        let fields: Vec<&[char]> = self
+            .records
+            .iter()
+            .map(|r| &r[start..end])
+            .collect::<Vec<&[char]>>();
+
+        let pad_symbol: Symbol = infer_pad_symbol(&fields);
+        let pad: char = char::from(pad_symbol);
+        let values: Vec<String> = fields
+            .iter()
+            .map(|f| f.iter().collect::<String>().trim_matches(pad).to_string())
+            .collect::<Vec<String>>();
+
+        let is_nullable: bool = values.iter().any(|v| v.is_empty());
+        let values: Vec<&str> = values
+            .iter()
+            .filter(|v| !v.is_empty())
+            .map(|v| v.as_str())
+            .collect::<Vec<&str>>();
+
+        let dtype: DataType = infer_dtype(&values);
+        let alignment: Alignment = infer_alignment(&fields, pad, dtype);
+
+        FixedColumn::new(
+            name,
+            start,
+            end - start,
+            dtype,
+            alignment,
+            pad_symbol,
+            is_nullable,
+        )
+    }
+}
+
+/// Get the start and end of each of the tokens of the characters.
+fn tokens(chars: &[char]) -> Vec<(usize, usize)> {
+    let mut tokens: Vec<(usize, usize)> = Vec::new();
+    let mut start: Option<usize> = None;
+    let mut previous: CharClass = CharClass::Space;
+
+    for idx in 0..chars.len() {
+        let class: CharClass = CharClass::of(chars, idx);
+        if let (Some(s), true) = (start, is_split(previous, class)) {
+            tokens.push((s, idx));
+            start = None;
+        }
+        if start.is_none() && class != CharClass::Space {
+            start = Some(idx);
+        }
+        previous = class;
+    }
+    if let Some(s) = start {
+        tokens.push((s, chars.len()));
+    }
+
+    tokens
+}
+
+/// Infer the pad symbol of a column as the symbol that most fields which are not full are
+/// padded with, preferring whitespace. A hyphen directly followed by a digit is the sign of a
+/// number and not padding.
+fn infer_pad_symbol(fields: &[&[char]]) -> Symbol {
+    let is_sign = |field: &[char]| -> bool {
+        field.first() == Some(&'-') && field.get(1).is_some_and(|c| c.is_ascii_digit())
+    };
+    let n_padded = |symbol: Symbol| -> usize {
+        let pad: char = char::from(symbol);
+        fields
+            .iter()
+            .filter(|f| (f.first() == Some(&pad) && !is_sign(f)) || f.last() == Some(&pad))
+            .count()
+    };
+
+    PAD_SYMBOLS
+        .iter()
+        .copied()
+        .fold(
+            (Symbol::Whitespace, 0),
+            |(best, n_best), symbol| match n_padded(symbol) {
+                n if n > n_best => (symbol, n),
+                _ => (best, n_best),
+            },
+        )
+        .0
+}
+
+/// Infer the narrowest datatype that all of the (non-empty) values can be parsed as.
+fn infer_dtype(values: &[&str]) -> DataType {
+    if values.is_empty() {
+        DataType::Utf8
+    } else if values.iter().all(|v| v.parse::<bool>().is_ok()) {
+        DataType::Boolean
+    } else if values.iter().all(|v| v.parse::<i32>().is_ok()) {
+        DataType::Int32
+    } else if values.iter().all(|v| v.parse::<i64>().is_ok()) {
+        DataType::Int64
+    } else if values
+        .iter()
+        .all(|v| v.parse::<f64>().is_ok_and(|f| f.is_finite()))
+    {
+        DataType::Float64
+    } else {
+        DataType::Utf8
+    }
+}
+
+/// Infer the alignment of a column from which side of its fields the padding is on. Fields
+/// without padding on either side do not count, and ties go to right alignment for numeric
+/// columns and to left alignment otherwise.
+fn infer_alignment(fields: &[&[char]], pad: char, dtype: DataType) -> Alignment {
+    let (mut n_left, mut n_right, mut n_center): (usize, usize, usize) = (0, 0, 0);
+    for field in fields {
+        match (field.first() == Some(&pad), field.last() == Some(&pad)) {
+            (false, true) => n_left += 1,
+            (true, false) => n_right += 1,
+            (true, true) if field.iter().any(|c| *c != pad) => n_center += 1,
+            _ => (),
+        }
+    }
+
+    let is_numeric: bool = !matches!(dtype, DataType::Utf8 | DataType::Boolean);
+    if n_center > n_left.max(n_right) {
+        Alignment::Center
+    } else if n_right > n_left || (n_right == n_left && is_numeric) {
+        Alignment::Right
+    } else {
+        Alignment::Left
+    }
+}
+
+#[cfg(test)]
+mod tests_infer {
+    use super::*;
+
+    fn records() -> Vec<String> {
+        [
+            "  1Anna  Malmö     12.50true ",
+            " 42Karl  Umeå      -3.25false",
+            "317Eva   Göteborg1234.00true ",
+            "  8Jon   Luleå      0.75     ",
+            " 99Ida   Kiruna    56.10false",
+        ]
+        .iter()
+        .map(|r| r.to_string())
+        .collect()
+    }
+
+    #[test]
+    fn test_infer_schema() {
+        let schema: FixedSchema = Sample::new("people", &records()).try_into_schema().unwrap();
+        assert_eq!("people", schema.name());
+        assert_eq!(29, schema.row_length());
+        assert_eq!(vec![0, 3, 9, 17, 24], schema.column_offsets());
+        assert_eq!(
+            vec![
+                DataType::Int32,
+                DataType::Utf8,
+                DataType::Utf8,
+                DataType::Float64,
+                DataType::Boolean,
+            ],
+            schema.dtypes(),
+        );
+
+        let columns: &Vec<FixedColumn> = schema.columns();
+        assert_eq!(Alignment::Right, columns[0].alignment());
+        assert_eq!(Alignment::Left, columns[1].alignment());
+        assert_eq!(Alignment::Right, columns[3].alignment());
+        assert_eq!(Alignment::Left, columns[4].alignment());
+        assert!(!columns[3].is_nullable());
+        assert!(columns[4].is_nullable());
+        assert!(schema.try_validate().is_ok());
+    }
+
+    #[test]
+    fn test_infer_pad_symbol() {
+        let records: Vec<String> = vec!["0001AB**".to_string(), "0002C***".to_string()];
+        let schema: FixedSchema = Sample::new("codes", &records).try_into_schema().unwrap();
+
+        assert_eq!(vec![4, 4], schema.column_lengths());
+        assert_eq!(DataType::Int32, schema.columns()[0].dtype());
+        assert_eq!(Symbol::Asterisk, schema.columns()[1].pad_symbol());
+        assert_eq!(Alignment::Left, schema.columns()[1].alignment());
+    }
+
+    #[test]
+    fn test_infer_schema_from_mocked_file() {
+        let mut path: PathBuf = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        path.push("res/test_valid_schema.json");
+        let expected: FixedSchema = FixedSchema::from_path(path).unwrap();
+
+        let mut path: PathBuf = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        path.push("res/test_valid_schema_mocked.flf");
+        let schema: FixedSchema = Sample::from_path(path, 100)
+            .unwrap()
+            .try_into_schema()
+            .unwrap();
+
+        assert_eq!(expected.row_length(), schema.row_length());
+        assert_eq!(expected.column_offsets(), schema.column_offsets());
+        assert_eq!(
+            expected
+                .columns()
+                .iter()
+                .map(|c| (c.alignment(), c.pad_symbol()))
+                .collect::<Vec<(Alignment, Symbol)>>(),
+            schema
+                .columns()
+                .iter()
+                .map(|c| (c.alignment(), c.pad_symbol()))
+                .collect::<Vec<(Alignment, Symbol)>>(),
+        );
+        assert_eq!(DataType::Boolean, schema.columns()[3].dtype());
+        assert_eq!(DataType::Float64, schema.columns()[4].dtype());
+    }
+
+    #[test]
+    fn test_infer_schema_without_records() {
+        assert!(Sample::new("empty", &[]).try_into_schema().is_err());
+    }
+}
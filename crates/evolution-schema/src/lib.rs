@@ -22,8 +22,14 @@
 // SOFTWARE.
 //
 // File created: 2024-05-24
-// Last updated: 2024-06-01
+// Last updated: 2026-10-18
 //
 
 pub mod column;
+pub mod copybook;
+pub mod detect;
+pub mod infer;
+pub mod multi;
+pub mod profile;
+pub mod record;
 pub mod schema;
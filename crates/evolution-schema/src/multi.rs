@@ -0,0 +1,227 @@
+//
+// MIT License
+//
+// Copyright (c) 2026 Firelink Data
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//
+// File created: 2026-10-18
+// Last updated: 2026-10-18
+//
+
+use evolution_builder::builder::{MultiParquetBuilder, ParquetBuilder};
+use evolution_common::error::{Result, SetupError};
+use serde::{Deserialize, Serialize};
+
+use std::collections::{BTreeMap, HashSet};
+use std::fs;
+use std::path::PathBuf;
+
+use crate::schema::FixedSchema;
+
+/// The position of the record type code (the discriminator) in each record.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq)]
+pub struct Discriminator {
+    /// The offset of the discriminator in the record (in number of runes).
+    offset: usize,
+    /// The length of the discriminator (in number of runes).
+    length: usize,
+}
+
+impl Discriminator {
+    /// Create a new [`Discriminator`] from the provided field values.
+    pub fn new(offset: usize, length: usize) -> Self {
+        Self { offset, length }
+    }
+
+    /// Get the offset of the discriminator (in number of runes).
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Get the length of the discriminator (in number of runes).
+    pub fn length(&self) -> usize {
+        self.length
+    }
+}
+
+/// Representation of the schema for a fixed-length file (.flf) which interleaves several
+/// record layouts, e.g., `01` for customers and `02` for accounts. The layout of each record
+/// is given by the record type code found at the position of the discriminator, which is
+/// mapped to the [`FixedSchema`] of that layout.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct MultiFixedSchema {
+    /// The symbolic name of the schema.
+    name: String,
+    /// The version of the schema.
+    version: usize,
+    /// The position of the record type code in each record.
+    discriminator: Discriminator,
+    /// The layout of the records for each record type code.
+    layouts: BTreeMap<String, FixedSchema>,
+}
+
+impl MultiFixedSchema {
+    /// Create a new [`MultiFixedSchema`] from the provided field values.
+    pub fn new(
+        name: String,
+        version: usize,
+        discriminator: Discriminator,
+        layouts: BTreeMap<String, FixedSchema>,
+    ) -> Self {
+        Self {
+            name,
+            version,
+            discriminator,
+            layouts,
+        }
+    }
+
+    /// Create a new [`MultiFixedSchema`] by reading a .json file at the provided path.
+    ///
+    /// # Errors
+    /// If the file could not be read or deserialized, see [`FixedSchema::from_path`] for
+    /// specifics, or if the schema could not be validated, see [`MultiFixedSchema::try_validate`].
+    pub fn from_path(path: PathBuf) -> Result<Self> {
+        let schema: Self = serde_json::from_slice(&fs::read(path)?)?;
+        schema.try_validate()?;
+        Ok(schema)
+    }
+
+    /// Try and validate the schema and each of its layouts.
+    ///
+    /// # Errors
+    /// This function might return an error for the following reasons:
+    /// * If there are no layouts.
+    /// * If the discriminator is empty, or does not fit within the rows of every layout.
+    /// * If two layouts have the same name, since the name identifies the output of a layout.
+    /// * If any of the layouts could not be validated, see [`FixedSchema::try_validate`].
+    pub fn try_validate(&self) -> Result<()> {
+        if self.layouts.is_empty() {
+            return Err(Box::new(SetupError::new(
+                "The schema has to contain at least one layout, exiting...",
+            )));
+        }
+
+        if self.discriminator.length == 0 {
+            return Err(Box::new(SetupError::new(
+                "The discriminator has to be at least one rune long, exiting...",
+            )));
+        }
+
+        let mut names: HashSet<&str> = HashSet::with_capacity(self.layouts.len());
+        for (code, layout) in self.layouts.iter() {
+            if self.discriminator.offset + self.discriminator.length > layout.row_length() {
+                return Err(Box::new(SetupError::new(&format!(
+                    "The discriminator does not fit within the rows of layout '{}', exiting...",
+                    code,
+                ))));
+            }
+
+            if !names.insert(layout.name()) {
+                return Err(Box::new(SetupError::new(&format!(
+                    "The name '{}' is used by more than one layout, exiting...",
+                    layout.name(),
+                ))));
+            }
+
+            layout.try_validate()?;
+        }
+
+        Ok(())
+    }
+
+    /// Get the name of the schema.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Get the version of the schema.
+    pub fn version(&self) -> usize {
+        self.version
+    }
+
+    /// Get the position of the record type code in each record.
+    pub fn discriminator(&self) -> Discriminator {
+        self.discriminator
+    }
+
+    /// Get the layout of the records for each record type code.
+    pub fn layouts(&self) -> &BTreeMap<String, FixedSchema> {
+        &self.layouts
+    }
+
+    /// Consume the [`MultiFixedSchema`] and produce a [`MultiParquetBuilder`] from it, with
+    /// one [`ParquetBuilder`] for each of the layouts.
+    pub fn into_builder(self) -> MultiParquetBuilder {
+        let builders = self
+            .layouts
+            .into_iter()
+            .map(|(code, layout)| (code, layout.into_builder::<ParquetBuilder>()))
+            .collect::<BTreeMap<String, ParquetBuilder>>();
+
+        MultiParquetBuilder::new(
+            self.discriminator.offset,
+            self.discriminator.length,
+            builders,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests_multi {
+    use super::*;
+    use arrow::array::RecordBatch;
+
+    #[test]
+    fn test_deserialize_multi_schema_from_file() {
+        let mut path: PathBuf = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        path.push("res/test_valid_multi_schema.json");
+
+        let schema: MultiFixedSchema = MultiFixedSchema::from_path(path).unwrap();
+        assert_eq!("ValidTestMultiSchema", schema.name());
+        assert_eq!(Discriminator::new(0, 2), schema.discriminator());
+        assert_eq!(2, schema.layouts().len());
+        assert_eq!("customer", schema.layouts()["01"].name());
+        assert_eq!("account", schema.layouts()["02"].name());
+    }
+
+    #[test]
+    fn test_route_records_to_layouts() {
+        let mut path: PathBuf = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        path.push("res/test_valid_multi_schema.json");
+
+        let mut builder: MultiParquetBuilder =
+            MultiFixedSchema::from_path(path).unwrap().into_builder();
+        let buffer: &[u8] =
+            "01    1Åsa      \n02    1   1050\n02    1    -20\n\n01    2Nils     \n".as_bytes();
+        builder.try_build_from_slice(buffer).unwrap();
+
+        let batches: BTreeMap<String, RecordBatch> = builder
+            .builders_mut()
+            .map(|(code, b)| (code.clone(), b.try_finish().unwrap()))
+            .collect();
+        assert_eq!(2, batches["01"].num_rows());
+        assert_eq!(2, batches["02"].num_rows());
+
+        assert!(builder
+            .try_build_from_slice("03    1   1050\n".as_bytes())
+            .is_err());
+    }
+}
@@ -0,0 +1,483 @@
+//
+// MIT License
+//
+// Copyright (c) 2026 Firelink Data
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//
+// File created: 2026-10-18
+// Last updated: 2026-10-18
+//
+
+//! Profiling of the values of the columns of a fixed-length file, from a sample of its records,
+//! e.g. to mock synthetic copies of a file which itself can not be shared.
+//!
+//! The profile of every column, including the fields of struct columns, has:
+//!
+//! * the rate of blank fields, i.e. fields of only padding, which are read as nulls,
+//! * the distribution of the lengths (in characters) of the values,
+//! * the minimum and maximum values, compared as numbers for numeric columns, as dates for
+//!   date columns, and as strings otherwise, ignoring values which can not be parsed,
+//! * the most frequent values, with their counts, and the number of distinct values,
+//! * and the distribution of the [`CharClass`] of the characters of the values.
+//!
+//! Note that the minimum, maximum and most frequent values are values of the profiled file, so
+//! a profile should be treated like the file itself.
+
+use chrono::NaiveDate;
+use evolution_common::datatype::DataType;
+use evolution_common::error::{Result, SetupError};
+use serde::{Deserialize, Serialize};
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+
+use crate::column::FixedColumn;
+use crate::detect::{field_values, read_body_records};
+use crate::schema::FixedSchema;
+
+/// The default number of most frequent values of each column to profile.
+pub static DEFAULT_TOP_K: usize = 10;
+
+/// The class of a character of a value.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum CharClass {
+    Upper,
+    Lower,
+    Digit,
+    Whitespace,
+    Punctuation,
+    /// Any other character, e.g. letters without case or emoji.
+    Other,
+}
+
+impl CharClass {
+    /// Get the class of the character.
+    pub fn of(c: char) -> Self {
+        match c {
+            c if c.is_uppercase() => Self::Upper,
+            c if c.is_lowercase() => Self::Lower,
+            c if c.is_ascii_digit() => Self::Digit,
+            c if c.is_whitespace() => Self::Whitespace,
+            c if c.is_ascii_punctuation() => Self::Punctuation,
+            _ => Self::Other,
+        }
+    }
+}
+
+/// One of the most frequent values of a column, with the number of times it occurs.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct TopValue {
+    value: String,
+    count: usize,
+}
+
+impl TopValue {
+    /// Get the value.
+    pub fn value(&self) -> &String {
+        &self.value
+    }
+
+    /// Get the number of times the value occurs.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+}
+
+/// The statistics of the values of a column.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ColumnProfile {
+    name: String,
+    dtype: DataType,
+    /// The number of fields, i.e. one per element of the column in each record.
+    n_values: usize,
+    /// The rate of blank fields.
+    null_rate: f64,
+    /// The number of distinct values, besides blank fields.
+    n_distinct: usize,
+    min: Option<String>,
+    max: Option<String>,
+    /// The number of values of each length.
+    lengths: BTreeMap<usize, usize>,
+    /// The most frequent values, by descending count.
+    top_values: Vec<TopValue>,
+    /// The number of characters of each class.
+    char_classes: BTreeMap<CharClass, usize>,
+}
+
+impl ColumnProfile {
+    /// Get the name of the column.
+    pub fn name(&self) -> &String {
+        &self.name
+    }
+
+    /// Get the datatype of the column.
+    pub fn dtype(&self) -> DataType {
+        self.dtype
+    }
+
+    /// Get the number of fields of the column.
+    pub fn n_values(&self) -> usize {
+        self.n_values
+    }
+
+    /// Get the rate of blank fields of the column.
+    pub fn null_rate(&self) -> f64 {
+        self.null_rate
+    }
+
+    /// Get the number of distinct values of the column.
+    pub fn n_distinct(&self) -> usize {
+        self.n_distinct
+    }
+
+    /// Get the minimum value of the column, if any.
+    pub fn min(&self) -> Option<&str> {
+        self.min.as_deref()
+    }
+
+    /// Get the maximum value of the column, if any.
+    pub fn max(&self) -> Option<&str> {
+        self.max.as_deref()
+    }
+
+    /// Get the number of values of each length (in characters).
+    pub fn lengths(&self) -> &BTreeMap<usize, usize> {
+        &self.lengths
+    }
+
+    /// Get the most frequent values of the column, by descending count.
+    pub fn top_values(&self) -> &[TopValue] {
+        &self.top_values
+    }
+
+    /// Get the number of characters of the values of each class.
+    pub fn char_classes(&self) -> &BTreeMap<CharClass, usize> {
+        &self.char_classes
+    }
+
+    /// Whether all of the distinct values of the column are among its most frequent values,
+    /// e.g. for columns of codes or flags.
+    pub fn is_categorical(&self) -> bool {
+        !self.top_values.is_empty() && self.n_distinct <= self.top_values.len()
+    }
+}
+
+/// The statistics of the values of each of the columns of a fixed-length file.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Profile {
+    /// The number of profiled records.
+    n_records: usize,
+    columns: Vec<ColumnProfile>,
+}
+
+impl Profile {
+    /// Create a new [`Profile`] by reading a .json file at the provided path.
+    ///
+    /// # Errors
+    /// If the file could not be read, or if it is not a valid profile.
+    pub fn from_path(path: PathBuf) -> Result<Self> {
+        let profile: Self = serde_json::from_slice(&fs::read(path)?)?;
+        Ok(profile)
+    }
+
+    /// Try and serialize the profile as pretty printed json.
+    ///
+    /// # Errors
+    /// If the profile could not be serialized.
+    pub fn try_to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// Get the number of profiled records.
+    pub fn n_records(&self) -> usize {
+        self.n_records
+    }
+
+    /// Get the profiles of the columns, with the fields of struct columns after their column.
+    pub fn columns(&self) -> &[ColumnProfile] {
+        &self.columns
+    }
+}
+
+/// A profiler of the values of the columns of a schema, from a sample of the body records of a
+/// fixed-length file.
+#[derive(Clone, Debug)]
+pub struct Profiler {
+    /// The characters of each of the sampled records.
+    records: Vec<Vec<char>>,
+    /// The number of most frequent values of each column to profile.
+    top_k: usize,
+}
+
+impl Profiler {
+    /// Create a new [`Profiler`] from the provided body records.
+    pub fn new(records: &[String]) -> Self {
+        Self {
+            records: records
+                .iter()
+                .map(|r| r.chars().collect::<Vec<char>>())
+                .collect(),
+            top_k: DEFAULT_TOP_K,
+        }
+    }
+
+    /// Try and read at most `n_records` body records from a file with the provided schema,
+    /// skipping the lines and the header record before them, and leaving out the trailer
+    /// record if the file ends within the sample.
+    ///
+    /// # Errors
+    /// If the file could not be opened, or any of the sampled records is not valid UTF-8.
+    pub fn from_path(path: PathBuf, schema: &FixedSchema, n_records: usize) -> Result<Self> {
+        Ok(Self::new(&read_body_records(path, schema, n_records)?))
+    }
+
+    /// Set the number of most frequent values of each column to profile.
+    pub fn with_top_k(mut self, top_k: usize) -> Self {
+        self.top_k = top_k;
+        self
+    }
+
+    /// Get the number of sampled records.
+    pub fn n_records(&self) -> usize {
+        self.records.len()
+    }
+
+    /// Try and profile the values of each of the columns of the schema.
+    ///
+    /// # Errors
+    /// If there are no sampled records.
+    pub fn try_profile(&self, schema: &FixedSchema) -> Result<Profile> {
+        if self.records.is_empty() {
+            return Err(Box::new(SetupError::new(
+                "Can not profile any columns without any sampled records, exiting...",
+            )));
+        }
+
+        let mut columns: Vec<ColumnProfile> = Vec::new();
+        for column in schema.iter() {
+            self.profile_column(column, &mut columns);
+        }
+
+        Ok(Profile {
+            n_records: self.records.len(),
+            columns,
+        })
+    }
+
+    /// Profile the column, or its fields if it is a struct column, appending the profiles.
+    fn profile_column(&self, column: &FixedColumn, profiles: &mut Vec<ColumnProfile>) {
+        if column.dtype() == DataType::Struct {
+            for field in column.fields().iter() {
+                self.profile_column(field, profiles);
+            }
+            return;
+        }
+
+        let values: Vec<String> = field_values(&self.records, column);
+
+        // The values are counted in order, so that the profile of a file is always the same.
+        let mut counts: BTreeMap<&str, usize> = BTreeMap::new();
+        let mut lengths: BTreeMap<usize, usize> = BTreeMap::new();
+        let mut char_classes: BTreeMap<CharClass, usize> = BTreeMap::new();
+        let mut n_blank: usize = 0;
+        for value in values.iter().map(|v| v.as_str()) {
+            if value.is_empty() {
+                n_blank += 1;
+                continue;
+            }
+
+            *counts.entry(value).or_default() += 1;
+            *lengths.entry(value.chars().count()).or_default() += 1;
+            for c in value.chars() {
+                *char_classes.entry(CharClass::of(c)).or_default() += 1;
+            }
+        }
+
+        let (min, max) = match column.dtype() {
+            DataType::Int16
+            | DataType::Int32
+            | DataType::Int64
+            | DataType::Float16
+            | DataType::Float32
+            | DataType::Float64
+            | DataType::Decimal128 => min_max(counts.keys(), |v| {
+                v.parse::<f64>().ok().filter(|f| f.is_finite())
+            }),
+            DataType::Date32 => min_max(counts.keys(), |v| {
+                NaiveDate::parse_from_str(v, column.date_format()).ok()
+            }),
+            _ => min_max(counts.keys(), |v| Some(v.to_string())),
+        };
+
+        let mut top_values: Vec<TopValue> = counts
+            .iter()
+            .map(|(value, count)| TopValue {
+                value: value.to_string(),
+                count: *count,
+            })
+            .collect();
+        // The sort is stable, so values of the same count stay in order.
+        top_values.sort_by_key(|v| std::cmp::Reverse(v.count));
+        top_values.truncate(self.top_k);
+
+        profiles.push(ColumnProfile {
+            name: column.name().clone(),
+            dtype: column.dtype(),
+            n_values: values.len(),
+            null_rate: match values.is_empty() {
+                true => 0.0,
+                false => n_blank as f64 / values.len() as f64,
+            },
+            n_distinct: counts.len(),
+            min,
+            max,
+            lengths,
+            top_values,
+            char_classes,
+        });
+    }
+}
+
+/// Find the minimum and maximum of the values, by the keys of the values which have one.
+fn min_max<'a, K: PartialOrd + Clone>(
+    values: impl Iterator<Item = &'a &'a str>,
+    key: impl Fn(&str) -> Option<K>,
+) -> (Option<String>, Option<String>) {
+    let mut min: Option<(K, &str)> = None;
+    let mut max: Option<(K, &str)> = None;
+    for value in values {
+        let k: K = match key(value) {
+            Some(k) => k,
+            None => continue,
+        };
+
+        if min.as_ref().is_none_or(|(m, _)| k < *m) {
+            min = Some((k.clone(), value));
+        }
+        if max.as_ref().is_none_or(|(m, _)| k > *m) {
+            max = Some((k, value));
+        }
+    }
+
+    (
+        min.map(|(_, v)| v.to_string()),
+        max.map(|(_, v)| v.to_string()),
+    )
+}
+
+#[cfg(test)]
+mod tests_profile {
+    use super::*;
+    use padder::{Alignment, Symbol};
+
+    fn schema() -> FixedSchema {
+        let column = |name: &str, offset: usize, length: usize, dtype: DataType| {
+            FixedColumn::new(
+                name.to_string(),
+                offset,
+                length,
+                dtype,
+                Alignment::Right,
+                Symbol::Whitespace,
+                true,
+            )
+        };
+
+        FixedSchema::new(
+            "profile".to_string(),
+            1,
+            vec![
+                column("id", 0, 4, DataType::Int32),
+                column("date", 4, 8, DataType::Date32).with_format("%Y%m%d".to_string()),
+                column("code", 12, 6, DataType::Utf8),
+            ],
+        )
+    }
+
+    fn records() -> Vec<String> {
+        [
+            "   920240131 AB-12",
+            "  1020231201 AB-12",
+            "    20200229  Cd 7",
+            "  -1            Åx",
+        ]
+        .iter()
+        .map(|r| r.to_string())
+        .collect()
+    }
+
+    #[test]
+    fn test_profile_columns() {
+        let profile: Profile = Profiler::new(&records())
+            .with_top_k(2)
+            .try_profile(&schema())
+            .unwrap();
+        assert_eq!(4, profile.n_records());
+
+        let columns: &[ColumnProfile] = profile.columns();
+        assert_eq!(
+            vec!["id", "date", "code"],
+            columns.iter().map(|c| c.name()).collect::<Vec<_>>(),
+        );
+
+        // Numbers are compared as numbers, not as strings.
+        let id: &ColumnProfile = &columns[0];
+        assert_eq!((Some("-1"), Some("10")), (id.min(), id.max()));
+        assert_eq!(0.25, id.null_rate());
+        assert_eq!(3, id.n_distinct());
+        assert!(!id.is_categorical());
+        assert_eq!(BTreeMap::from([(1, 1), (2, 2)]), id.lengths().clone(),);
+
+        let date: &ColumnProfile = &columns[1];
+        assert_eq!(
+            (Some("20200229"), Some("20240131")),
+            (date.min(), date.max()),
+        );
+        assert_eq!(0.25, date.null_rate());
+        assert_eq!(4, date.n_values());
+
+        let code: &ColumnProfile = &columns[2];
+        assert_eq!(3, code.n_distinct());
+        assert_eq!(
+            vec![("AB-12", 2), ("Cd 7", 1)],
+            code.top_values()
+                .iter()
+                .map(|v| (v.value().as_str(), v.count()))
+                .collect::<Vec<_>>(),
+        );
+        assert_eq!(
+            BTreeMap::from([
+                (CharClass::Upper, 6),
+                (CharClass::Lower, 2),
+                (CharClass::Digit, 5),
+                (CharClass::Whitespace, 1),
+                (CharClass::Punctuation, 2),
+            ]),
+            code.char_classes().clone(),
+        );
+    }
+
+    #[test]
+    fn test_profile_without_records() {
+        assert!(Profiler::new(&[]).try_profile(&schema()).is_err());
+    }
+}
@@ -0,0 +1,178 @@
+//
+// MIT License
+//
+// Copyright (c) 2026 Firelink Data
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//
+// File created: 2026-10-18
+// Last updated: 2026-10-18
+//
+
+use evolution_common::datatype::DataType;
+use evolution_common::error::{Result, SetupError};
+use serde::{Deserialize, Serialize};
+
+use crate::column::FixedColumn;
+
+/// A control sum declared by a header or trailer record, i.e., a field which holds the sum of
+/// a body column over all of the records in the file.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct ControlSum {
+    /// The name of the header or trailer field holding the sum.
+    field: String,
+    /// The name of the body column that is summed.
+    column: String,
+}
+
+impl ControlSum {
+    /// Create a new [`ControlSum`] from the provided field values.
+    pub fn new(field: String, column: String) -> Self {
+        Self { field, column }
+    }
+
+    /// Get the name of the header or trailer field holding the sum.
+    pub fn field(&self) -> &str {
+        &self.field
+    }
+
+    /// Get the name of the body column that is summed.
+    pub fn column(&self) -> &str {
+        &self.column
+    }
+}
+
+/// Representation of the layout of a header or trailer record in a fixed-length file (.flf),
+/// which differs from the layout of the body records. The layout can declare control totals,
+/// i.e., a field holding the number of body records and fields holding the sums of body columns,
+/// which are validated against what was actually converted.
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+pub struct RecordLayout {
+    /// The fields that make up the record.
+    columns: Vec<FixedColumn>,
+    /// The name of the field holding the number of body records, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    record_count: Option<String>,
+    /// The fields holding sums of body columns.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    control_sums: Vec<ControlSum>,
+}
+
+impl RecordLayout {
+    /// Create a new [`RecordLayout`] from the provided fields.
+    pub fn new(columns: Vec<FixedColumn>) -> Self {
+        Self {
+            columns,
+            record_count: None,
+            control_sums: Vec::new(),
+        }
+    }
+
+    /// Set the name of the field holding the number of body records.
+    pub fn with_record_count(mut self, field: String) -> Self {
+        self.record_count = Some(field);
+        self
+    }
+
+    /// Add a field holding the sum of a body column.
+    pub fn with_control_sum(mut self, control_sum: ControlSum) -> Self {
+        self.control_sums.push(control_sum);
+        self
+    }
+
+    /// Get the fields that make up the record.
+    pub fn columns(&self) -> &Vec<FixedColumn> {
+        &self.columns
+    }
+
+    /// Get the name of the field holding the number of body records, if any.
+    pub fn record_count(&self) -> Option<&str> {
+        self.record_count.as_deref()
+    }
+
+    /// Get the fields holding sums of body columns.
+    pub fn control_sums(&self) -> &Vec<ControlSum> {
+        &self.control_sums
+    }
+
+    /// Try and extract the name and (trimmed) value of each field from a single record.
+    ///
+    /// # Errors
+    /// If any of the fields is not valid UTF-8.
+    pub fn try_parse_fields(&self, record: &[u8]) -> Result<Vec<(String, String)>> {
+        self.columns
+            .iter()
+            .map(|c| Ok((c.name().clone(), c.try_extract_field(record)?.to_string())))
+            .collect::<Result<Vec<(String, String)>>>()
+    }
+
+    /// Try and validate that the control totals refer to existing fields of the record, and
+    /// that each control sum refers to a numeric column among the provided body columns.
+    ///
+    /// # Errors
+    /// If any of the referred fields or columns do not exist, or if a summed column is not numeric.
+    pub fn try_validate(&self, body_columns: &[FixedColumn]) -> Result<()> {
+        let fields = self
+            .record_count
+            .iter()
+            .chain(self.control_sums.iter().map(|s| &s.field));
+
+        for field in fields {
+            if !self.columns.iter().any(|c| c.name() == field) {
+                return Err(Box::new(SetupError::new(&format!(
+                    "Control field '{}' does not exist in the record layout, exiting...",
+                    field,
+                ))));
+            }
+        }
+
+        for control_sum in self.control_sums.iter() {
+            match body_columns
+                .iter()
+                .find(|c| c.name() == &control_sum.column)
+            {
+                Some(c) => match c.dtype() {
+                    DataType::Boolean
+                    | DataType::Utf8
+                    | DataType::LargeUtf8
+                    | DataType::Date32
+                    | DataType::Struct => {
+                        return Err(Box::new(SetupError::new(&format!(
+                            "Column '{}' of control sum '{}' is not numeric, exiting...",
+                            control_sum.column, control_sum.field,
+                        ))))
+                    }
+                    _ => (),
+                },
+                None => {
+                    return Err(Box::new(SetupError::new(&format!(
+                        "Column '{}' of control sum '{}' does not exist, exiting...",
+                        control_sum.column, control_sum.field,
+                    ))))
+                }
+            }
+        }
+
+        for column in self.columns.iter() {
+            column.try_validate()?;
+        }
+
+        Ok(())
+    }
+}
@@ -22,19 +22,26 @@
 // SOFTWARE.
 //
 // File created: 2023-11-25
-// Last updated: 2024-10-11
+// Last updated: 2026-10-18
 //
 
 use arrow::datatypes::{Field as ArrowField, Schema as ArrowSchema};
-use evolution_builder::builder::{Builder, ColumnBuilderRef};
+use evolution_builder::alternative::{
+    AlternativeColumnBuilder, DiscriminatingColumnBuilder, SharedField,
+};
+use evolution_builder::builder::{Builder, ColumnBuilderRef, ParquetBuilder};
 use evolution_common::datatype::DataType;
-use evolution_common::error::Result;
+use evolution_common::error::{Result, SetupError};
+use evolution_parser::datatype::IntParser;
 use serde::{Deserialize, Serialize};
 
+use std::collections::HashMap;
 use std::fs;
 use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
 
 use crate::column::FixedColumn;
+use crate::record::RecordLayout;
 
 /// Unified trait for all types of schemas.
 pub trait Schema {}
@@ -49,6 +56,19 @@ pub struct FixedSchema {
     version: usize,
     /// The columns that make up the fixed-length file.
     columns: Vec<FixedColumn>,
+    /// The number of lines to skip at the start of the file, before any header record.
+    #[serde(default, skip_serializing_if = "is_zero")]
+    skip_lines: usize,
+    /// The layout of the header record following the skipped lines, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    header: Option<RecordLayout>,
+    /// The layout of the trailer record at the end of the file, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    trailer: Option<RecordLayout>,
+}
+
+fn is_zero(value: &usize) -> bool {
+    *value == 0
 }
 
 impl FixedSchema {
@@ -58,9 +78,39 @@ impl FixedSchema {
             name,
             version,
             columns,
+            skip_lines: 0,
+            header: None,
+            trailer: None,
         }
     }
 
+    /// Set the number of lines to skip at the start of the file, before any header record.
+    pub fn with_skip_lines(mut self, skip_lines: usize) -> Self {
+        self.skip_lines = skip_lines;
+        self
+    }
+
+    /// Set the layout of the header record following the skipped lines.
+    pub fn with_header(mut self, header: RecordLayout) -> Self {
+        self.header = Some(header);
+        self
+    }
+
+    /// Set the layout of the trailer record at the end of the file.
+    pub fn with_trailer(mut self, trailer: RecordLayout) -> Self {
+        self.trailer = Some(trailer);
+        self
+    }
+
+    /// Remove the skipped lines, header, and trailer, e.g., to read a range of the body records
+    /// of a file whose other lines have already been split off.
+    pub fn without_envelope(mut self) -> Self {
+        self.skip_lines = 0;
+        self.header = None;
+        self.trailer = None;
+        self
+    }
+
     /// Create a new [`FixedSchema`] by reading a .json file at the provided path.
     ///
     /// # Errors
@@ -74,6 +124,9 @@ impl FixedSchema {
     /// * [`AlreadyExists`]: `create_new` was specified and the file already exists.
     /// * [`InvalidInput`]: Invalid combinations of open options (truncate without write access, no access mode set, etc.).
     ///
+    /// It will also return a [`SetupError`] if any of the columns could not be validated, see
+    /// [`FixedSchema::try_validate`] for specifics.
+    ///
     /// # Examples
     ///
     /// ```no_run
@@ -90,11 +143,107 @@ impl FixedSchema {
     /// [`InvalidInput`]: io::ErrorKind::InvalidInput
     /// [`NotFound`]: io::ErrorKind::NotFound
     /// [`PermissionDenied`]: io::ErrorKind::PermissionDenied
+    /// [`SetupError`]: evolution_common::error::SetupError
     pub fn from_path(path: PathBuf) -> Result<Self> {
         let schema: Self = serde_json::from_slice(&fs::read(path)?)?;
+        schema.try_validate()?;
         Ok(schema)
     }
 
+    /// Try and serialize the schema as pretty printed json.
+    ///
+    /// # Errors
+    /// If the schema could not be serialized.
+    pub fn try_to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// Try and validate each of the columns in the schema, and the header and trailer layouts.
+    ///
+    /// # Errors
+    /// If any of the columns could not be validated, see [`FixedColumn::try_validate`], if the
+    /// number of elements of a list column does not depend on an earlier integer column which
+    /// does not repeat, if the condition of an alternative is not on an earlier column which
+    /// does not repeat and is neither a struct nor an alternative, or has values which are not
+    /// valid values of that column, see [`FixedColumn::is_valid_value`], if a column overlaps the
+    /// previous column without both being alternatives of the same region, or if the header
+    /// or trailer layout could not be validated, see [`RecordLayout::try_validate`].
+    pub fn try_validate(&self) -> Result<()> {
+        for (idx, column) in self.columns.iter().enumerate() {
+            column.try_validate()?;
+
+            if let Some(name) = column.condition().map(|c| c.column()) {
+                let is_valid: bool = self.columns[..idx].iter().any(|c| {
+                    c.name() == name
+                        && c.occurs().is_none()
+                        && c.condition().is_none()
+                        && c.dtype() != DataType::Struct
+                });
+
+                if !is_valid {
+                    return Err(Box::new(SetupError::new(&format!(
+                        "Column '{}' is chosen by '{}', which has to be an earlier column that does not repeat and is neither a struct nor an alternative, exiting...",
+                        column.name(),
+                        name,
+                    ))));
+                }
+
+                let discriminator: &FixedColumn = self.columns[..idx]
+                    .iter()
+                    .find(|c| c.name() == name)
+                    .unwrap();
+                if let Some(value) = column
+                    .condition()
+                    .and_then(|c| c.values().iter().find(|v| !discriminator.is_valid_value(v)))
+                {
+                    return Err(Box::new(SetupError::new(&format!(
+                        "Column '{}' is chosen by the value '{}', which is not a valid value of '{}', exiting...",
+                        column.name(),
+                        value,
+                        name,
+                    ))));
+                }
+            }
+
+            if let Some(previous) = idx.checked_sub(1).map(|i| &self.columns[i]) {
+                if column.offset() < previous.offset() + previous.length()
+                    && !self.is_redefinition(idx)
+                {
+                    return Err(Box::new(SetupError::new(&format!(
+                        "Column '{}' overlaps column '{}', which is only allowed for alternatives of the same region, exiting...",
+                        column.name(),
+                        previous.name(),
+                    ))));
+                }
+            }
+
+            if let Some(name) = column.occurs().and_then(|o| o.depending_on()) {
+                let is_valid: bool = self.columns[..idx].iter().any(|c| {
+                    c.name() == name
+                        && c.occurs().is_none()
+                        && matches!(
+                            c.dtype(),
+                            DataType::Int16 | DataType::Int32 | DataType::Int64
+                        )
+                });
+
+                if !is_valid {
+                    return Err(Box::new(SetupError::new(&format!(
+                        "Column '{}' depends on '{}', which has to be an earlier integer column that does not repeat, exiting...",
+                        column.name(),
+                        name,
+                    ))));
+                }
+            }
+        }
+
+        for layout in self.header.iter().chain(self.trailer.iter()) {
+            layout.try_validate(&self.columns)?;
+        }
+
+        Ok(())
+    }
+
     /// Get the name of the schema.
     pub fn name(&self) -> &str {
         &self.name
@@ -110,14 +259,59 @@ impl FixedSchema {
         &self.columns
     }
 
+    /// Get the number of lines to skip at the start of the file, before any header record.
+    pub fn skip_lines(&self) -> usize {
+        self.skip_lines
+    }
+
+    /// Get the layout of the header record, if any.
+    pub fn header(&self) -> Option<&RecordLayout> {
+        self.header.as_ref()
+    }
+
+    /// Get the layout of the trailer record, if any.
+    pub fn trailer(&self) -> Option<&RecordLayout> {
+        self.trailer.as_ref()
+    }
+
     /// Get the number of columns in the schema.
     pub fn num_columns(&self) -> usize {
         self.columns.len()
     }
 
-    /// Get the total length of a fixed-length row.
+    /// Get the total length of a fixed-length row, where the region of several alternatives
+    /// is only counted once.
     pub fn row_length(&self) -> usize {
-        self.columns.iter().map(|c| c.length()).sum()
+        self.columns
+            .iter()
+            .enumerate()
+            .filter(|(idx, _)| !self.is_redefinition(*idx))
+            .map(|(_, c)| c.length())
+            .sum()
+    }
+
+    /// Get whether or not the column with the provided index is an alternative layout of the
+    /// same region as the previous column, i.e., both have conditions, offsets and lengths.
+    pub fn is_redefinition(&self, idx: usize) -> bool {
+        match (idx.checked_sub(1), self.columns.get(idx)) {
+            (Some(previous), Some(column)) => {
+                let previous: &FixedColumn = &self.columns[previous];
+                column.condition().is_some()
+                    && previous.condition().is_some()
+                    && column.offset() == previous.offset()
+                    && column.length() == previous.length()
+            }
+            _ => false,
+        }
+    }
+
+    /// Get the alternative layouts of the region starting with the column with the provided
+    /// index, which is only the column itself unless it is followed by redefinitions.
+    pub fn alternatives(&self, idx: usize) -> &[FixedColumn] {
+        let n_redefinitions: usize = (idx + 1..self.columns.len())
+            .take_while(|i| self.is_redefinition(*i))
+            .count();
+        &self.columns[idx..=idx + n_redefinitions]
     }
 
     /// Get the names of the columns.
@@ -128,6 +322,27 @@ impl FixedSchema {
             .collect::<Vec<&String>>()
     }
 
+    /// Try and find the indices of the columns with the provided names.
+    ///
+    /// # Errors
+    /// If any of the names does not match a column in the schema.
+    pub fn try_column_indices(&self, names: &[String]) -> Result<Vec<usize>> {
+        names
+            .iter()
+            .map(|name| {
+                self.columns
+                    .iter()
+                    .position(|c| c.name() == name)
+                    .ok_or_else(|| {
+                        Box::new(SetupError::new(&format!(
+                            "Column '{}' does not exist in schema '{}', exiting...",
+                            name, self.name,
+                        ))) as Box<dyn std::error::Error>
+                    })
+            })
+            .collect::<Result<Vec<usize>>>()
+    }
+
     /// Get the offset indices for each column (in number of runes).
     pub fn column_offsets(&self) -> Vec<usize> {
         self.columns
@@ -192,13 +407,172 @@ impl FixedSchema {
     where
         T: Builder,
     {
-        let column_builders = self
-            .columns
+        let projection: Vec<usize> = (0..self.columns.len()).collect::<Vec<usize>>();
+        T::from(self.column_builders(&projection))
+    }
+
+    /// Create the column builders of the columns with the provided (sorted) indices, where the
+    /// builder of each column that the number of elements of a list column depends on, or that
+    /// alternatives are chosen by, shares its fields with the builders of the list columns and
+    /// the alternatives.
+    fn column_builders(&self, projection: &[usize]) -> Vec<ColumnBuilderRef> {
+        let mut fields: HashMap<&str, SharedField> = HashMap::new();
+        for idx in projection {
+            let column: &FixedColumn = &self.columns[*idx];
+            if let Some(name) = column
+                .occurs()
+                .and_then(|o| o.depending_on())
+                .or(column.condition().map(|c| c.column()))
+            {
+                fields.insert(name, Arc::new(Mutex::new(Vec::new())));
+            }
+        }
+
+        let consumes: Vec<bool> = self.consumes_region(projection);
+
+        projection
+            .iter()
+            .zip(consumes)
+            .map(|(idx, consumes)| {
+                let column: &FixedColumn = &self.columns[*idx];
+                let depending_on: Option<&str> = column.occurs().and_then(|o| o.depending_on());
+
+                let mut builder: ColumnBuilderRef = match (depending_on, column.condition()) {
+                    (Some(name), _) => {
+                        let count_parser: IntParser = self
+                            .columns
+                            .iter()
+                            .find(|c| c.name() == name)
+                            .map(|c| c.int_parser())
+                            .unwrap_or_else(IntParser::new);
+                        column.as_list_column_builder(fields[name].clone(), count_parser)
+                    }
+                    (None, Some(condition)) => Box::new(AlternativeColumnBuilder::new(
+                        column.name().clone(),
+                        column.length(),
+                        condition.values().clone(),
+                        fields[condition.column()].clone(),
+                        consumes,
+                        column.as_column_builder(),
+                    )),
+                    (None, None) => column.as_column_builder(),
+                };
+
+                if let Some(shared) = fields.get(column.name().as_str()) {
+                    builder = Box::new(DiscriminatingColumnBuilder::new(builder, shared.clone()));
+                }
+                builder
+            })
+            .collect::<Vec<ColumnBuilderRef>>()
+    }
+
+    /// Get whether or not each of the columns with the provided (sorted) indices consumes its
+    /// region when built, which all but the last of the built alternatives of a region do not.
+    fn consumes_region(&self, projection: &[usize]) -> Vec<bool> {
+        projection
+            .iter()
+            .enumerate()
+            .map(|(i, idx)| {
+                projection
+                    .get(i + 1)
+                    .is_none_or(|next| !(*idx + 1..=*next).all(|j| self.is_redefinition(j)))
+            })
+            .collect::<Vec<bool>>()
+    }
+
+    /// Get the names of the columns which the number of elements of the list columns, or the
+    /// alternatives, with the provided indices depend on. These columns have to be built for
+    /// the list columns and alternatives to be.
+    pub fn dependency_column_names(&self, projection: &[usize]) -> Vec<String> {
+        projection
+            .iter()
+            .filter_map(|idx| self.columns.get(*idx))
+            .filter_map(|c| {
+                c.occurs()
+                    .and_then(|o| o.depending_on())
+                    .or(c.condition().map(|c| c.column()))
+            })
+            .map(|n| n.to_string())
+            .collect::<Vec<String>>()
+    }
+
+    /// Try and sort and deduplicate the provided column indices of a projection.
+    ///
+    /// # Errors
+    /// If the projection is empty or any of the indices are out of bounds for the schema.
+    fn try_sorted_projection(&self, projection: &[usize]) -> Result<Vec<usize>> {
+        if projection.is_empty() {
+            return Err(Box::new(SetupError::new(
+                "The projection has to contain at least one column, exiting...",
+            )));
+        }
+
+        let mut sorted: Vec<usize> = projection.to_vec();
+        sorted.sort_unstable();
+        sorted.dedup();
+
+        match sorted.last() {
+            Some(idx) if *idx >= self.columns.len() => Err(Box::new(SetupError::new(&format!(
+                "Column index {} is out of bounds for a schema with {} columns, exiting...",
+                idx,
+                self.columns.len(),
+            )))),
+            _ => Ok(sorted),
+        }
+    }
+
+    /// Consume the [`FixedSchema`] and try and produce an [`ArrowSchema`] containing only the
+    /// columns with the provided indices. The fields are ordered as they appear in the schema.
+    ///
+    /// # Errors
+    /// If the projection is empty or any of the indices are out of bounds for the schema.
+    pub fn try_into_projected_arrow_schema(self, projection: &[usize]) -> Result<ArrowSchema> {
+        let projection: Vec<usize> = self.try_sorted_projection(projection)?;
+        Ok(self.into_arrow_schema().project(&projection)?)
+    }
+
+    /// Consume the [`FixedSchema`] and try and produce a [`ParquetBuilder`] which only builds
+    /// the columns with the provided indices. The fields of all other columns are skipped
+    /// based on the column offsets, without being parsed.
+    ///
+    /// # Errors
+    /// If the projection is empty, if any of the indices are out of bounds for the schema, or
+    /// if a list column is projected without the column its number of elements depends on, or
+    /// an alternative without the column it is chosen by.
+    pub fn try_into_projected_builder(self, projection: &[usize]) -> Result<ParquetBuilder> {
+        let projection: Vec<usize> = self.try_sorted_projection(projection)?;
+        let row_length: usize = self.row_length();
+
+        let projected: Vec<&String> = projection
             .iter()
-            .map(|c| c.as_column_builder())
-            .collect::<Vec<ColumnBuilderRef>>();
+            .map(|idx| self.columns[*idx].name())
+            .collect::<Vec<&String>>();
+        if let Some(name) = self
+            .dependency_column_names(&projection)
+            .into_iter()
+            .find(|n| !projected.contains(&n))
+        {
+            return Err(Box::new(SetupError::new(&format!(
+                "Column '{}' has to be converted, since a list column or an alternative depends on it, exiting...",
+                name,
+            ))));
+        }
+
+        let column_builders: Vec<ColumnBuilderRef> = self.column_builders(&projection);
+        let mut n_runes_to_skip: Vec<usize> = Vec::with_capacity(projection.len() + 1);
+        let mut end_of_previous: usize = 0;
 
-        T::from(column_builders)
+        for (idx, consumes) in projection.iter().zip(self.consumes_region(&projection)) {
+            let column: &FixedColumn = &self.columns[*idx];
+            n_runes_to_skip.push(column.offset().saturating_sub(end_of_previous));
+            end_of_previous = match consumes {
+                true => column.offset() + column.length(),
+                false => column.offset(),
+            };
+        }
+        n_runes_to_skip.push(row_length.saturating_sub(end_of_previous));
+
+        Ok(ParquetBuilder::from(column_builders).with_runes_to_skip(n_runes_to_skip))
     }
 }
 
@@ -230,6 +604,10 @@ impl<'a> Iterator for FixedSchemaIterator<'a> {
 #[cfg(test)]
 mod tests_schema {
     use super::*;
+    use crate::column::{Condition, Occurs};
+    use crate::record::ControlSum;
+    use arrow::array::{Array, AsArray, Int32Array, StringArray};
+    use arrow::datatypes::{DataType as ArrowDataType, Int32Type};
     use padder::{Alignment, Symbol};
 
     #[test]
@@ -302,6 +680,57 @@ mod tests_schema {
         let _: FixedSchema = FixedSchema::from_path(path).unwrap();
     }
 
+    #[test]
+    fn test_deserialize_default_schema_from_file() {
+        let mut path: PathBuf = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        path.push("res/test_valid_default_schema.json");
+
+        let schema: FixedSchema = FixedSchema::from_path(path).unwrap();
+        let defaults: Vec<Option<&str>> = schema.columns().iter().map(|c| c.default()).collect();
+
+        assert_eq!(
+            vec![None, Some("0"), Some("UNKNOWN"), Some("false")],
+            defaults
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_deserialize_invalid_default_schema_from_file() {
+        let mut path: PathBuf = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        path.push("res/test_invalid_default_schema.json");
+
+        let _: FixedSchema = FixedSchema::from_path(path).unwrap();
+    }
+
+    #[test]
+    fn test_deserialize_envelope_schema_from_file() {
+        let mut path: PathBuf = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        path.push("res/test_valid_envelope_schema.json");
+
+        let schema: FixedSchema = FixedSchema::from_path(path).unwrap();
+        assert_eq!(1, schema.skip_lines());
+        assert_eq!(2, schema.header().unwrap().columns().len());
+
+        let trailer: &RecordLayout = schema.trailer().unwrap();
+        assert_eq!(Some("record_count"), trailer.record_count());
+        assert_eq!("amount", trailer.control_sums()[0].column());
+
+        let fields: Vec<(String, String)> = trailer
+            .try_parse_fields("00000003      1042".as_bytes())
+            .unwrap();
+        assert_eq!(("record_count".to_string(), "3".to_string()), fields[0]);
+        assert_eq!(("amount_sum".to_string(), "1042".to_string()), fields[1]);
+
+        let invalid: FixedSchema = schema
+            .clone()
+            .with_trailer(trailer.clone().with_control_sum(ControlSum::new(
+                String::from("amount_sum"),
+                String::from("country"),
+            )));
+        assert!(invalid.try_validate().is_err());
+    }
+
     #[test]
     fn test_iterate_schema_columns() {
         let columns: Vec<FixedColumn> = vec![
@@ -337,4 +766,291 @@ mod tests_schema {
         assert_ne!(DataType::Boolean, c2.dtype());
         assert_eq!(None, iterator.next());
     }
+
+    #[test]
+    fn test_build_list_columns() {
+        let mut path: PathBuf = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        path.push("res/test_valid_list_schema.json");
+
+        let schema: FixedSchema = FixedSchema::from_path(path).unwrap();
+        assert_eq!(19, schema.row_length());
+
+        let arrow_schema: ArrowSchema = schema.clone().into_arrow_schema();
+        assert!(matches!(
+            arrow_schema.field(1).data_type(),
+            ArrowDataType::FixedSizeList(_, 4)
+        ));
+        assert!(matches!(
+            arrow_schema.field(2).data_type(),
+            ArrowDataType::List(_)
+        ));
+
+        let mut builder: ParquetBuilder = schema.clone().into_builder();
+        let buffer: &[u8] = "2  1  2  3  4SEFIDK
+   5  6  7  8NO    
+"
+        .as_bytes();
+        builder.try_build_from_slice(buffer).unwrap();
+
+        let batch = builder.try_finish().unwrap();
+        let months = batch.column(1).as_fixed_size_list();
+        let codes = batch.column(2).as_list::<i32>();
+
+        assert_eq!(
+            &Int32Array::from(vec![5, 6, 7, 8]),
+            months.value(1).as_primitive::<Int32Type>(),
+        );
+        assert_eq!(
+            &StringArray::from(vec!["SE", "FI"]),
+            codes.value(0).as_string::<i32>(),
+        );
+        assert!(codes.is_null(1));
+
+        assert!(schema.clone().try_into_projected_builder(&[2]).is_err());
+        assert!(schema.try_into_projected_builder(&[0, 2]).is_ok());
+    }
+
+    #[test]
+    fn test_build_non_nullable_list_columns() {
+        let columns: Vec<FixedColumn> = vec![
+            FixedColumn::new(
+                "n".to_string(),
+                0,
+                1,
+                DataType::Int16,
+                Alignment::Right,
+                Symbol::Whitespace,
+                false,
+            ),
+            FixedColumn::new(
+                "amounts".to_string(),
+                1,
+                3,
+                DataType::Int32,
+                Alignment::Right,
+                Symbol::Whitespace,
+                false,
+            )
+            .with_occurs(Occurs::new(3).with_depending_on("n".to_string())),
+        ];
+        let schema: FixedSchema = FixedSchema::new("lists".to_string(), 1, columns);
+        schema.try_validate().unwrap();
+
+        let mut builder: ParquetBuilder = schema.clone().into_builder();
+        builder
+            .try_build_from_slice("2  1  2   \n0         \n".as_bytes())
+            .unwrap();
+
+        let batch = builder.try_finish().unwrap();
+        let amounts = batch.column(1).as_list::<i32>();
+        assert_eq!(&[0, 2, 2], amounts.value_offsets());
+        assert_eq!(
+            &Int32Array::from(vec![1, 2]),
+            amounts.value(0).as_primitive::<Int32Type>(),
+        );
+
+        let mut builder: ParquetBuilder = schema.into_builder();
+        let error = builder
+            .try_build_from_slice("4  1  2  3\n".as_bytes())
+            .unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("Column 'amounts' has 4 elements"));
+    }
+
+    #[test]
+    fn test_build_struct_columns() {
+        let mut path: PathBuf = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        path.push("res/test_valid_struct_schema.json");
+
+        let schema: FixedSchema = FixedSchema::from_path(path).unwrap();
+        assert_eq!(22, schema.row_length());
+
+        let arrow_schema: ArrowSchema = schema.clone().into_arrow_schema();
+        match arrow_schema.field(1).data_type() {
+            ArrowDataType::Struct(fields) => assert_eq!(3, fields.len()),
+            dtype => panic!("Expected a struct but got {:?}", dtype),
+        }
+
+        let mut builder: ParquetBuilder = schema.clone().into_builder();
+        let buffer: &[u8] = "  1Storgata21120Malmö 
+  2Kungsg.      Umeå  
+"
+        .as_bytes();
+        builder.try_build_from_slice(buffer).unwrap();
+
+        let batch = builder.try_finish().unwrap();
+        let address = batch.column(1).as_struct();
+
+        assert_eq!(
+            &StringArray::from(vec!["Malmö", "Umeå"]),
+            address.column_by_name("city").unwrap().as_string::<i32>(),
+        );
+        assert!(address.column_by_name("zip").unwrap().is_null(1));
+
+        let mut fields: Vec<FixedColumn> = schema.columns()[1].fields().clone();
+        fields.remove(1);
+        let gap: FixedColumn = schema.columns()[1].clone().with_fields(fields);
+        assert!(gap.try_validate().is_err());
+    }
+
+    #[test]
+    fn test_build_alternative_columns() {
+        let mut path: PathBuf = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        path.push("res/test_valid_alternative_schema.json");
+
+        let schema: FixedSchema = FixedSchema::from_path(path).unwrap();
+        assert_eq!(20, schema.row_length());
+        assert!(schema.is_redefinition(3));
+        assert_eq!(2, schema.alternatives(2).len());
+
+        let buffer: &[u8] =
+            "  1PAnna  Berg    10\n  2CAcme AB  123  20\n  3XUnknown       30\n".as_bytes();
+
+        let mut builder: ParquetBuilder = schema.clone().into_builder();
+        builder.try_build_from_slice(buffer).unwrap();
+        let batch = builder.try_finish().unwrap();
+
+        let person = batch.column(2).as_struct();
+        let company = batch.column(3).as_struct();
+        assert_eq!(
+            vec![true, false, false],
+            (0..3).map(|i| person.is_valid(i)).collect::<Vec<bool>>()
+        );
+        assert_eq!(
+            vec![false, true, false],
+            (0..3).map(|i| company.is_valid(i)).collect::<Vec<bool>>()
+        );
+        assert_eq!("Berg", person.column(1).as_string::<i32>().value(0));
+        assert_eq!(123, company.column(1).as_primitive::<Int32Type>().value(1));
+        assert_eq!(
+            &Int32Array::from(vec![10, 20, 30]),
+            batch.column(4).as_primitive::<Int32Type>(),
+        );
+
+        let mut builder: ParquetBuilder = schema
+            .clone()
+            .try_into_projected_builder(&[1, 3, 4])
+            .unwrap();
+        builder.try_build_from_slice(buffer).unwrap();
+        let batch = builder.try_finish().unwrap();
+        assert_eq!(
+            "Acme AB",
+            batch
+                .column(1)
+                .as_struct()
+                .column(0)
+                .as_string::<i32>()
+                .value(1)
+        );
+        assert_eq!(30, batch.column(2).as_primitive::<Int32Type>().value(2));
+
+        assert!(schema.clone().try_into_projected_builder(&[2, 4]).is_err());
+        assert_eq!(
+            vec!["cust_type".to_string()],
+            schema.dependency_column_names(&[2])
+        );
+
+        let mut columns: Vec<FixedColumn> = schema.columns().clone();
+        columns[3] = columns[3]
+            .clone()
+            .with_condition(Condition::new("amount".to_string(), vec!["C".to_string()]));
+        assert!(FixedSchema::new("a".to_string(), 1, columns)
+            .try_validate()
+            .is_err());
+
+        let mut columns: Vec<FixedColumn> = schema.columns().clone();
+        columns[2] = columns[2].clone().with_condition(Condition::new(
+            "cust_type".to_string(),
+            vec!["PRIVATE".to_string()],
+        ));
+        assert!(FixedSchema::new("c".to_string(), 1, columns)
+            .try_validate()
+            .is_err());
+
+        let mut columns: Vec<FixedColumn> = schema.columns().clone();
+        columns[1] = FixedColumn::new(
+            "cust_type".to_string(),
+            2,
+            2,
+            DataType::Utf8,
+            Alignment::Left,
+            Symbol::Whitespace,
+            false,
+        );
+        assert!(FixedSchema::new("b".to_string(), 1, columns)
+            .try_validate()
+            .is_err());
+    }
+
+    #[test]
+    fn test_projected_builder_skips_columns() {
+        let columns: Vec<FixedColumn> = vec![
+            FixedColumn::new(
+                String::from("id"),
+                0,
+                4,
+                DataType::Int32,
+                Alignment::Right,
+                Symbol::Whitespace,
+                false,
+            ),
+            FixedColumn::new(
+                String::from("city"),
+                4,
+                6,
+                DataType::Utf8,
+                Alignment::Left,
+                Symbol::Whitespace,
+                false,
+            ),
+            FixedColumn::new(
+                String::from("amount"),
+                10,
+                5,
+                DataType::Int64,
+                Alignment::Right,
+                Symbol::Whitespace,
+                false,
+            ),
+        ];
+        let schema: FixedSchema = FixedSchema::new(String::from("Projected"), 1, columns);
+
+        let arrow_schema: ArrowSchema = schema
+            .clone()
+            .try_into_projected_arrow_schema(&[2, 0])
+            .unwrap();
+        assert_eq!(2, arrow_schema.fields().len());
+        assert_eq!("id", arrow_schema.field(0).name());
+        assert_eq!("amount", arrow_schema.field(1).name());
+
+        let mut builder: ParquetBuilder = schema.clone().try_into_projected_builder(&[1]).unwrap();
+        let buffer: &[u8] = "   1Malmö   -12\n   2Luleå    40\n".as_bytes();
+        builder.try_build_from_slice(buffer).unwrap();
+
+        let batch = builder.try_finish().unwrap();
+        assert_eq!(1, batch.num_columns());
+        assert_eq!(2, batch.num_rows());
+
+        let cities = batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        assert_eq!("Malmö", cities.value(0));
+        assert_eq!("Luleå", cities.value(1));
+
+        assert_eq!(
+            vec![2, 0],
+            schema
+                .try_column_indices(&[String::from("amount"), String::from("id")])
+                .unwrap(),
+        );
+        assert!(schema
+            .try_column_indices(&[String::from("country")])
+            .is_err());
+
+        assert!(schema.clone().try_into_projected_builder(&[]).is_err());
+        assert!(schema.try_into_projected_builder(&[3]).is_err());
+    }
 }
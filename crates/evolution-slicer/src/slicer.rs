@@ -22,7 +22,7 @@
 // SOFTWARE.
 //
 // File created: 2023-12-11
-// Last updated: 2024-10-13
+// Last updated: 2026-10-18
 //
 
 use evolution_common::error::{ExecutionError, Result};
@@ -30,7 +30,7 @@ use evolution_common::NUM_BYTES_FOR_NEWLINE;
 use log::warn;
 
 use std::fs::{File, OpenOptions};
-use std::io::{BufReader, ErrorKind, Read};
+use std::io::{BufRead, BufReader, ErrorKind, Read, Seek, SeekFrom};
 use std::path::PathBuf;
 use std::sync::Arc;
 
@@ -42,9 +42,14 @@ pub trait Slicer {
 ///
 pub type SlicerRef = Arc<dyn Slicer>;
 
+/// The leading and trailing lines split off from a file, without their line breaks.
+pub type SplitLines = (Vec<Vec<u8>>, Vec<Vec<u8>>);
+
 ///
 pub struct FileSlicer {
     inner: BufReader<File>,
+    /// The byte index in the file where the lines to read start, i.e., after any split off lines.
+    body_start: usize,
     bytes_to_read: usize,
     remaining_bytes: usize,
     bytes_processed: usize,
@@ -71,6 +76,7 @@ impl FileSlicer {
 
         Ok(FileSlicer {
             inner,
+            body_start: 0,
             bytes_to_read,
             remaining_bytes,
             bytes_processed,
@@ -333,6 +339,167 @@ impl FileSlicer {
         Ok(())
     }
 
+    /// Try and split the file into at most `n_ranges` byte ranges of roughly equal size, where
+    /// each range starts at the beginning of a row. The ranges are returned as tuples of start
+    /// (inclusive) and end (exclusive) byte indices in the file, and together they cover all of
+    /// the lines to read, i.e., the whole file unless lines have been split off from it.
+    /// The position of the buffered reader is restored before returning.
+    ///
+    /// # Note
+    /// Fewer ranges than requested are returned if the rows are too long, or the file too small,
+    /// to fit the requested number of ranges. An empty file produces no ranges.
+    ///
+    /// # Errors
+    /// If any I/O error was returned when seeking in, or reading from, the file.
+    pub fn try_find_row_aligned_ranges(&mut self, n_ranges: usize) -> Result<Vec<(usize, usize)>> {
+        if self.bytes_to_read == 0 {
+            return Ok(vec![]);
+        }
+
+        let position: u64 = self.inner.stream_position()?;
+        let n_ranges: usize = n_ranges.max(1);
+        let target_range_length: usize = self.bytes_to_read.div_ceil(n_ranges);
+
+        let mut starts: Vec<usize> = vec![0];
+        let mut line: Vec<u8> = Vec::new();
+
+        for range_idx in 1..n_ranges {
+            let target: usize = range_idx * target_range_length;
+            if target >= self.bytes_to_read {
+                break;
+            }
+
+            if target <= *starts.last().unwrap() {
+                continue;
+            }
+
+            // Read from the byte before the target, so that a row starting exactly at the
+            // target is not skipped.
+            self.inner
+                .seek(SeekFrom::Start((self.body_start + target - 1) as u64))?;
+            line.clear();
+            let start: usize = target - 1 + self.inner.read_until(0x0a, &mut line)?;
+
+            if start >= self.bytes_to_read {
+                break;
+            }
+
+            starts.push(start);
+        }
+
+        self.inner.seek(SeekFrom::Start(position))?;
+
+        let mut ends: Vec<usize> = starts[1..].to_vec();
+        ends.push(self.bytes_to_read);
+
+        Ok(starts
+            .into_iter()
+            .zip(ends)
+            .map(|(start, end)| (self.body_start + start, self.body_start + end))
+            .collect::<Vec<(usize, usize)>>())
+    }
+
+    /// Try and split off the first `n_leading` and the last `n_trailing` lines of the file, e.g.,
+    /// header and trailer records, returning them without their line breaks. Afterwards, the
+    /// slicer only reads the lines in between. Empty lines at the very end of the file are not
+    /// considered lines.
+    ///
+    /// # Note
+    /// This has to be called before anything is read from the file.
+    ///
+    /// # Errors
+    /// If the file does not contain enough lines, or if any I/O error was returned when
+    /// seeking in, or reading from, the file.
+    pub fn try_split_off_lines(
+        &mut self,
+        n_leading: usize,
+        n_trailing: usize,
+    ) -> Result<SplitLines> {
+        let n_bytes_total: usize = self.bytes_to_read;
+        let too_few_lines = || {
+            Box::new(ExecutionError::new(&format!(
+                "The file contains fewer than {} header and trailer lines, exiting...",
+                n_leading + n_trailing,
+            )))
+        };
+
+        self.inner.seek(SeekFrom::Start(0))?;
+        let mut leading: Vec<Vec<u8>> = Vec::with_capacity(n_leading);
+        let mut body_start: usize = 0;
+        for _ in 0..n_leading {
+            let mut line: Vec<u8> = Vec::new();
+            let n_bytes: usize = self.inner.read_until(0x0a, &mut line)?;
+            if n_bytes == 0 {
+                return Err(too_few_lines());
+            }
+            body_start += n_bytes;
+            leading.push(strip_line_break(line));
+        }
+
+        let mut trailing: Vec<Vec<u8>> = Vec::with_capacity(n_trailing);
+        let mut body_end: usize = n_bytes_total;
+        if n_trailing > 0 {
+            // Read increasingly large chunks from the end of the file until they contain
+            // all of the trailing lines.
+            let mut n_bytes_tail: usize = (n_bytes_total - body_start).min(64 * 1024);
+            loop {
+                let tail_start: usize = n_bytes_total - n_bytes_tail;
+                let mut tail: Vec<u8> = vec![0u8; n_bytes_tail];
+                self.inner.seek(SeekFrom::Start(tail_start as u64))?;
+                self.inner.read_exact(&mut tail)?;
+
+                let end: usize = tail.len()
+                    - tail
+                        .iter()
+                        .rev()
+                        .take_while(|b| **b == 0x0a || **b == 0x0d)
+                        .count();
+
+                let line_breaks: Vec<usize> = tail[..end]
+                    .iter()
+                    .enumerate()
+                    .rev()
+                    .filter(|(_, b)| **b == 0x0a)
+                    .map(|(idx, _)| idx)
+                    .take(n_trailing)
+                    .collect::<Vec<usize>>();
+
+                let is_entire_body: bool = tail_start == body_start;
+                if line_breaks.len() == n_trailing
+                    || (is_entire_body && line_breaks.len() + 1 == n_trailing)
+                {
+                    let first_line_start: usize = match line_breaks.len() == n_trailing {
+                        true => line_breaks[n_trailing - 1] + 1,
+                        false => 0,
+                    };
+
+                    if end == 0 || first_line_start >= end {
+                        return Err(too_few_lines());
+                    }
+
+                    for line in tail[first_line_start..end].split(|b| *b == 0x0a) {
+                        trailing.push(strip_line_break(line.to_vec()));
+                    }
+                    body_end = tail_start + first_line_start;
+                    break;
+                }
+
+                if is_entire_body {
+                    return Err(too_few_lines());
+                }
+
+                n_bytes_tail = (n_bytes_tail * 2).min(n_bytes_total - body_start);
+            }
+        }
+
+        self.inner.seek(SeekFrom::Start(body_start as u64))?;
+        self.body_start = body_start;
+        self.bytes_to_read = body_end - body_start;
+        self.remaining_bytes = self.bytes_to_read;
+
+        Ok((leading, trailing))
+    }
+
     /// Try and seek relative to the current position in the buffered reader.
     ///
     /// # Errors
@@ -351,6 +518,17 @@ impl FileSlicer {
     }
 }
 
+/// Remove the line break, either `\n` or `\r\n`, from the end of the line, if any.
+fn strip_line_break(mut line: Vec<u8>) -> Vec<u8> {
+    if line.last() == Some(&0x0a) {
+        line.pop();
+    }
+    if line.last() == Some(&0x0d) {
+        line.pop();
+    }
+    line
+}
+
 impl Slicer for FileSlicer {
     /// Get whether or not this [`Slicer`] is done reading the input file.
     fn is_done(&self) -> bool {
@@ -0,0 +1,239 @@
+//
+// MIT License
+//
+// Copyright (c) 2026 Firelink Data
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//
+// File created: 2026-10-18
+// Last updated: 2026-10-18
+//
+
+use arrow::array::{Array, ArrayRef};
+use arrow::util::display::{ArrayFormatter, FormatOptions};
+use evolution_common::error::Result;
+use serde::Serialize;
+
+use std::fs::File;
+use std::io::BufWriter;
+use std::path::PathBuf;
+
+/// A single value of a column that did not survive the round trip, where `None` is a null.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Mismatch {
+    /// The index of the row of the value.
+    row: usize,
+    /// The value the mocker generated, typed per the schema.
+    expected: Option<String>,
+    /// The value read back from the converted file.
+    actual: Option<String>,
+}
+
+impl Mismatch {
+    /// Get the index of the row of the value.
+    pub fn row(&self) -> usize {
+        self.row
+    }
+
+    /// Get the value the mocker generated, or `None` if it is a null.
+    pub fn expected(&self) -> Option<&str> {
+        self.expected.as_deref()
+    }
+
+    /// Get the value read back from the converted file, or `None` if it is a null.
+    pub fn actual(&self) -> Option<&str> {
+        self.actual.as_deref()
+    }
+}
+
+/// The values of a single column that did not survive the round trip.
+#[derive(Clone, Debug, Serialize)]
+pub struct ColumnMismatches {
+    /// The symbolic name of the column.
+    name: String,
+    /// The number of values that did not survive the round trip.
+    n_mismatches: usize,
+    /// The first of the mismatched values, at most as many as the examples requested.
+    examples: Vec<Mismatch>,
+}
+
+impl ColumnMismatches {
+    /// Try and compare the expected values of a column with the values read back from the
+    /// converted file, row by row, keeping at most `max_examples` of the mismatched values.
+    /// Rows missing from either of the arrays are not compared.
+    ///
+    /// # Errors
+    /// If any of the values of the arrays could not be formatted.
+    pub(crate) fn try_compare(
+        name: &str,
+        expected: &ArrayRef,
+        actual: &ArrayRef,
+        max_examples: usize,
+    ) -> Result<Self> {
+        let options: FormatOptions = FormatOptions::default();
+        let expected_values: ArrayFormatter = ArrayFormatter::try_new(expected.as_ref(), &options)?;
+        let actual_values: ArrayFormatter = ArrayFormatter::try_new(actual.as_ref(), &options)?;
+
+        let mut mismatches: Self = Self {
+            name: name.to_string(),
+            n_mismatches: 0,
+            examples: Vec::new(),
+        };
+
+        for row in 0..expected.len().min(actual.len()) {
+            let expected: Option<String> =
+                (!expected.is_null(row)).then(|| expected_values.value(row).to_string());
+            let actual: Option<String> =
+                (!actual.is_null(row)).then(|| actual_values.value(row).to_string());
+
+            if expected != actual {
+                mismatches.n_mismatches += 1;
+                if mismatches.examples.len() < max_examples {
+                    mismatches.examples.push(Mismatch {
+                        row,
+                        expected,
+                        actual,
+                    });
+                }
+            }
+        }
+
+        Ok(mismatches)
+    }
+
+    /// Get the name of the column.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Get the number of values that did not survive the round trip.
+    pub fn n_mismatches(&self) -> usize {
+        self.n_mismatches
+    }
+
+    /// Get the first of the mismatched values.
+    pub fn examples(&self) -> &[Mismatch] {
+        &self.examples
+    }
+}
+
+/// The result of a round trip, i.e. of mocking a file, converting it, and comparing the values
+/// read back from the converted file with the values the mocker generated.
+#[derive(Clone, Debug, Serialize)]
+pub struct RoundTripReport {
+    /// The seed the file was mocked from.
+    seed: u64,
+    /// The number of threads used to mock and convert the file.
+    n_threads: usize,
+    /// The number of rows the mocker generated.
+    expected_rows: usize,
+    /// The number of rows read back from the converted file.
+    actual_rows: usize,
+    /// The mismatched values of each of the columns.
+    columns: Vec<ColumnMismatches>,
+}
+
+impl RoundTripReport {
+    /// Create a new [`RoundTripReport`] from the mismatched values of each of the columns.
+    pub(crate) fn new(
+        seed: u64,
+        n_threads: usize,
+        expected_rows: usize,
+        actual_rows: usize,
+        columns: Vec<ColumnMismatches>,
+    ) -> Self {
+        Self {
+            seed,
+            n_threads,
+            expected_rows,
+            actual_rows,
+            columns,
+        }
+    }
+
+    /// Get the seed the file was mocked from.
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Get the number of threads used to mock and convert the file.
+    pub fn n_threads(&self) -> usize {
+        self.n_threads
+    }
+
+    /// Get the number of rows the mocker generated.
+    pub fn expected_rows(&self) -> usize {
+        self.expected_rows
+    }
+
+    /// Get the number of rows read back from the converted file.
+    pub fn actual_rows(&self) -> usize {
+        self.actual_rows
+    }
+
+    /// Get the mismatched values of each of the columns.
+    pub fn columns(&self) -> &[ColumnMismatches] {
+        &self.columns
+    }
+
+    /// Get the total number of values that did not survive the round trip.
+    pub fn n_mismatches(&self) -> usize {
+        self.columns.iter().map(|c| c.n_mismatches).sum()
+    }
+
+    /// Whether every row and every value survived the round trip.
+    pub fn is_ok(&self) -> bool {
+        self.expected_rows == self.actual_rows && self.n_mismatches() == 0
+    }
+
+    /// Try and write the report as pretty printed json to the file at the path.
+    ///
+    /// # Errors
+    /// If the file could not be created or the report could not be serialized.
+    pub fn try_write_to_path(&self, path: PathBuf) -> Result<()> {
+        let writer: BufWriter<File> = BufWriter::new(File::create(path)?);
+        serde_json::to_writer_pretty(writer, self)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests_report {
+    use super::*;
+    use arrow::array::{Int32Array, StringArray};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_compare_columns() {
+        let expected: ArrayRef = Arc::new(Int32Array::from(vec![Some(1), None, Some(3), Some(4)]));
+        let actual: ArrayRef = Arc::new(Int32Array::from(vec![Some(1), Some(0), Some(3), None]));
+        let mismatches = ColumnMismatches::try_compare("a", &expected, &actual, 1).unwrap();
+
+        assert_eq!(2, mismatches.n_mismatches());
+        assert_eq!(1, mismatches.examples().len());
+        assert_eq!(1, mismatches.examples()[0].row());
+        assert_eq!(None, mismatches.examples()[0].expected());
+        assert_eq!(Some("0"), mismatches.examples()[0].actual());
+
+        let expected: ArrayRef = Arc::new(StringArray::from(vec!["", "Åsa"]));
+        let actual: ArrayRef = Arc::new(StringArray::from(vec![Some(""), Some("Åsa")]));
+        let mismatches = ColumnMismatches::try_compare("b", &expected, &actual, 10).unwrap();
+        assert_eq!(0, mismatches.n_mismatches());
+    }
+}
@@ -0,0 +1,451 @@
+//
+// MIT License
+//
+// Copyright (c) 2026 Firelink Data
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//
+// File created: 2026-10-18
+// Last updated: 2026-10-18
+//
+
+//! Round trips of mocked files, which are mocked from a seed, converted to parquet, and read
+//! back, so that every converted value can be compared with the value the mocker generated.
+
+use arrow::array::{RecordBatch, RecordBatchReader};
+use arrow::compute::concat_batches;
+use arrow::datatypes::SchemaRef as ArrowSchemaRef;
+use evolution_common::error::{Result, SetupError};
+use evolution_converter::converter::ParquetConverter;
+use evolution_mocker::mocker::{FixedLengthFileMocker, FixedLengthFileMockerBuilder};
+use evolution_mocker::text::Charset;
+use evolution_mocker::truth::GroundTruth;
+use log::info;
+use parquet::arrow::arrow_reader::{ParquetRecordBatchReader, ParquetRecordBatchReaderBuilder};
+
+use std::fs::{self, File};
+use std::path::{Path, PathBuf};
+
+use crate::report::{ColumnMismatches, RoundTripReport};
+
+/// The default number of mismatched values listed per column in the report.
+pub static DEFAULT_MAX_EXAMPLES: usize = 10;
+
+/// A round trip of a mocked file: the file is mocked from a seed, converted to parquet, and
+/// read back with the [`parquet`] crate, and every value is compared with the value the mocker
+/// generated, typed per the schema.
+///
+/// The mocked and converted files are written to a work directory and removed afterwards,
+/// unless they are kept, so that a failed round trip can be inspected.
+pub struct RoundTrip {
+    schema_path: PathBuf,
+    work_dir: PathBuf,
+    n_rows: usize,
+    n_threads: usize,
+    seed: u64,
+    write_buffer_size: usize,
+    read_buffer_size: usize,
+    thread_channel_capacity: Option<usize>,
+    null_ratio: Option<f64>,
+    charset: Option<Charset>,
+    config_path: Option<PathBuf>,
+    max_examples: usize,
+    keep_files: bool,
+}
+
+impl RoundTrip {
+    /// Create a new instance of a [`RoundTripBuilder`] with default values.
+    pub fn builder() -> RoundTripBuilder {
+        RoundTripBuilder {
+            ..Default::default()
+        }
+    }
+
+    /// Try and mock, convert, and read back the file, and compare every value read back with
+    /// the value the mocker generated.
+    ///
+    /// # Errors
+    /// If the file could not be mocked, e.g. if the schema is not flat, see [`GroundTruth`],
+    /// if it could not be converted, or if the converted file could not be read back.
+    pub fn try_verify(&self) -> Result<RoundTripReport> {
+        let stem: String = format!(
+            "evolution_round_trip_{}_{}_{}",
+            self.seed,
+            self.n_threads,
+            std::process::id(),
+        );
+        let flf_path: PathBuf = self.work_dir.join(format!("{}.flf", stem));
+        let parquet_path: PathBuf = self.work_dir.join(format!("{}.parquet", stem));
+
+        let report: Result<RoundTripReport> = self.try_round_trip(&flf_path, &parquet_path);
+
+        if !self.keep_files {
+            for path in [&flf_path, &parquet_path] {
+                if path.exists() {
+                    fs::remove_file(path)?;
+                }
+            }
+        } else {
+            info!(
+                "Keeping the mocked file {:?} and the converted file {:?}.",
+                flf_path, parquet_path,
+            );
+        }
+
+        report
+    }
+
+    /// Try and mock the file to the first path, convert it to the second path, and compare the
+    /// values read back with the values the mocker generated.
+    fn try_round_trip(&self, flf_path: &Path, parquet_path: &Path) -> Result<RoundTripReport> {
+        // The parquet writer appends to existing files, so any previous output is removed first.
+        if parquet_path.exists() {
+            fs::remove_file(parquet_path)?;
+        }
+
+        info!(
+            "Mocking {} rows from seed {} with {} threads.",
+            self.n_rows, self.seed, self.n_threads,
+        );
+        let truth: GroundTruth = self
+            .mocker_builder(flf_path.to_path_buf())
+            .try_build()?
+            .try_mock_with_ground_truth()?;
+
+        info!("Converting the mocked file to parquet.");
+        ParquetConverter::builder()
+            .with_in_file(flf_path.to_path_buf())
+            .with_schema(self.schema_path.to_path_buf())
+            .with_out_file(parquet_path.to_path_buf())
+            .with_num_threads(self.n_threads)
+            .with_read_buffer_size(self.read_buffer_size)
+            .with_thread_channel_capacity(self.thread_channel_capacity)
+            .try_build()?
+            .try_convert()?;
+
+        info!("Comparing the converted values with the mocked values.");
+        let expected: RecordBatch = truth.try_to_record_batch()?;
+        let actual: RecordBatch = try_read_parquet(parquet_path)?;
+
+        let columns: Vec<ColumnMismatches> = expected
+            .schema()
+            .fields()
+            .iter()
+            .enumerate()
+            .map(|(idx, field)| match actual.column_by_name(field.name()) {
+                Some(array) => ColumnMismatches::try_compare(
+                    field.name(),
+                    expected.column(idx),
+                    array,
+                    self.max_examples,
+                ),
+                None => Err(Box::new(SetupError::new(&format!(
+                    "The converted file does not have column '{}', exiting...",
+                    field.name(),
+                )))
+                .into()),
+            })
+            .collect::<Result<Vec<ColumnMismatches>>>()?;
+
+        Ok(RoundTripReport::new(
+            self.seed,
+            self.n_threads,
+            expected.num_rows(),
+            actual.num_rows(),
+            columns,
+        ))
+    }
+
+    /// Create the builder of the mocker of the round trip, writing to the path.
+    fn mocker_builder(&self, flf_path: PathBuf) -> FixedLengthFileMockerBuilder {
+        let mut builder: FixedLengthFileMockerBuilder = FixedLengthFileMocker::builder()
+            .with_schema(self.schema_path.to_path_buf())
+            .with_out_file(flf_path)
+            .with_num_rows(self.n_rows)
+            .with_num_threads(self.n_threads)
+            .with_write_buffer_size(self.write_buffer_size)
+            .with_thread_channel_capacity(self.thread_channel_capacity)
+            .with_force_create_new(false)
+            .with_truncate_existing(true)
+            .with_seed(self.seed);
+
+        if let Some(null_ratio) = self.null_ratio {
+            builder = builder.with_null_ratio(null_ratio);
+        }
+
+        if let Some(charset) = self.charset {
+            builder = builder.with_charset(charset);
+        }
+
+        if let Some(config_path) = &self.config_path {
+            builder = builder.with_config(config_path.to_path_buf());
+        }
+
+        builder
+    }
+}
+
+/// Try and read every row of the parquet file at the path as a single [`RecordBatch`].
+///
+/// # Errors
+/// If the file could not be opened, or is not a valid parquet file.
+fn try_read_parquet(path: &Path) -> Result<RecordBatch> {
+    let reader: ParquetRecordBatchReader =
+        ParquetRecordBatchReaderBuilder::try_new(File::open(path)?)?.build()?;
+    let schema: ArrowSchemaRef = reader.schema();
+    let batches: Vec<RecordBatch> = reader.collect::<std::result::Result<Vec<_>, _>>()?;
+    Ok(concat_batches(&schema, &batches)?)
+}
+
+/// A helper struct for building an instance of a [`RoundTrip`] struct.
+#[derive(Default)]
+pub struct RoundTripBuilder {
+    schema_path: Option<PathBuf>,
+    work_dir: Option<PathBuf>,
+    n_rows: Option<usize>,
+    n_threads: Option<usize>,
+    seed: Option<u64>,
+    write_buffer_size: Option<usize>,
+    read_buffer_size: Option<usize>,
+    thread_channel_capacity: Option<usize>,
+    null_ratio: Option<f64>,
+    charset: Option<Charset>,
+    config_path: Option<PathBuf>,
+    max_examples: Option<usize>,
+    keep_files: bool,
+}
+
+impl RoundTripBuilder {
+    /// Set the relative or absolute path to the json schema to mock the file from.
+    pub fn with_schema(mut self, schema_path: PathBuf) -> Self {
+        self.schema_path = Some(schema_path);
+        self
+    }
+
+    /// Set the directory to write the mocked and converted files to, defaults to the
+    /// temporary directory of the operating system.
+    pub fn with_work_dir(mut self, work_dir: PathBuf) -> Self {
+        self.work_dir = Some(work_dir);
+        self
+    }
+
+    /// Set the number of rows to mock.
+    pub fn with_num_rows(mut self, n_rows: usize) -> Self {
+        self.n_rows = Some(n_rows);
+        self
+    }
+
+    /// Set the number of threads to mock and convert the file with.
+    pub fn with_num_threads(mut self, n_threads: usize) -> Self {
+        self.n_threads = Some(n_threads);
+        self
+    }
+
+    /// Set the seed to mock the file from.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Set the size of the write buffer used when mocking (in rows).
+    pub fn with_write_buffer_size(mut self, buffer_size: usize) -> Self {
+        self.write_buffer_size = Some(buffer_size);
+        self
+    }
+
+    /// Set the size of the read buffer used when converting (in bytes).
+    pub fn with_read_buffer_size(mut self, buffer_size: usize) -> Self {
+        self.read_buffer_size = Some(buffer_size);
+        self
+    }
+
+    /// Set the maximum capacity of the thread channels (in number of messages).
+    pub fn with_thread_channel_capacity(mut self, capacity: Option<usize>) -> Self {
+        self.thread_channel_capacity = capacity;
+        self
+    }
+
+    /// Set the ratio, between 0 and 1, of the fields of the nullable columns to leave blank.
+    pub fn with_null_ratio(mut self, null_ratio: f64) -> Self {
+        self.null_ratio = Some(null_ratio);
+        self
+    }
+
+    /// Set the characters of the mocked strings.
+    pub fn with_charset(mut self, charset: Charset) -> Self {
+        self.charset = Some(charset);
+        self
+    }
+
+    /// Set the relative or absolute path to a json mock config file, with the specifications
+    /// of the generators of individual columns.
+    pub fn with_config(mut self, config_path: PathBuf) -> Self {
+        self.config_path = Some(config_path);
+        self
+    }
+
+    /// Set the maximum number of mismatched values listed per column in the report.
+    pub fn with_max_examples(mut self, max_examples: usize) -> Self {
+        self.max_examples = Some(max_examples);
+        self
+    }
+
+    /// Set whether to keep the mocked and converted files after the round trip.
+    pub fn with_keep_files(mut self, keep_files: bool) -> Self {
+        self.keep_files = keep_files;
+        self
+    }
+
+    /// Set default values for the optional configuration fields.
+    ///
+    /// # Note
+    /// The default round trip is single-threaded, with a small write buffer and a read buffer
+    /// of 10 MB, so that files of a few thousand rows are converted in several chunks.
+    pub fn with_default_values(mut self) -> Self {
+        self.n_threads = Some(1);
+        self.write_buffer_size = Some(1000);
+        self.read_buffer_size = Some(10 * 1024 * 1024); // 10 MB
+        self
+    }
+
+    /// Try creating a new [`RoundTrip`] from the previously set values.
+    ///
+    /// # Errors
+    /// If any of the required fields are `None`.
+    pub fn try_build(self) -> Result<RoundTrip> {
+        let schema_path: PathBuf = self.schema_path.ok_or_else(|| {
+            Box::new(SetupError::new(
+                "Required field 'schema_path' was not provided, exiting...",
+            ))
+        })?;
+
+        let n_rows: usize = self.n_rows.ok_or_else(|| {
+            Box::new(SetupError::new(
+                "Required field 'n_rows' was not provided, exiting...",
+            ))
+        })?;
+
+        let n_threads: usize = self.n_threads.ok_or_else(|| {
+            Box::new(SetupError::new(
+                "Required field 'n_threads' was not provided, exiting...",
+            ))
+        })?;
+
+        let seed: u64 = self.seed.ok_or_else(|| {
+            Box::new(SetupError::new(
+                "Required field 'seed' was not provided, exiting...",
+            ))
+        })?;
+
+        let write_buffer_size: usize = self.write_buffer_size.ok_or_else(|| {
+            Box::new(SetupError::new(
+                "Required field 'write_buffer_size' was not provided, exiting...",
+            ))
+        })?;
+
+        let read_buffer_size: usize = self.read_buffer_size.ok_or_else(|| {
+            Box::new(SetupError::new(
+                "Required field 'read_buffer_size' was not provided, exiting...",
+            ))
+        })?;
+
+        Ok(RoundTrip {
+            schema_path,
+            work_dir: self.work_dir.unwrap_or_else(std::env::temp_dir),
+            n_rows,
+            n_threads,
+            seed,
+            write_buffer_size,
+            read_buffer_size,
+            thread_channel_capacity: self.thread_channel_capacity,
+            null_ratio: self.null_ratio,
+            charset: self.charset,
+            config_path: self.config_path,
+            max_examples: self.max_examples.unwrap_or(DEFAULT_MAX_EXAMPLES),
+            keep_files: self.keep_files,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests_roundtrip {
+    use super::*;
+    use evolution_mocker::mocker::MIN_NUM_ROWS_FOR_MULTITHREADING;
+
+    fn round_trip(n_rows: usize, n_threads: usize, seed: u64) -> RoundTripBuilder {
+        let mut schema_path: PathBuf = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        schema_path.push("res/test_round_trip_schema.json");
+
+        RoundTrip::builder()
+            .with_schema(schema_path)
+            .with_num_rows(n_rows)
+            .with_seed(seed)
+            .with_default_values()
+            .with_num_threads(n_threads)
+    }
+
+    #[test]
+    fn test_round_trip_single_threaded() {
+        for charset in [Charset::Ascii, Charset::Mixed] {
+            let report: RoundTripReport = round_trip(5000, 1, 42)
+                .with_null_ratio(0.2)
+                .with_charset(charset)
+                .try_build()
+                .unwrap()
+                .try_verify()
+                .unwrap();
+
+            assert_eq!(5000, report.actual_rows());
+            assert!(report.is_ok(), "{:?}", report);
+        }
+    }
+
+    #[test]
+    fn test_round_trip_with_generators() {
+        let mut config_path: PathBuf = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        config_path.push("res/test_round_trip_config.json");
+
+        // The generators produce numbers beyond what the columns can hold, which are clamped
+        // to them instead of being cut when padded.
+        let report: RoundTripReport = round_trip(5000, 1, 3)
+            .with_null_ratio(0.1)
+            .with_config(config_path)
+            .try_build()
+            .unwrap()
+            .try_verify()
+            .unwrap();
+
+        assert_eq!(5000, report.actual_rows());
+        assert!(report.is_ok(), "{:?}", report);
+    }
+
+    #[test]
+    fn test_round_trip_multithreaded() {
+        let report: RoundTripReport = round_trip(MIN_NUM_ROWS_FOR_MULTITHREADING, 3, 7)
+            .with_null_ratio(0.1)
+            .with_read_buffer_size(1024 * 1024)
+            .try_build()
+            .unwrap()
+            .try_verify()
+            .unwrap();
+
+        assert_eq!(MIN_NUM_ROWS_FOR_MULTITHREADING, report.actual_rows());
+        assert!(report.is_ok(), "{:?}", report);
+    }
+}
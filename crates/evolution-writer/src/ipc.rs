@@ -0,0 +1,114 @@
+//
+// MIT License
+//
+// Copyright (c) 2026 Firelink Data
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//
+// File created: 2026-10-18
+// Last updated: 2026-10-18
+//
+
+use arrow::array::RecordBatch;
+use arrow::datatypes::SchemaRef as ArrowSchemaRef;
+use arrow::ipc::writer::FileWriter;
+use evolution_common::error::{Result, SetupError};
+
+use std::fs::File;
+use std::path::PathBuf;
+
+/// A writer of record batches to a file in the arrow IPC file format.
+pub struct IpcWriter {
+    inner: FileWriter<File>,
+}
+
+impl IpcWriter {
+    /// Create a new instance of a [`IpcWriterBuilder`] with default values.
+    pub fn builder() -> IpcWriterBuilder {
+        IpcWriterBuilder {
+            ..Default::default()
+        }
+    }
+
+    /// Try and write the [`RecordBatch`] to the underlying arrow writer.
+    pub fn try_write_batch(&mut self, record_batch: &RecordBatch) -> Result<()> {
+        self.inner.write(record_batch)?;
+        Ok(())
+    }
+
+    /// Close and finalize the underlying arrow writer, writing the footer of the file.
+    pub fn try_finish(&mut self) -> Result<()> {
+        Ok(self.inner.finish()?)
+    }
+}
+
+/// A helper struct for building an instance of a [`IpcWriter`] struct.
+#[derive(Default)]
+pub struct IpcWriterBuilder {
+    out_path: Option<PathBuf>,
+    schema: Option<ArrowSchemaRef>,
+}
+
+impl IpcWriterBuilder {
+    /// Set the relative or absolute path to the output file to produce, which is truncated if
+    /// it already exists.
+    pub fn with_out_path(mut self, out_path: PathBuf) -> Self {
+        self.out_path = Some(out_path);
+        self
+    }
+
+    /// Set the [`ArrowSchemaRef`] to use for the IPC file.
+    pub fn with_arrow_schema(mut self, schema: ArrowSchemaRef) -> Self {
+        self.schema = Some(schema);
+        self
+    }
+
+    /// Try creating a new [`IpcWriter`] from the previously set values.
+    ///
+    /// # Errors
+    /// If any of the required fields are `None`, or if the output file could not be created.
+    pub fn try_build(self) -> Result<IpcWriter> {
+        let out_file: File = match self.out_path {
+            Some(p) => File::create(p)?,
+            None => {
+                return Err(Box::new(SetupError::new(
+                    "Required field 'out_path' was not provided, exiting...",
+                )))
+            }
+        };
+
+        let schema: ArrowSchemaRef = self.schema.ok_or_else(|| {
+            Box::new(SetupError::new(
+                "Required field 'schema' was not provided, exiting...",
+            ))
+        })?;
+
+        let inner: FileWriter<File> = FileWriter::try_new(out_file, &schema)?;
+
+        Ok(IpcWriter { inner })
+    }
+
+    /// Creates a new [`IpcWriter`] from the previously set values.
+    ///
+    /// # Panics
+    /// If any of the required fields are `None`, see [`IpcWriterBuilder::try_build`].
+    pub fn build(self) -> IpcWriter {
+        self.try_build().unwrap()
+    }
+}
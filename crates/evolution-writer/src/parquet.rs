@@ -22,15 +22,16 @@
 // SOFTWARE.
 //
 // File created: 2024-05-05
-// Last updated: 2024-10-11
+// Last updated: 2026-10-18
 //
 
-use arrow::array::{ArrayRef, RecordBatch};
+use arrow::array::RecordBatch;
 use arrow::datatypes::SchemaRef as ArrowSchemaRef;
 use evolution_builder::builder::ParquetBuilder;
 use evolution_common::error::{Result, SetupError};
 use parquet::arrow::ArrowWriter;
 use parquet::file::properties::WriterProperties as ArrowWriterProperties;
+use parquet::format::{FileMetaData, KeyValue};
 
 use std::fs::{File, OpenOptions};
 use std::path::PathBuf;
@@ -38,7 +39,6 @@ use std::path::PathBuf;
 ///
 pub struct ParquetWriter {
     inner: ArrowWriter<File>,
-    n_columns: usize,
 }
 
 impl ParquetWriter {
@@ -49,24 +49,28 @@ impl ParquetWriter {
         }
     }
 
-    /// TODO: NOTE THIS ALLOCATES MEMORY ON THE HEAP!!! BAD!!!
+    /// Try and finish the column builders of the [`ParquetBuilder`] and write the built
+    /// columns as a [`RecordBatch`].
     pub fn try_write_from_builder(&mut self, builder: &mut ParquetBuilder) -> Result<()> {
-        let mut buffer: Vec<(&str, ArrayRef)> = Vec::with_capacity(self.n_columns);
-        for column_builder in builder.columns().iter_mut() {
-            buffer.push(column_builder.finish());
-        }
-
-        // BAD PERFORMANCE!!!
-        let record_batch: RecordBatch = RecordBatch::try_from_iter(buffer)?;
-        self.inner.write(&record_batch)?;
+        let record_batch: RecordBatch = builder.try_finish()?;
+        self.try_write_batch(&record_batch)
+    }
 
+    /// Try and write the [`RecordBatch`] to the underlying arrow writer.
+    pub fn try_write_batch(&mut self, record_batch: &RecordBatch) -> Result<()> {
+        self.inner.write(record_batch)?;
         Ok(())
     }
 
-    /// Close and finalize the underlying arrow writer.
-    pub fn try_finish(&mut self) -> Result<()> {
-        self.inner.finish()?;
-        Ok(())
+    /// Add a key-value pair to the metadata of the written file.
+    pub fn append_key_value_metadata(&mut self, key: String, value: String) {
+        self.inner
+            .append_key_value_metadata(KeyValue::new(key, Some(value)));
+    }
+
+    /// Close and finalize the underlying arrow writer, returning the metadata of the written file.
+    pub fn try_finish(&mut self) -> Result<FileMetaData> {
+        Ok(self.inner.finish()?)
     }
 }
 
@@ -114,12 +118,10 @@ impl ParquetWriterBuilder {
             ))
         })?;
 
-        let n_columns: usize = schema.all_fields().len();
-
         // Note, here it is OK for no properties to be set.
         let inner: ArrowWriter<File> = ArrowWriter::try_new(out_file, schema, self.properties)?;
 
-        Ok(ParquetWriter { inner, n_columns })
+        Ok(ParquetWriter { inner })
     }
 
     ///
@@ -22,17 +22,41 @@
 // SOFTWARE.
 //
 // File created: 2024-02-05
-// Last updated: 2024-10-13
+// Last updated: 2026-10-18
 //
 
 use clap::{value_parser, ArgAction, Parser, Subcommand};
-use evolution_common::error::Result;
+#[cfg(feature = "mock")]
+use evolution_common::error::ExecutionError;
+use evolution_common::error::{Result, SetupError};
 use evolution_common::thread::get_available_threads;
 use evolution_converter::converter::ParquetConverter;
+use evolution_converter::multi::MultiParquetConverter;
+#[cfg(feature = "mock")]
+use evolution_mocker::dataset::DatasetMocker;
+#[cfg(feature = "mock")]
+use evolution_mocker::fault::FaultKind;
 #[cfg(feature = "mock")]
 use evolution_mocker::mocker::FixedLengthFileMocker;
+#[cfg(feature = "mock")]
+use evolution_mocker::text::Charset;
+#[cfg(feature = "mock")]
+use evolution_mocker::truth::GroundTruthFormat;
+use evolution_schema::column::Condition;
+use evolution_schema::copybook::Copybook;
+use evolution_schema::detect::DtypeDetector;
+use evolution_schema::infer::Sample;
+use evolution_schema::profile::{Profiler, DEFAULT_TOP_K};
+use evolution_schema::schema::FixedSchema;
 use evolution_target::target::Target;
+#[cfg(feature = "mock")]
+use evolution_verify::report::RoundTripReport;
+#[cfg(feature = "mock")]
+use evolution_verify::roundtrip::{RoundTrip, DEFAULT_MAX_EXAMPLES};
+#[cfg(feature = "mock")]
+use log::{info, warn};
 
+use std::fs;
 use std::path::PathBuf;
 
 #[derive(Parser)]
@@ -136,6 +160,101 @@ enum Commands {
             required = false,
         )]
         target: Target,
+
+        /// Write a json summary of the conversion to this file.
+        #[arg(
+            long = "report-file",
+            action = ArgAction::Set,
+            required = false,
+        )]
+        report_file: Option<PathBuf>,
+
+        /// A comma separated list of the names of the columns to convert, all columns are
+        /// converted if not provided.
+        #[arg(
+            long = "columns",
+            action = ArgAction::Set,
+            value_delimiter = ',',
+            num_args = 1..,
+            required = false,
+        )]
+        columns: Option<Vec<String>>,
+
+        /// Only convert the rows satisfying this filter expression, e.g. "country = 'SE' AND amount > 0".
+        #[arg(
+            long = "filter",
+            action = ArgAction::Set,
+            required = false,
+        )]
+        filter: Option<String>,
+
+        /// Treat the schema as a multi-layout schema, where each record type is written to its
+        /// own file in the directory given as the output file.
+        #[arg(
+            long = "multi-layout",
+            action = ArgAction::SetTrue,
+            required = false,
+        )]
+        multi_layout: bool,
+    },
+
+    /// Create json schemas for fixed-length files.
+    Schema {
+        #[command(subcommand)]
+        command: SchemaCommands,
+    },
+
+    /// Profile the values of the columns of a fixed-length file, e.g. to mock synthetic copies
+    /// of it.
+    Profile {
+        /// The json schema of the fixed-length file.
+        #[arg(
+            short = 's',
+            long = "schema",
+            action = ArgAction::Set,
+            required = true,
+        )]
+        schema: PathBuf,
+
+        /// The fixed-length file to profile.
+        #[arg(
+            short = 'i',
+            long = "in-file",
+            action = ArgAction::Set,
+            required = true,
+        )]
+        in_file: PathBuf,
+
+        /// The file to write the json profile to, it is printed if not provided.
+        #[arg(
+            short = 'o',
+            long = "out-file",
+            action = ArgAction::Set,
+            required = false,
+        )]
+        out_file: Option<PathBuf>,
+
+        /// The number of body records, from the start of the file, to profile.
+        #[arg(
+            short = 'n',
+            long = "n-records",
+            action = ArgAction::Set,
+            default_value = "1000000",
+            value_parser = value_parser!(usize),
+            required = false,
+        )]
+        n_records: usize,
+
+        /// The number of most frequent values of each column to profile.
+        #[arg(
+            short = 'k',
+            long = "top-k",
+            action = ArgAction::Set,
+            default_value_t = DEFAULT_TOP_K,
+            value_parser = value_parser!(usize),
+            required = false,
+        )]
+        top_k: usize,
     },
 
     /// Generate mocked fixed-length files.
@@ -185,6 +304,403 @@ enum Commands {
             required = false,
         )]
         truncate_existing: bool,
+
+        /// The seed to mock the rows from, the same seed always produces the same file.
+        #[arg(
+            long = "seed",
+            action = ArgAction::Set,
+            value_parser = value_parser!(u64),
+            required = false,
+        )]
+        seed: Option<u64>,
+
+        /// The ratio, between 0 and 1, of the fields of the nullable columns to leave blank.
+        #[arg(
+            long = "null-ratio",
+            action = ArgAction::Set,
+            value_parser = value_parser!(f64),
+            required = false,
+        )]
+        null_ratio: Option<f64>,
+
+        /// A comma separated list of null ratios of individual nullable columns, overriding the
+        /// null ratio for them, e.g. "city=0.5,email=0.1".
+        #[arg(
+            long = "column-null-ratios",
+            action = ArgAction::Set,
+            value_delimiter = ',',
+            value_parser = parse_column_null_ratio,
+            num_args = 1..,
+            required = false,
+        )]
+        column_null_ratios: Option<Vec<(String, f64)>>,
+
+        /// A json mock config file, with the specifications of the generators of the values of
+        /// individual columns.
+        #[arg(
+            long = "config",
+            action = ArgAction::Set,
+            required = false,
+        )]
+        config: Option<PathBuf>,
+
+        /// A json profile of a fixed-length file with the same schema, to mock values like the
+        /// profiled values, where null ratios and generators set otherwise take precedence.
+        #[arg(
+            long = "profile",
+            action = ArgAction::Set,
+            required = false,
+        )]
+        profile: Option<PathBuf>,
+
+        /// The characters of the mocked strings, one of ascii, nordic, cjk, emoji and mixed.
+        /// All but ascii mock strings of multibyte characters, padded to the number of
+        /// characters of their columns.
+        #[arg(
+            long = "charset",
+            action = ArgAction::Set,
+            value_parser = str::parse::<Charset>,
+            required = false,
+        )]
+        charset: Option<Charset>,
+
+        /// A comma separated list of the rates of the rows to inject faults into, by the kind
+        /// of fault, e.g. "unparseable_value=0.01,truncated_line=0.001". The kinds of faults
+        /// are unparseable_value, truncated_line, overlong_line, invalid_utf8,
+        /// wrong_line_terminator and missing_trailing_newline.
+        #[arg(
+            long = "fault-rates",
+            action = ArgAction::Set,
+            value_delimiter = ',',
+            value_parser = parse_fault_rate,
+            num_args = 1..,
+            required = false,
+        )]
+        fault_rates: Option<Vec<(FaultKind, f64)>>,
+
+        /// The json file to write the manifest of the injected faults to.
+        #[arg(
+            long = "fault-manifest",
+            action = ArgAction::Set,
+            required = false,
+        )]
+        fault_manifest: Option<PathBuf>,
+
+        /// The file to write the mocked values to, typed by the schema, as the ground truth
+        /// to compare a converted file with. Can not be combined with faults.
+        #[arg(
+            long = "ground-truth",
+            action = ArgAction::Set,
+            required = false,
+        )]
+        ground_truth: Option<PathBuf>,
+
+        /// The format of the ground truth file, either "parquet" or "ipc".
+        #[arg(
+            long = "ground-truth-format",
+            action = ArgAction::Set,
+            required = false,
+            value_parser = str::parse::<GroundTruthFormat>,
+        )]
+        ground_truth_format: Option<GroundTruthFormat>,
+    },
+
+    /// Generate a dataset of mocked fixed-length files whose columns relate by foreign keys.
+    #[cfg(feature = "mock")]
+    MockDataset {
+        /// The json dataset specification, with the files to generate and the foreign keys
+        /// between them.
+        #[arg(
+            short = 'd',
+            long = "dataset",
+            action = ArgAction::Set,
+            required = true,
+        )]
+        dataset: PathBuf,
+
+        /// Writer option to return an error if any of the files already exists.
+        #[arg(
+            long = "force-create-new",
+            action = ArgAction::SetTrue,
+            required = false,
+        )]
+        force_create_new: bool,
+
+        /// Writer option to truncate any of the files if it already exists.
+        #[arg(
+            long = "truncate-existing",
+            action = ArgAction::SetTrue,
+            required = false,
+        )]
+        truncate_existing: bool,
+
+        /// The seed to mock the dataset from, the same seed always produces the same files.
+        #[arg(
+            long = "seed",
+            action = ArgAction::Set,
+            value_parser = value_parser!(u64),
+            required = false,
+        )]
+        seed: Option<u64>,
+    },
+
+    /// Mock a file from a seed, convert it to parquet, and compare every converted value with
+    /// the value the mocker generated.
+    #[cfg(feature = "mock")]
+    Verify {
+        /// The json schema to generate data based on.
+        #[arg(
+            short = 's',
+            long = "schema",
+            action = ArgAction::Set,
+            required = true,
+        )]
+        schema: PathBuf,
+
+        /// The number of rows to generate.
+        #[arg(
+            short = 'n',
+            long = "n-rows",
+            action = ArgAction::Set,
+            default_value = "100000",
+            value_parser = value_parser!(usize),
+            required = false,
+        )]
+        n_rows: usize,
+
+        /// The seed to mock the rows from.
+        #[arg(
+            long = "seed",
+            action = ArgAction::Set,
+            default_value = "0",
+            value_parser = value_parser!(u64),
+            required = false,
+        )]
+        seed: u64,
+
+        /// The ratio, between 0 and 1, of the fields of the nullable columns to leave blank.
+        #[arg(
+            long = "null-ratio",
+            action = ArgAction::Set,
+            value_parser = value_parser!(f64),
+            required = false,
+        )]
+        null_ratio: Option<f64>,
+
+        /// A json mock config file, with the specifications of the generators of the values of
+        /// individual columns.
+        #[arg(
+            long = "config",
+            action = ArgAction::Set,
+            required = false,
+        )]
+        config: Option<PathBuf>,
+
+        /// The characters of the mocked strings, one of ascii, nordic, cjk, emoji and mixed.
+        #[arg(
+            long = "charset",
+            action = ArgAction::Set,
+            value_parser = str::parse::<Charset>,
+            required = false,
+        )]
+        charset: Option<Charset>,
+
+        /// The directory to write the mocked and converted files to, defaults to the temporary
+        /// directory.
+        #[arg(
+            long = "work-dir",
+            action = ArgAction::Set,
+            required = false,
+        )]
+        work_dir: Option<PathBuf>,
+
+        /// Keep the mocked and converted files after the round trip.
+        #[arg(
+            long = "keep-files",
+            action = ArgAction::SetTrue,
+            required = false,
+        )]
+        keep_files: bool,
+
+        /// The maximum number of mismatched values to list per column.
+        #[arg(
+            long = "max-examples",
+            action = ArgAction::Set,
+            default_value_t = DEFAULT_MAX_EXAMPLES,
+            value_parser = value_parser!(usize),
+            required = false,
+        )]
+        max_examples: usize,
+
+        /// Write a json report of the mismatched values to this file.
+        #[arg(
+            long = "report-file",
+            action = ArgAction::Set,
+            required = false,
+        )]
+        report_file: Option<PathBuf>,
+    },
+}
+
+/// Parse the null ratio of a column, written as `name=ratio`.
+#[cfg(feature = "mock")]
+fn parse_column_null_ratio(value: &str) -> std::result::Result<(String, f64), String> {
+    let (name, ratio) = value
+        .split_once('=')
+        .ok_or_else(|| format!("expected 'name=ratio' but found '{}'", value))?;
+    let ratio: f64 = ratio
+        .parse::<f64>()
+        .map_err(|e| format!("invalid null ratio '{}': {}", ratio, e))?;
+
+    Ok((name.to_string(), ratio))
+}
+
+/// Parse the rate of a kind of fault, written as `kind=rate`.
+#[cfg(feature = "mock")]
+fn parse_fault_rate(value: &str) -> std::result::Result<(FaultKind, f64), String> {
+    let (kind, rate) = value
+        .split_once('=')
+        .ok_or_else(|| format!("expected 'kind=rate' but found '{}'", value))?;
+    let rate: f64 = rate
+        .parse::<f64>()
+        .map_err(|e| format!("invalid fault rate '{}': {}", rate, e))?;
+
+    Ok((kind.parse::<FaultKind>()?, rate))
+}
+
+/// Parse the condition of a definition of a redefined copybook region, written as
+/// `item=column:value,...`.
+fn parse_condition(value: &str) -> std::result::Result<(String, Condition), String> {
+    let (item, condition) = value
+        .split_once('=')
+        .ok_or_else(|| format!("expected 'item=column:value,...' but found '{}'", value))?;
+    let (column, values) = condition
+        .split_once(':')
+        .ok_or_else(|| format!("expected 'column:value,...' but found '{}'", condition))?;
+
+    Ok((
+        item.to_string(),
+        Condition::new(
+            column.to_string(),
+            values.split(',').map(|v| v.to_string()).collect(),
+        ),
+    ))
+}
+
+#[derive(Subcommand)]
+enum SchemaCommands {
+    /// Create a json schema from a COBOL copybook.
+    Copybook {
+        /// The copybook to create the schema from.
+        #[arg(
+            short = 'i',
+            long = "in-file",
+            action = ArgAction::Set,
+            required = true,
+        )]
+        in_file: PathBuf,
+
+        /// The file to write the json schema to, it is printed if not provided.
+        #[arg(
+            short = 'o',
+            long = "out-file",
+            action = ArgAction::Set,
+            required = false,
+        )]
+        out_file: Option<PathBuf>,
+
+        /// The name of the record to create the schema from, if the copybook contains several.
+        #[arg(
+            long = "record",
+            action = ArgAction::Set,
+            required = false,
+        )]
+        record: Option<String>,
+
+        /// The condition under which a definition of a redefined region is in use, written as
+        /// "item=column:value,...", e.g. "PERSON=CUST-TYPE:P". Can be given several times, and
+        /// a region becomes alternative columns when all of its definitions have a condition.
+        #[arg(
+            long = "condition",
+            action = ArgAction::Append,
+            value_parser = parse_condition,
+            required = false,
+        )]
+        conditions: Option<Vec<(String, Condition)>>,
+    },
+
+    /// Infer a draft json schema from a sample of the records of a fixed-length file.
+    Infer {
+        /// The fixed-length file to sample the records from.
+        #[arg(
+            short = 'i',
+            long = "in-file",
+            action = ArgAction::Set,
+            required = true,
+        )]
+        in_file: PathBuf,
+
+        /// The file to write the json schema to, it is printed if not provided.
+        #[arg(
+            short = 'o',
+            long = "out-file",
+            action = ArgAction::Set,
+            required = false,
+        )]
+        out_file: Option<PathBuf>,
+
+        /// The number of records, from the start of the file, to infer the schema from.
+        #[arg(
+            short = 'n',
+            long = "n-records",
+            action = ArgAction::Set,
+            default_value = "1000",
+            value_parser = value_parser!(usize),
+            required = false,
+        )]
+        n_records: usize,
+    },
+
+    /// Detect narrower datatypes for the string columns of a json schema, from a sample of the
+    /// records of a fixed-length file.
+    DetectDtypes {
+        /// The json schema to detect the datatypes for.
+        #[arg(
+            short = 's',
+            long = "schema",
+            action = ArgAction::Set,
+            required = true,
+        )]
+        schema: PathBuf,
+
+        /// The fixed-length file to sample the records from.
+        #[arg(
+            short = 'i',
+            long = "in-file",
+            action = ArgAction::Set,
+            required = true,
+        )]
+        in_file: PathBuf,
+
+        /// The file to write the json schema to, it is printed if not provided.
+        #[arg(
+            short = 'o',
+            long = "out-file",
+            action = ArgAction::Set,
+            required = false,
+        )]
+        out_file: Option<PathBuf>,
+
+        /// The number of body records, from the start of the file, to detect the datatypes from.
+        #[arg(
+            short = 'n',
+            long = "n-records",
+            action = ArgAction::Set,
+            default_value = "1000",
+            value_parser = value_parser!(usize),
+            required = false,
+        )]
+        n_records: usize,
     },
 }
 
@@ -201,10 +717,32 @@ impl Cli {
                 schema,
                 out_file,
                 target,
+                report_file,
+                columns,
+                filter,
+                multi_layout,
             } => match target {
                 Target::Delta => todo!(),
                 Target::Iceberg => todo!(),
                 Target::Ipc => todo!(),
+                Target::Parquet if *multi_layout => {
+                    if columns.is_some() || filter.is_some() {
+                        return Err(Box::new(SetupError::new(
+                            "Columns and filters are not supported for multi-layout schemas, exiting...",
+                        )));
+                    }
+
+                    MultiParquetConverter::builder()
+                        .with_in_file(in_file.to_path_buf())
+                        .with_schema(schema.to_path_buf())
+                        .with_out_dir(out_file.to_path_buf())
+                        .with_num_threads(n_threads)
+                        .with_read_buffer_size(read_buffer_size)
+                        .with_thread_channel_capacity(self.thread_channel_capacity)
+                        .with_report_file(report_file.clone())
+                        .try_build()?
+                        .try_convert()?;
+                }
                 Target::Parquet => {
                     ParquetConverter::builder()
                         .with_in_file(in_file.to_path_buf())
@@ -213,10 +751,87 @@ impl Cli {
                         .with_num_threads(n_threads)
                         .with_read_buffer_size(read_buffer_size)
                         .with_thread_channel_capacity(self.thread_channel_capacity)
+                        .with_report_file(report_file.clone())
+                        .with_columns(columns.clone())
+                        .with_filter(filter.clone())
                         .try_build()?
                         .try_convert()?;
                 }
             },
+            Commands::Schema { command } => match command {
+                SchemaCommands::Copybook {
+                    in_file,
+                    out_file,
+                    record,
+                    conditions,
+                } => {
+                    let json: String = conditions
+                        .iter()
+                        .flatten()
+                        .fold(
+                            Copybook::from_path(in_file.to_path_buf())?,
+                            |copybook, (item, condition)| {
+                                copybook.with_condition(item, condition.clone())
+                            },
+                        )
+                        .try_into_schema(record.as_deref())?
+                        .try_to_json()?;
+
+                    match out_file {
+                        Some(path) => fs::write(path, json)?,
+                        None => println!("{}", json),
+                    }
+                }
+                SchemaCommands::Infer {
+                    in_file,
+                    out_file,
+                    n_records,
+                } => {
+                    let json: String = Sample::from_path(in_file.to_path_buf(), *n_records)?
+                        .try_into_schema()?
+                        .try_to_json()?;
+
+                    match out_file {
+                        Some(path) => fs::write(path, json)?,
+                        None => println!("{}", json),
+                    }
+                }
+                SchemaCommands::DetectDtypes {
+                    schema,
+                    in_file,
+                    out_file,
+                    n_records,
+                } => {
+                    let schema: FixedSchema = FixedSchema::from_path(schema.to_path_buf())?;
+                    let json: String =
+                        DtypeDetector::from_path(in_file.to_path_buf(), &schema, *n_records)?
+                            .try_detect(&schema)?
+                            .try_to_json()?;
+
+                    match out_file {
+                        Some(path) => fs::write(path, json)?,
+                        None => println!("{}", json),
+                    }
+                }
+            },
+            Commands::Profile {
+                schema,
+                in_file,
+                out_file,
+                n_records,
+                top_k,
+            } => {
+                let schema: FixedSchema = FixedSchema::from_path(schema.to_path_buf())?;
+                let json: String = Profiler::from_path(in_file.to_path_buf(), &schema, *n_records)?
+                    .with_top_k(*top_k)
+                    .try_profile(&schema)?
+                    .try_to_json()?;
+
+                match out_file {
+                    Some(path) => fs::write(path, json)?,
+                    None => println!("{}", json),
+                }
+            }
             #[cfg(feature = "mock")]
             Commands::Mock {
                 schema,
@@ -224,17 +839,164 @@ impl Cli {
                 n_rows,
                 force_create_new,
                 truncate_existing,
+                seed,
+                null_ratio,
+                column_null_ratios,
+                config,
+                profile,
+                charset,
+                fault_rates,
+                fault_manifest,
+                ground_truth,
+                ground_truth_format,
             } => {
-                FixedLengthFileMocker::builder()
+                let mut builder = FixedLengthFileMocker::builder()
                     .with_schema(schema.to_path_buf())
                     .with_out_file(out_file.to_path_buf())
                     .with_num_rows(*n_rows)
                     .with_num_threads(n_threads)
                     .with_write_buffer_size(write_buffer_size)
                     .with_force_create_new(*force_create_new)
-                    .with_truncate_existing(*truncate_existing)
-                    .try_build()?
-                    .try_mock()?;
+                    .with_truncate_existing(*truncate_existing);
+
+                if let Some(seed) = seed {
+                    builder = builder.with_seed(*seed);
+                }
+
+                if let Some(null_ratio) = null_ratio {
+                    builder = builder.with_null_ratio(*null_ratio);
+                }
+
+                for (name, ratio) in column_null_ratios.iter().flatten() {
+                    builder = builder.with_column_null_ratio(name.clone(), *ratio);
+                }
+
+                if let Some(config) = config {
+                    builder = builder.with_config(config.to_path_buf());
+                }
+
+                if let Some(profile) = profile {
+                    builder = builder.with_profile(profile.to_path_buf());
+                }
+
+                if let Some(charset) = charset {
+                    builder = builder.with_charset(*charset);
+                }
+
+                for (kind, rate) in fault_rates.iter().flatten() {
+                    builder = builder.with_fault_rate(*kind, *rate);
+                }
+
+                if let Some(fault_manifest) = fault_manifest {
+                    builder = builder.with_fault_manifest(fault_manifest.to_path_buf());
+                }
+
+                if let Some(ground_truth) = ground_truth {
+                    builder = builder.with_ground_truth_file(ground_truth.to_path_buf());
+                }
+
+                if let Some(ground_truth_format) = ground_truth_format {
+                    builder = builder.with_ground_truth_format(*ground_truth_format);
+                }
+
+                builder.try_build()?.try_mock()?;
+            }
+            #[cfg(feature = "mock")]
+            Commands::MockDataset {
+                dataset,
+                force_create_new,
+                truncate_existing,
+                seed,
+            } => {
+                let mut builder = DatasetMocker::builder()
+                    .with_spec(dataset.to_path_buf())
+                    .with_num_threads(n_threads)
+                    .with_write_buffer_size(write_buffer_size)
+                    .with_thread_channel_capacity(self.thread_channel_capacity)
+                    .with_force_create_new(*force_create_new)
+                    .with_truncate_existing(*truncate_existing);
+
+                if let Some(seed) = seed {
+                    builder = builder.with_seed(*seed);
+                }
+
+                builder.try_build()?.try_mock()?;
+            }
+            #[cfg(feature = "mock")]
+            Commands::Verify {
+                schema,
+                n_rows,
+                seed,
+                null_ratio,
+                config,
+                charset,
+                work_dir,
+                keep_files,
+                max_examples,
+                report_file,
+            } => {
+                let mut builder = RoundTrip::builder()
+                    .with_schema(schema.to_path_buf())
+                    .with_num_rows(*n_rows)
+                    .with_seed(*seed)
+                    .with_num_threads(n_threads)
+                    .with_write_buffer_size(write_buffer_size)
+                    .with_read_buffer_size(read_buffer_size)
+                    .with_thread_channel_capacity(self.thread_channel_capacity)
+                    .with_max_examples(*max_examples)
+                    .with_keep_files(*keep_files);
+
+                if let Some(null_ratio) = null_ratio {
+                    builder = builder.with_null_ratio(*null_ratio);
+                }
+
+                if let Some(config) = config {
+                    builder = builder.with_config(config.to_path_buf());
+                }
+
+                if let Some(charset) = charset {
+                    builder = builder.with_charset(*charset);
+                }
+
+                if let Some(work_dir) = work_dir {
+                    builder = builder.with_work_dir(work_dir.to_path_buf());
+                }
+
+                let report: RoundTripReport = builder.try_build()?.try_verify()?;
+
+                if let Some(path) = report_file {
+                    report.try_write_to_path(path.to_path_buf())?;
+                }
+
+                for column in report.columns().iter().filter(|c| c.n_mismatches() > 0) {
+                    warn!(
+                        "Column '{}' has {} mismatched values.",
+                        column.name(),
+                        column.n_mismatches(),
+                    );
+                    for mismatch in column.examples() {
+                        warn!(
+                            "Row {}: expected {:?}, found {:?}.",
+                            mismatch.row(),
+                            mismatch.expected(),
+                            mismatch.actual(),
+                        );
+                    }
+                }
+
+                if !report.is_ok() {
+                    return Err(Box::new(ExecutionError::new(&format!(
+                        "The round trip of {} mocked rows read back {} rows with {} mismatched values, exiting...",
+                        report.expected_rows(),
+                        report.actual_rows(),
+                        report.n_mismatches(),
+                    ))));
+                }
+
+                info!(
+                    "All {} mocked rows survived the round trip.",
+                    report.expected_rows()
+                );
             }
         };
 